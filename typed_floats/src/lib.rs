@@ -28,7 +28,25 @@
 //!
 //! let c: StrictlyPositive = a + b;
 //!
-//! assert_eq!(c, 1.0);   
+//! assert_eq!(c, 1.0);
+//! ```
+//!
+//! The product of two non-zero numbers may still round to zero,
+//! and the product of zero and infinity is `NaN`:
+//!
+//! ```
+//! use typed_floats::*;
+//!
+//! let a: StrictlyPositive = 2.0f64.try_into().unwrap();
+//! let b: StrictlyNegative = (-3.0f64).try_into().unwrap();
+//!
+//! let c: Negative = a * b;
+//!
+//! assert_eq!(c, -6.0);
+//!
+//! let d: f64 = tf64::ZERO * tf64::INFINITY;
+//!
+//! assert_is_nan!(d);
 //! ```
 //!
 //! Operations that assign the result to the left operand are only
@@ -56,16 +74,80 @@
 //! assert_eq!(a, f64::INFINITY);
 //! ```
 //!
+//! The same applies to `%=` and `/=`: the remainder has the sign of the dividend, so it can be assigned
+//! as long as the divisor can't be zero, but a division may overflow or be rounded to zero.
+//!
+//! ```
+//! use typed_floats::*;
+//!
+//! let mut a: PositiveFinite = 5.0f64.try_into().unwrap();
+//! let b: StrictlyPositiveFinite = 3.0f64.try_into().unwrap();
+//!
+//! a %= b;
+//!
+//! assert_eq!(a, 2.0);
+//! ```
+//!
+//! ```compile_fail
+//! use typed_floats::*;
+//!
+//! let mut a: PositiveFinite = 5.0f64.try_into().unwrap();
+//! let b: PositiveFinite = 0.0f64.try_into().unwrap();
+//!
+//! a %= b;// Does not compile, the result may be `NaN`
+//! ```
+//!
+//! ```compile_fail
+//! use typed_floats::*;
+//!
+//! let mut a: StrictlyPositive = 5.0f64.try_into().unwrap();
+//! let b: StrictlyPositive = 5.0f64.try_into().unwrap();
+//!
+//! a %= b;// Does not compile, the result may be zero
+//! ```
+//!
+//! ```compile_fail
+//! use typed_floats::*;
+//!
+//! let mut a: PositiveFinite = f64::MAX.try_into().unwrap();
+//! let b: StrictlyPositiveFinite = 0.5f64.try_into().unwrap();
+//!
+//! a /= b;// Does not compile, the result may be infinite
+//! ```
+//!
+//! Conversions between types are free when the target type accepts every value of the source type:
+//!
+//! ```
+//! use typed_floats::*;
+//!
+//! let a: StrictlyPositiveFinite = 1.0f64.try_into().unwrap();
+//! let b: Positive = a.into(); // no need for try_into
+//!
+//! assert_eq!(b, 1.0);
+//! ```
+//!
+//! ```compile_fail
+//! use typed_floats::*;
+//!
+//! let a: Positive = 1.0f64.try_into().unwrap();
+//! let b: StrictlyPositiveFinite = a.into(); // Does not compile, `a` may be zero or infinite
+//! ```
+//!
 //! Conversions from non-zero integers are available:
 //!
 //! ```
 //! use typed_floats::*;
-//! use core::num::NonZeroU64;
+//! use core::num::{NonZeroI16, NonZeroU64};
 //!
 //! let a = NonZeroU64::new(1).unwrap();
 //! let b: StrictlyPositive = a.into(); // no need for try_into
 //!
 //! assert_eq!(b, 1.0);
+//!
+//! let c = NonZeroI16::new(-5).unwrap();
+//! let d: NonZeroNonNaNFinite = c.into();
+//!
+//! assert_eq!(d, -5.0);
 //! ```
 //!
 //! Also, comparaison between types is available:
@@ -117,12 +199,25 @@
 extern crate alloc;
 
 mod macros;
+pub mod polynomial;
+mod possibilities;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub mod powi;
+mod range;
 mod traits;
 mod types;
 
 #[cfg(feature = "serde")]
 mod serde;
 
+#[cfg(feature = "serde")]
+pub mod serde_helpers;
+
+#[cfg(feature = "units")]
+pub mod units;
+
+pub use possibilities::*;
+pub use range::*;
 pub use traits::*;
 pub use types::*;
 
@@ -168,6 +263,9 @@ pub mod tf64 {
     /// Equivalent to `NegativeFinite<f64>`
     pub type NegativeFinite = crate::NegativeFinite<f64>;
 
+    /// Equivalent to `UnitInterval<f64>`
+    pub type UnitInterval = crate::UnitInterval<f64>;
+
     /// Returns `true` if the number is positive zero.
     ///     
     /// # Examples
@@ -390,7 +488,7 @@ pub mod tf64 {
     }
 
     #[cfg(test)]
-    pub(crate) const TEST_VALUES: [f64; 21] = typed_floats_macros::test_values!(f64);
+    pub(crate) const TEST_VALUES: [f64; 23] = typed_floats_macros::test_values!(f64);
 }
 
 /// This module contains constants from [`core::f32`], casted to the corresponding type
@@ -431,6 +529,9 @@ pub mod tf32 {
     /// Equivalent to `NegativeFinite<f32>`
     pub type NegativeFinite = crate::NegativeFinite<f32>;
 
+    /// Equivalent to `UnitInterval<f32>`
+    pub type UnitInterval = crate::UnitInterval<f32>;
+
     /// Returns `true` if the number is positive zero.
     ///     
     /// # Examples
@@ -653,5 +754,261 @@ pub mod tf32 {
     }
 
     #[cfg(test)]
-    pub(crate) const TEST_VALUES: [f32; 21] = typed_floats_macros::test_values!(f32);
+    pub(crate) const TEST_VALUES: [f32; 23] = typed_floats_macros::test_values!(f32);
+}
+
+/// This module contains constants from [`half::f16`], casted to the corresponding type
+#[cfg(feature = "half")]
+pub mod tf16 {
+    /// Equivalent to `NonNaN<half::f16>`
+    pub type NonNaN = crate::NonNaN<half::f16>;
+
+    /// Equivalent to `NonNaNFinite<half::f16>`
+    pub type NonNaNFinite = crate::NonNaNFinite<half::f16>;
+
+    /// Equivalent to `NonZeroNonNaN<half::f16>`
+    pub type NonZeroNonNaN = crate::NonZeroNonNaN<half::f16>;
+
+    /// Equivalent to `NonZeroNonNaNFinite<half::f16>`
+    pub type NonZeroNonNaNFinite = crate::NonZeroNonNaNFinite<half::f16>;
+
+    /// Equivalent to `StrictlyPositive<half::f16>`
+    pub type StrictlyPositive = crate::StrictlyPositive<half::f16>;
+
+    /// Equivalent to `StrictlyNegative<half::f16>`
+    pub type StrictlyNegative = crate::StrictlyNegative<half::f16>;
+
+    /// Equivalent to `Positive<half::f16>`
+    pub type Positive = crate::Positive<half::f16>;
+
+    /// Equivalent to `Negative<half::f16>`
+    pub type Negative = crate::Negative<half::f16>;
+
+    /// Equivalent to `StrictlyPositiveFinite<half::f16>`
+    pub type StrictlyPositiveFinite = crate::StrictlyPositiveFinite<half::f16>;
+
+    /// Equivalent to `StrictlyNegativeFinite<half::f16>`
+    pub type StrictlyNegativeFinite = crate::StrictlyNegativeFinite<half::f16>;
+
+    /// Equivalent to `PositiveFinite<half::f16>`
+    pub type PositiveFinite = crate::PositiveFinite<half::f16>;
+
+    /// Equivalent to `NegativeFinite<half::f16>`
+    pub type NegativeFinite = crate::NegativeFinite<half::f16>;
+
+    crate::macros::generate_half_const!(
+        INFINITY,
+        StrictlyPositive,
+        f16,
+        half::f16::INFINITY,
+        "Infinity (∞)."
+    );
+
+    crate::macros::generate_half_const!(
+        NEG_INFINITY,
+        StrictlyNegative,
+        f16,
+        half::f16::NEG_INFINITY,
+        "Negative infinity (−∞)."
+    );
+
+    crate::macros::generate_half_const!(
+        ZERO,
+        PositiveFinite,
+        f16,
+        half::f16::ZERO,
+        "Positive zero (+0.0)."
+    );
+
+    crate::macros::generate_half_const!(
+        NEG_ZERO,
+        NegativeFinite,
+        f16,
+        half::f16::NEG_ZERO,
+        "Negative zero (-0.0)."
+    );
+
+    crate::macros::generate_half_const!(
+        MAX,
+        StrictlyPositiveFinite,
+        f16,
+        half::f16::MAX,
+        "Largest finite `f16` value."
+    );
+
+    crate::macros::generate_half_const!(
+        MIN,
+        StrictlyNegativeFinite,
+        f16,
+        half::f16::MIN,
+        "Smallest finite `f16` value."
+    );
+
+    crate::macros::generate_half_const!(
+        MIN_POSITIVE,
+        StrictlyPositiveFinite,
+        f16,
+        half::f16::MIN_POSITIVE,
+        "Smallest positive normal `f16` value."
+    );
+
+    crate::macros::generate_half_const!(
+        ONE,
+        StrictlyPositiveFinite,
+        f16,
+        half::f16::ONE,
+        "One (1.0)."
+    );
+
+    crate::macros::generate_half_const!(
+        NEG_ONE,
+        StrictlyNegativeFinite,
+        f16,
+        half::f16::NEG_ONE,
+        "Negative one (-1.0)."
+    );
+
+    crate::macros::generate_half_const!(
+        PI,
+        StrictlyPositiveFinite,
+        f16,
+        half::f16::PI,
+        "Archimedes' constant (π)."
+    );
+
+    crate::macros::generate_half_const!(
+        E,
+        StrictlyPositiveFinite,
+        f16,
+        half::f16::E,
+        "Euler's number (e)."
+    );
+}
+
+/// This module contains constants from [`half::bf16`], casted to the corresponding type
+#[cfg(feature = "half")]
+pub mod tbf16 {
+    /// Equivalent to `NonNaN<half::bf16>`
+    pub type NonNaN = crate::NonNaN<half::bf16>;
+
+    /// Equivalent to `NonNaNFinite<half::bf16>`
+    pub type NonNaNFinite = crate::NonNaNFinite<half::bf16>;
+
+    /// Equivalent to `NonZeroNonNaN<half::bf16>`
+    pub type NonZeroNonNaN = crate::NonZeroNonNaN<half::bf16>;
+
+    /// Equivalent to `NonZeroNonNaNFinite<half::bf16>`
+    pub type NonZeroNonNaNFinite = crate::NonZeroNonNaNFinite<half::bf16>;
+
+    /// Equivalent to `StrictlyPositive<half::bf16>`
+    pub type StrictlyPositive = crate::StrictlyPositive<half::bf16>;
+
+    /// Equivalent to `StrictlyNegative<half::bf16>`
+    pub type StrictlyNegative = crate::StrictlyNegative<half::bf16>;
+
+    /// Equivalent to `Positive<half::bf16>`
+    pub type Positive = crate::Positive<half::bf16>;
+
+    /// Equivalent to `Negative<half::bf16>`
+    pub type Negative = crate::Negative<half::bf16>;
+
+    /// Equivalent to `StrictlyPositiveFinite<half::bf16>`
+    pub type StrictlyPositiveFinite = crate::StrictlyPositiveFinite<half::bf16>;
+
+    /// Equivalent to `StrictlyNegativeFinite<half::bf16>`
+    pub type StrictlyNegativeFinite = crate::StrictlyNegativeFinite<half::bf16>;
+
+    /// Equivalent to `PositiveFinite<half::bf16>`
+    pub type PositiveFinite = crate::PositiveFinite<half::bf16>;
+
+    /// Equivalent to `NegativeFinite<half::bf16>`
+    pub type NegativeFinite = crate::NegativeFinite<half::bf16>;
+
+    crate::macros::generate_half_const!(
+        INFINITY,
+        StrictlyPositive,
+        bf16,
+        half::bf16::INFINITY,
+        "Infinity (∞)."
+    );
+
+    crate::macros::generate_half_const!(
+        NEG_INFINITY,
+        StrictlyNegative,
+        bf16,
+        half::bf16::NEG_INFINITY,
+        "Negative infinity (−∞)."
+    );
+
+    crate::macros::generate_half_const!(
+        ZERO,
+        PositiveFinite,
+        bf16,
+        half::bf16::ZERO,
+        "Positive zero (+0.0)."
+    );
+
+    crate::macros::generate_half_const!(
+        NEG_ZERO,
+        NegativeFinite,
+        bf16,
+        half::bf16::NEG_ZERO,
+        "Negative zero (-0.0)."
+    );
+
+    crate::macros::generate_half_const!(
+        MAX,
+        StrictlyPositiveFinite,
+        bf16,
+        half::bf16::MAX,
+        "Largest finite `bf16` value."
+    );
+
+    crate::macros::generate_half_const!(
+        MIN,
+        StrictlyNegativeFinite,
+        bf16,
+        half::bf16::MIN,
+        "Smallest finite `bf16` value."
+    );
+
+    crate::macros::generate_half_const!(
+        MIN_POSITIVE,
+        StrictlyPositiveFinite,
+        bf16,
+        half::bf16::MIN_POSITIVE,
+        "Smallest positive normal `bf16` value."
+    );
+
+    crate::macros::generate_half_const!(
+        ONE,
+        StrictlyPositiveFinite,
+        bf16,
+        half::bf16::ONE,
+        "One (1.0)."
+    );
+
+    crate::macros::generate_half_const!(
+        NEG_ONE,
+        StrictlyNegativeFinite,
+        bf16,
+        half::bf16::NEG_ONE,
+        "Negative one (-1.0)."
+    );
+
+    crate::macros::generate_half_const!(
+        PI,
+        StrictlyPositiveFinite,
+        bf16,
+        half::bf16::PI,
+        "Archimedes' constant (π)."
+    );
+
+    crate::macros::generate_half_const!(
+        E,
+        StrictlyPositiveFinite,
+        bf16,
+        half::bf16::E,
+        "Euler's number (e)."
+    );
 }
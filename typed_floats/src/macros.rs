@@ -252,6 +252,71 @@ macro_rules! as_const {
     }};
 }
 
+/// Macro to create a value from a literal, checked at compile time.
+///
+/// Unlike `as_const!`, the value is always evaluated in a `const` item,
+/// so an invalid value fails the build even when the macro is used outside of a `const` context.
+///
+/// # Examples
+///
+/// ```
+/// # use typed_floats::*;
+/// let a: StrictlyPositive = typed_float!(StrictlyPositive, 2.5); // f64
+/// let b: NonNaN<f32> = typed_float!(NonNaN, f32, -1.0);
+/// const C: PositiveFinite = typed_float!(PositiveFinite, 0.0);
+///
+/// assert_eq!(a, 2.5);
+/// assert_eq!(b, -1.0);
+/// assert_eq!(C, 0.0);
+/// ```
+///
+/// Those examples fail to compile:
+///
+/// ```compile_fail
+/// # use typed_floats::*;
+/// let x: StrictlyPositive = typed_float!(StrictlyPositive, -1.0);
+/// ```
+///
+/// ```compile_fail
+/// # use typed_floats::*;
+/// let x: StrictlyPositive = typed_float!(StrictlyPositive, 0.0);
+/// ```
+///
+/// ```compile_fail
+/// # use typed_floats::*;
+/// let x: Positive = typed_float!(Positive, -0.0);
+/// ```
+///
+/// ```compile_fail
+/// # use typed_floats::*;
+/// let x: NonNaNFinite<f32> = typed_float!(NonNaNFinite, f32, 1.0 / 0.0);
+/// ```
+///
+/// ```compile_fail
+/// # use typed_floats::*;
+/// let x: NonNaN = typed_float!(NonNaN, 0.0 / 0.0);
+/// ```
+#[macro_export]
+macro_rules! typed_float {
+    ($type:ident, $float:ident, $x:expr) => {{
+        const VALUE: $crate::$type<$float> = match $crate::$type::<$float>::try_new($x) {
+            Some(x) => x,
+            None => panic!(concat!(
+                "The value is not a valid ",
+                stringify!($type),
+                "<",
+                stringify!($float),
+                ">"
+            )),
+        };
+
+        VALUE
+    }};
+    ($type:ident, $x:expr) => {{
+        $crate::typed_float!($type, f64, $x)
+    }};
+}
+
 /// Macro to create a constant.
 /// It will generate the full declaration of the constant:
 /// ```ignore
@@ -320,3 +385,20 @@ macro_rules! generate_const {
         pub const $name: $crate::$type = $crate::as_const!($type, f64, $x);
     };
 }
+
+/// Same as `generate_const!` for the `half` types, as `as_const!` uses
+/// comparisons that are not available in `const` contexts for them.
+#[cfg(feature = "half")]
+macro_rules! generate_half_const {
+    ($name:ident, $type:ident, $float:ident, $x:expr, $doc:expr) => {
+        #[doc = $doc]
+        pub const $name: $crate::$type<half::$float> =
+            match $crate::$type::<half::$float>::try_new($x) {
+                Some(x) => x,
+                None => panic!("The value is not valid"),
+            };
+    };
+}
+
+#[cfg(feature = "half")]
+pub(crate) use generate_half_const;
@@ -0,0 +1,60 @@
+//! Evaluation of polynomials with finite coefficients.
+//!
+//! # Examples
+//!
+//! ```
+//! use typed_floats::polynomial::eval_poly;
+//! use typed_floats::*;
+//!
+//! // x² + 1
+//! let coeffs: [NonNaNFinite; 3] = [1.0.try_into().unwrap(), tf64::ZERO.into(), 1.0.try_into().unwrap()];
+//!
+//! let y: NonNaN = eval_poly(&coeffs, 2.0.try_into().unwrap());
+//! assert_eq!(y, 5.0);
+//! ```
+
+use crate::{NonNaN, NonNaNFinite};
+
+/// Evaluates the polynomial `coeffs[0] + coeffs[1] * x + coeffs[2] * x² + ...` with Horner's method.
+///
+/// The coefficients are in ascending order of degree, and an empty slice is the zero polynomial,
+/// evaluated to `+0.0`.
+///
+/// Each step is a multiplication followed by an addition, without `mul_add`, so the result
+/// is the same with or without `std`. It may overflow to an infinity, but is never `NaN`.
+///
+/// # Examples
+///
+/// ```
+/// use typed_floats::polynomial::eval_poly;
+/// use typed_floats::*;
+///
+/// // 3x - 2
+/// let coeffs: [NonNaNFinite; 2] = [(-2.0).try_into().unwrap(), 3.0.try_into().unwrap()];
+///
+/// assert_eq!(eval_poly(&coeffs, 4.0.try_into().unwrap()), 10.0);
+///
+/// // Overflow
+/// let coeffs: [NonNaNFinite; 3] = [tf64::ZERO.into(), tf64::ZERO.into(), tf64::MAX.into()];
+///
+/// assert_eq!(eval_poly(&coeffs, 2.0.try_into().unwrap()), f64::INFINITY);
+///
+/// assert_is_positive_zero!(eval_poly(&[], 2.0.try_into().unwrap()));
+/// ```
+#[inline]
+#[must_use]
+#[allow(clippy::suboptimal_flops)] // `mul_add` is not available without `std`
+pub fn eval_poly(coeffs: &[NonNaNFinite<f64>], x: NonNaNFinite<f64>) -> NonNaN<f64> {
+    let x = x.get();
+
+    let y = coeffs
+        .iter()
+        .rev()
+        .fold(0.0, |acc: f64, coeff| acc * x + coeff.get());
+
+    // # Safety
+    // `x` and the coefficients are finite. While `acc` is finite, `acc * x` is finite or infinite, never `NaN`.
+    // Once `acc` is infinite, `x` is not zero (otherwise `acc * x` would have stayed finite),
+    // so `acc * x` is infinite and adding a finite coefficient keeps it infinite.
+    unsafe { NonNaN::<f64>::new_unchecked(y) }
+}
@@ -0,0 +1,33 @@
+/// The kinds of values a float can take.
+///
+/// Each of the 12 types accepts exactly the values of its `POSSIBILITIES` constant
+/// (e.g. [`NonNaN::POSSIBILITIES`](crate::NonNaN::POSSIBILITIES)), and [`result_possibilities`] gives the ones of the result of an operation.
+///
+/// # Examples
+///
+/// ```
+/// # use typed_floats::*;
+/// let possibilities = StrictlyPositiveFinite::<f64>::POSSIBILITIES;
+///
+/// assert!(!possibilities.accept_nan);
+/// assert!(!possibilities.accept_inf);
+/// assert!(!possibilities.accept_zero);
+/// assert!(possibilities.accept_positive);
+/// assert!(!possibilities.accept_negative);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(clippy::struct_excessive_bools)] // Each field is an independent possibility
+pub struct FloatPossibilities {
+    /// `NaN` is possible
+    pub accept_nan: bool,
+    /// `+inf` is possible if `accept_positive` is `true`, `-inf` if `accept_negative` is `true`
+    pub accept_inf: bool,
+    /// `+0.0` is possible if `accept_positive` is `true`, `-0.0` if `accept_negative` is `true`
+    pub accept_zero: bool,
+    /// Values with a positive sign are possible
+    pub accept_positive: bool,
+    /// Values with a negative sign are possible
+    pub accept_negative: bool,
+}
+
+typed_floats_macros::generate_result_possibilities!();
@@ -0,0 +1,55 @@
+//! The exponents of [`PowiConst::powi_const()`](crate::PowiConst::powi_const), classified at compile time.
+//!
+//! The possible results of `x.powi(n)` depend on whether `n` is zero, even or odd, and on its sign,
+//! so each [`Exponent`] is mapped to the marker type of its class by [`ExponentClass`],
+//! which selects the result type of `powi_const`.
+//! It is implemented for the exponents from `-64` to `64`.
+//!
+//! # Examples
+//!
+//! ```
+//! use typed_floats::powi::{Exponent, ExponentClass, PositiveEven};
+//!
+//! fn is_positive_even<const N: i32>()
+//! where
+//!     Exponent<N>: ExponentClass<Class = PositiveEven>,
+//! {
+//! }
+//!
+//! is_positive_even::<2>();
+//! is_positive_even::<64>();
+//! ```
+
+/// An exponent known at compile time.
+#[derive(Debug, Clone, Copy)]
+pub struct Exponent<const N: i32>;
+
+/// Maps an [`Exponent`] to the marker type of its class.
+pub trait ExponentClass {
+    /// One of [`Zero`], [`PositiveEven`], [`PositiveOdd`], [`NegativeEven`] and [`NegativeOdd`].
+    type Class;
+}
+
+/// The class of `0`: `x.powi(0)` is `1.0`, even for zero and the infinities.
+#[derive(Debug, Clone, Copy)]
+pub struct Zero;
+
+/// The class of the positive even exponents: the result is never negative, `-0.0` included.
+#[derive(Debug, Clone, Copy)]
+pub struct PositiveEven;
+
+/// The class of the positive odd exponents: the result keeps the sign of the base.
+#[derive(Debug, Clone, Copy)]
+pub struct PositiveOdd;
+
+/// The class of the negative even exponents: the result is never negative, `-0.0` included.
+/// The zeros become infinities and the infinities become zeros.
+#[derive(Debug, Clone, Copy)]
+pub struct NegativeEven;
+
+/// The class of the negative odd exponents: the result keeps the sign of the base.
+/// The zeros become infinities and the infinities become zeros.
+#[derive(Debug, Clone, Copy)]
+pub struct NegativeOdd;
+
+typed_floats_macros::generate_exponent_classes!();
@@ -0,0 +1,227 @@
+use core::marker::PhantomData;
+
+use crate::{
+    Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN, NonZeroNonNaNFinite, Positive,
+    PositiveFinite, StrictlyNegative, StrictlyNegativeFinite, StrictlyPositive,
+    StrictlyPositiveFinite,
+};
+
+// Every float that is not `NaN` is mapped to a key, in ascending order:
+// `0` is `-inf`, `INF_BITS` is `-0.0`, `INF_BITS + 1` is `+0.0` and `2 * INF_BITS + 1` is `+inf`.
+// The valid values of each type are the keys between its smallest and its largest value,
+// except the zeros that may be rejected in the middle, so only them have to be skipped.
+
+/// An iterator over every valid value of a type, in ascending order, created by [`representable_range`].
+///
+/// `-0.0` is yielded before `+0.0` when both are valid.
+///
+/// # Examples
+///
+/// ```
+/// # use typed_floats::*;
+/// let mut values = representable_range::<StrictlyNegativeFinite<f32>>();
+///
+/// assert_eq!(values.next(), Some(tf32::MIN));
+/// assert_eq!(values.next_back().unwrap(), -f32::from_bits(1));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RepresentableRange<T> {
+    /// The key of the next value of `next`, included
+    front: u64,
+    /// The key after the next value of `next_back`, excluded, or `u64::MAX` before the first call
+    back: u64,
+    marker: PhantomData<T>,
+}
+
+/// Returns an iterator over every valid value of `T`, in ascending order.
+///
+/// It can be used to test a function with every possible `f32` value of a type.
+///
+/// # Examples
+///
+/// ```
+/// # use typed_floats::*;
+/// let mut values = representable_range::<StrictlyPositiveFinite<f32>>();
+///
+/// // The smallest subnormal number
+/// assert_eq!(values.next(), Some(StrictlyPositiveFinite::<f32>::new(f32::from_bits(1)).unwrap()));
+/// assert_eq!(values.next_back(), Some(tf32::MAX));
+///
+/// // The zeros are skipped, and `nth` doesn't iterate over the values
+/// let mut values = representable_range::<NonZeroNonNaN<f32>>();
+///
+/// assert_eq!(values.nth(0x7f80_0000), Some(NonZeroNonNaN::<f32>::new(f32::from_bits(1)).unwrap()));
+/// ```
+#[inline]
+#[must_use]
+pub const fn representable_range<T>() -> RepresentableRange<T> {
+    RepresentableRange {
+        front: 0,
+        back: u64::MAX,
+        marker: PhantomData,
+    }
+}
+
+macro_rules! impl_representable_range {
+    ($type:ident, $float:ident, $bits:ident, $sign_mask:expr, $inf_bits:expr) => {
+        impl RepresentableRange<$type<$float>> {
+            const INF_BITS: u64 = $inf_bits;
+
+            #[inline]
+            fn value(key: u64) -> $float {
+                let bits = if key <= Self::INF_BITS {
+                    $sign_mask | (Self::INF_BITS - key)
+                } else {
+                    key - Self::INF_BITS - 1
+                };
+
+                // The key is at most `2 * INF_BITS + 1`, so the bits fit
+                #[allow(clippy::cast_possible_truncation)]
+                $float::from_bits(bits as $bits)
+            }
+
+            #[inline]
+            fn is_valid(key: u64) -> bool {
+                $type::<$float>::new(Self::value(key)).is_ok()
+            }
+
+            /// Restricts the keys to the ones between the smallest and the largest valid values.
+            #[inline]
+            fn clamp(&mut self) {
+                if self.back != u64::MAX {
+                    // Already done
+                    return;
+                }
+
+                let inf = Self::INF_BITS;
+
+                let first = [0, 1, inf, inf + 1, inf + 2]
+                    .into_iter()
+                    .find(|&key| Self::is_valid(key))
+                    .unwrap_or(0);
+                let last = [2 * inf + 1, 2 * inf, inf + 1, inf, inf - 1]
+                    .into_iter()
+                    .find(|&key| Self::is_valid(key))
+                    .unwrap_or(2 * inf + 1);
+
+                self.front = self.front.max(first);
+                self.back = self.back.min(last + 1);
+            }
+
+            /// Returns the number of invalid keys in `[start, end)`, which can only be the zeros.
+            #[inline]
+            fn invalid_between(start: u64, end: u64) -> u64 {
+                let inf = Self::INF_BITS;
+
+                [inf, inf + 1]
+                    .into_iter()
+                    .filter(|&key| start <= key && key < end && !Self::is_valid(key))
+                    .count() as u64
+            }
+        }
+
+        impl Iterator for RepresentableRange<$type<$float>> {
+            type Item = $type<$float>;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.clamp();
+
+                while self.front < self.back {
+                    let key = self.front;
+                    self.front += 1;
+
+                    if let Ok(value) = $type::<$float>::new(Self::value(key)) {
+                        return Some(value);
+                    }
+                }
+
+                None
+            }
+
+            #[inline]
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                self.clamp();
+
+                let mut n = n as u64;
+
+                while n > 0 && self.front < self.back {
+                    let end = self.front.saturating_add(n).min(self.back);
+                    let invalid = Self::invalid_between(self.front, end);
+
+                    n -= end - self.front - invalid;
+                    self.front = end;
+                }
+
+                self.next()
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let mut range = self.clone();
+                range.clamp();
+
+                let len = range.back.saturating_sub(range.front);
+
+                // At most the two zeros are skipped
+                let lower = usize::try_from(len.saturating_sub(2)).unwrap_or(usize::MAX);
+                let upper = usize::try_from(len).ok();
+
+                (lower, upper)
+            }
+        }
+
+        impl DoubleEndedIterator for RepresentableRange<$type<$float>> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.clamp();
+
+                while self.front < self.back {
+                    self.back -= 1;
+
+                    if let Ok(value) = $type::<$float>::new(Self::value(self.back)) {
+                        return Some(value);
+                    }
+                }
+
+                None
+            }
+
+            #[inline]
+            fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+                self.clamp();
+
+                let mut n = n as u64;
+
+                while n > 0 && self.front < self.back {
+                    let start = self.back.saturating_sub(n).max(self.front);
+                    let invalid = Self::invalid_between(start, self.back);
+
+                    n -= self.back - start - invalid;
+                    self.back = start;
+                }
+
+                self.next_back()
+            }
+        }
+
+        impl core::iter::FusedIterator for RepresentableRange<$type<$float>> {}
+    };
+    ($type:ident) => {
+        impl_representable_range!($type, f32, u32, 1 << 31, 0x7f80_0000);
+        impl_representable_range!($type, f64, u64, 1 << 63, 0x7ff0_0000_0000_0000);
+    };
+}
+
+impl_representable_range!(NonNaN);
+impl_representable_range!(NonNaNFinite);
+impl_representable_range!(NonZeroNonNaN);
+impl_representable_range!(NonZeroNonNaNFinite);
+impl_representable_range!(StrictlyPositive);
+impl_representable_range!(StrictlyNegative);
+impl_representable_range!(Positive);
+impl_representable_range!(Negative);
+impl_representable_range!(StrictlyPositiveFinite);
+impl_representable_range!(StrictlyNegativeFinite);
+impl_representable_range!(PositiveFinite);
+impl_representable_range!(NegativeFinite);
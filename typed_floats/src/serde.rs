@@ -1,4 +1,4 @@
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::types::{
     Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN, NonZeroNonNaNFinite, Positive,
@@ -6,8 +6,29 @@ use crate::types::{
     StrictlyPositiveFinite,
 };
 
-macro_rules! impl_deserialize {
-    ($type:ident) => {
+macro_rules! impl_serde {
+    ($type:ident, $expected:literal) => {
+        // Serialized as the inner number, without any wrapper
+        impl Serialize for $type<f64> {
+            #[inline]
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.serialize_f64(self.get())
+            }
+        }
+
+        impl Serialize for $type<f32> {
+            #[inline]
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.serialize_f32(self.get())
+            }
+        }
+
         impl<'de> Deserialize<'de> for $type<f64> {
             fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
             where
@@ -15,7 +36,12 @@ macro_rules! impl_deserialize {
             {
                 let val: f64 = Deserialize::deserialize(deserializer)?;
 
-                val.try_into().map_err(serde::de::Error::custom)
+                Self::new(val).map_err(|err| {
+                    serde::de::Error::custom(format_args!(
+                        "expected {}, got {val:?} ({err})",
+                        $expected
+                    ))
+                })
             }
         }
 
@@ -26,21 +52,26 @@ macro_rules! impl_deserialize {
             {
                 let val: f32 = Deserialize::deserialize(deserializer)?;
 
-                val.try_into().map_err(serde::de::Error::custom)
+                Self::new(val).map_err(|err| {
+                    serde::de::Error::custom(format_args!(
+                        "expected {}, got {val:?} ({err})",
+                        $expected
+                    ))
+                })
             }
         }
     };
 }
 
-impl_deserialize!(NonNaN);
-impl_deserialize!(NonZeroNonNaN);
-impl_deserialize!(NonNaNFinite);
-impl_deserialize!(NonZeroNonNaNFinite);
-impl_deserialize!(Positive);
-impl_deserialize!(Negative);
-impl_deserialize!(PositiveFinite);
-impl_deserialize!(NegativeFinite);
-impl_deserialize!(StrictlyPositive);
-impl_deserialize!(StrictlyNegative);
-impl_deserialize!(StrictlyPositiveFinite);
-impl_deserialize!(StrictlyNegativeFinite);
+impl_serde!(NonNaN, "a non-NaN number");
+impl_serde!(NonZeroNonNaN, "a non-zero and non-NaN number");
+impl_serde!(NonNaNFinite, "a finite number");
+impl_serde!(NonZeroNonNaNFinite, "a finite non-zero number");
+impl_serde!(Positive, "a positive number");
+impl_serde!(Negative, "a negative number");
+impl_serde!(PositiveFinite, "a finite positive number");
+impl_serde!(NegativeFinite, "a finite negative number");
+impl_serde!(StrictlyPositive, "a strictly positive number");
+impl_serde!(StrictlyNegative, "a strictly negative number");
+impl_serde!(StrictlyPositiveFinite, "a finite strictly positive number");
+impl_serde!(StrictlyNegativeFinite, "a finite strictly negative number");
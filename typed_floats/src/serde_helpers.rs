@@ -0,0 +1,190 @@
+//! Helpers to use with `#[serde(with = "...")]`.
+//!
+//! This module is not named `serde` to avoid conflicts with the `serde` crate
+//! when using `use typed_floats::*;`.
+
+/// Serialize a typed float as a string, and deserialize it by parsing then validating the string.
+///
+/// Useful for formats that would lose precision with numbers.
+///
+/// # Examples
+///
+/// ```
+/// use typed_floats::*;
+///
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Price {
+///     #[serde(with = "typed_floats::serde_helpers::as_string")]
+///     value: StrictlyPositiveFinite,
+/// }
+///
+/// let price = Price { value: tf64::MAX };
+///
+/// let json = serde_json::to_string(&price).unwrap();
+/// assert_eq!(json, r#"{"value":"179769313486231570000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"}"#);
+///
+/// let price: Price = serde_json::from_str(&json).unwrap();
+/// assert_eq!(price.value, f64::MAX);
+///
+/// assert!(serde_json::from_str::<Price>(r#"{"value":"-1.0"}"#).is_err());
+/// assert!(serde_json::from_str::<Price>(r#"{"value":1.0}"#).is_err());
+/// ```
+pub mod as_string {
+    use core::fmt::Display;
+    use core::marker::PhantomData;
+    use core::str::FromStr;
+
+    use serde::{Deserializer, Serializer};
+
+    /// Serialize `value` as a string, without loss of precision.
+    ///
+    /// # Errors
+    /// Returns an error if the serializer fails
+    #[inline]
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Display,
+        S: Serializer,
+    {
+        serializer.collect_str(value)
+    }
+
+    /// Deserialize a string then parse and validate it.
+    ///
+    /// # Errors
+    /// Returns an error if the value is not a string, can't be parsed, or is not valid for `T`.
+    #[inline]
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(StrVisitor(PhantomData))
+    }
+
+    struct StrVisitor<T>(PhantomData<T>);
+
+    impl<T> serde::de::Visitor<'_> for StrVisitor<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+            formatter.write_str("a string containing a number")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<T, E>
+        where
+            E: serde::de::Error,
+        {
+            value
+                .parse()
+                .map_err(|err| E::custom(format_args!("invalid number {value:?} ({err})")))
+        }
+    }
+}
+
+/// Like [`as_string`] for an `Option` of a typed float, `None` being serialized as `null`.
+///
+/// # Examples
+///
+/// ```
+/// use typed_floats::*;
+///
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Price {
+///     #[serde(with = "typed_floats::serde_helpers::as_string_option")]
+///     value: Option<PositiveFinite>,
+/// }
+///
+/// let price: Price = serde_json::from_str(r#"{"value":"1.5"}"#).unwrap();
+/// assert_eq!(price.value.unwrap(), 1.5);
+///
+/// let price: Price = serde_json::from_str(r#"{"value":null}"#).unwrap();
+/// assert!(price.value.is_none());
+/// assert_eq!(serde_json::to_string(&price).unwrap(), r#"{"value":null}"#);
+/// ```
+pub mod as_string_option {
+    use core::fmt::Display;
+    use core::marker::PhantomData;
+    use core::str::FromStr;
+
+    use serde::{Deserializer, Serialize, Serializer};
+
+    struct AsString<'a, T>(&'a T);
+
+    impl<T: Display> Serialize for AsString<'_, T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::as_string::serialize(self.0, serializer)
+        }
+    }
+
+    /// Serialize `Some(value)` as a string and `None` as `null`.
+    ///
+    /// # Errors
+    /// Returns an error if the serializer fails
+    #[inline]
+    #[allow(clippy::ref_option)] // Required by `#[serde(with = "...")]`
+    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Display,
+        S: Serializer,
+    {
+        match value {
+            Some(value) => serializer.serialize_some(&AsString(value)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize `null` as `None`, or a string then parse and validate it.
+    ///
+    /// # Errors
+    /// Returns an error if the value is neither `null` nor a string, can't be parsed, or is not valid for `T`.
+    #[inline]
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_option(OptionVisitor(PhantomData))
+    }
+
+    struct OptionVisitor<T>(PhantomData<T>);
+
+    impl<'de, T> serde::de::Visitor<'de> for OptionVisitor<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        type Value = Option<T>;
+
+        fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+            formatter.write_str("null or a string containing a number")
+        }
+
+        fn visit_none<E>(self) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            Ok(None)
+        }
+
+        fn visit_unit<E>(self) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            Ok(None)
+        }
+
+        fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            super::as_string::deserialize(deserializer).map(Some)
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::InvalidNumber;
+
 #[cfg(any(feature = "std", feature = "libm"))]
 /// This trait is used to specify the return type of the [`Hypot::hypot()`] function.
 pub trait Hypot<T> {
@@ -9,6 +11,9 @@ pub trait Hypot<T> {
     /// right-angle triangle with other sides having length `x.abs()` and
     /// `y.abs()`.
     ///
+    /// The result is never negative, and it is zero only if both numbers are zero.
+    /// It may overflow to infinity even if both numbers are finite.
+    ///
     /// # Examples
     ///
     /// ```
@@ -17,6 +22,14 @@ pub trait Hypot<T> {
     /// let y: NonNaN = 4.0.try_into().unwrap();
     ///
     /// assert_eq!(x.hypot(y), 5.0);
+    ///
+    /// let a: StrictlyNegativeFinite = (-3.0).try_into().unwrap();
+    /// let b: PositiveFinite = 0.0.try_into().unwrap();
+    ///
+    /// let c: StrictlyPositive = a.hypot(b);
+    /// assert_eq!(c, 3.0);
+    ///
+    /// assert_eq!(tf64::MAX.hypot(tf64::MAX), f64::INFINITY);
     /// ```
     ///
     /// See [`f64::hypot()`] for more details.
@@ -46,6 +59,22 @@ pub trait Min<T> {
     /// assert_eq!(Min::min(x, y), 3.0);
     /// ```
     ///
+    /// As neither operand can be `NaN`, the result is never `NaN`
+    /// and its type is as strict as both types allow:
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let a: Negative = (-0.0).try_into().unwrap();
+    /// let b: StrictlyPositive = 1.0.try_into().unwrap();
+    ///
+    /// let c: Negative = Min::min(a, b);
+    /// assert_is_negative_zero!(c);
+    ///
+    /// let d: StrictlyNegativeFinite = (-1.0).try_into().unwrap();
+    /// let e: StrictlyNegativeFinite = Min::min(d, b);
+    /// assert_eq!(e, -1.0);
+    /// ```
+    ///
     /// See [`f64::min()`] for more details.
     fn min(self, rhs: T) -> Self::Output;
 }
@@ -73,10 +102,240 @@ pub trait Max<T> {
     /// assert_eq!(Max::max(x, y), 4.0);
     /// ```
     ///
+    /// As neither operand can be `NaN`, the result is never `NaN`
+    /// and its type is as strict as both types allow:
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let a: StrictlyPositive = 1.0.try_into().unwrap();
+    /// let b: Positive = 0.0.try_into().unwrap();
+    ///
+    /// let c: StrictlyPositive = Max::max(a, b);
+    /// assert_eq!(c, 1.0);
+    ///
+    /// let d: PositiveFinite = 0.0.try_into().unwrap();
+    /// let e: StrictlyNegativeFinite = (-1.0).try_into().unwrap();
+    ///
+    /// let f: PositiveFinite = Max::max(d, e);
+    /// assert_eq!(f, 0.0);
+    /// ```
+    ///
     /// See [`f64::max()`] for more details.
     fn max(self, rhs: T) -> Self::Output;
 }
 
+/// This trait is used to specify the return type of the [`TotalMin::total_min()`] function.
+pub trait TotalMin<T> {
+    /// The resulting type after applying [`TotalMin::total_min()`].
+    type Output;
+
+    /// Returns the minimum of the two numbers, using the total ordering of [`f64::total_cmp()`].
+    ///
+    /// Unlike [`Min::min()`], `-0.0` is less than `+0.0`, so the result is deterministic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let x: NonNaN = 3.0.try_into().unwrap();
+    /// let y: NonNaN = 4.0.try_into().unwrap();
+    ///
+    /// assert_eq!(TotalMin::total_min(x, y), 3.0);
+    ///
+    /// let a: Negative = (-0.0).try_into().unwrap();
+    /// let b: Positive = 0.0.try_into().unwrap();
+    ///
+    /// let c: Negative = TotalMin::total_min(a, b);
+    /// assert_is_negative_zero!(c);
+    /// ```
+    fn total_min(self, rhs: T) -> Self::Output;
+}
+
+/// This trait is used to specify the return type of the [`TotalMax::total_max()`] function.
+pub trait TotalMax<T> {
+    /// The resulting type after applying [`TotalMax::total_max()`].
+    type Output;
+
+    /// Returns the maximum of the two numbers, using the total ordering of [`f64::total_cmp()`].
+    ///
+    /// Unlike [`Max::max()`], `+0.0` is greater than `-0.0`, so the result is deterministic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let x: NonNaN = 3.0.try_into().unwrap();
+    /// let y: NonNaN = 4.0.try_into().unwrap();
+    ///
+    /// assert_eq!(TotalMax::total_max(x, y), 4.0);
+    ///
+    /// let a: Negative = (-0.0).try_into().unwrap();
+    /// let b: Positive = 0.0.try_into().unwrap();
+    ///
+    /// let c: Positive = TotalMax::total_max(a, b);
+    /// assert_is_positive_zero!(c);
+    /// ```
+    fn total_max(self, rhs: T) -> Self::Output;
+}
+
+/// This trait is used to specify the return type of the [`Midpoint::midpoint()`] function.
+pub trait Midpoint<T> {
+    /// The resulting type after applying [`Midpoint::midpoint()`].
+    type Output;
+
+    /// Calculates the middle point of `self` and `rhs`.
+    ///
+    /// This returns `NaN` only if a combination of `+inf` and `-inf` is provided as arguments.
+    /// Unlike `(self + rhs) / 2.0`, the result can't overflow: the midpoint of two finite
+    /// numbers is finite, and it is correctly rounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let a: StrictlyPositiveFinite = 1.0.try_into().unwrap();
+    /// let b: StrictlyPositiveFinite = 4.0.try_into().unwrap();
+    ///
+    /// let c: StrictlyPositiveFinite = a.midpoint(b);
+    /// assert_eq!(c, 2.5);
+    ///
+    /// assert_eq!(tf64::MAX.midpoint(tf64::MAX), f64::MAX);
+    /// assert_is_nan!(tf64::INFINITY.midpoint(tf64::NEG_INFINITY));
+    /// ```
+    ///
+    /// See [`f64::midpoint()`](https://doc.rust-lang.org/std/primitive.f64.html#method.midpoint) for more details.
+    fn midpoint(self, rhs: T) -> Self::Output;
+}
+
+/// This trait is used to specify the return type of the [`Lerp::lerp()`] function.
+pub trait Lerp<T, F = f64> {
+    /// The resulting type after applying [`Lerp::lerp()`].
+    type Output;
+
+    /// Linear interpolation between `self` (at `t = 0`) and `rhs` (at `t = 1`).
+    ///
+    /// It is computed as `self + (rhs - self) * t`, and the endpoints are returned exactly
+    /// at `t = 0` and `t = 1`. The result is clamped between the endpoints, so it accepts
+    /// the values of both: the interpolation between two positive finite numbers is positive and finite.
+    /// It may be zero if the endpoints have opposite signs, and it is `NaN` only
+    /// between two infinities of opposite signs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let a: StrictlyPositiveFinite = 2.0.try_into().unwrap();
+    /// let b: StrictlyPositiveFinite = 4.0.try_into().unwrap();
+    /// let t: UnitInterval = 0.25.try_into().unwrap();
+    ///
+    /// let c: StrictlyPositiveFinite = a.lerp(b, t);
+    /// assert_eq!(c, 2.5);
+    ///
+    /// assert_eq!(a.lerp(b, UnitInterval::<f64>::ZERO), a);
+    /// assert_eq!(a.lerp(b, UnitInterval::<f64>::ONE), b);
+    ///
+    /// // Unlike `MIN + (MAX - MIN) * 0.5`, it doesn't overflow
+    /// let half: UnitInterval = 0.5.try_into().unwrap();
+    /// let c: NonNaNFinite = tf64::MIN.lerp(tf64::MAX, half);
+    /// assert_eq!(c, 0.0);
+    /// ```
+    fn lerp(self, rhs: T, t: crate::UnitInterval<F>) -> Self::Output;
+}
+
+/// This trait is used to specify the return type of the [`PositiveDiff::positive_diff()`] function.
+pub trait PositiveDiff<T> {
+    /// The resulting type after applying [`PositiveDiff::positive_diff()`].
+    type Output;
+
+    /// Returns the positive difference of `self` and `rhs`: `self - rhs` if it is positive, `+0.0` otherwise.
+    ///
+    /// It is the `dim` function of Fortran. The result is never negative nor `NaN`:
+    /// the difference of two infinity of the same sign is `+0.0`.
+    /// It can overflow to infinity if `self` is positive and `rhs` negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let a: PositiveFinite = 3.0.try_into().unwrap();
+    /// let b: PositiveFinite = 5.0.try_into().unwrap();
+    ///
+    /// let c: PositiveFinite = a.positive_diff(b);
+    /// assert_is_positive_zero!(c);
+    /// assert_eq!(b.positive_diff(a), 2.0);
+    ///
+    /// assert_eq!(tf64::MAX.positive_diff(tf64::MIN), f64::INFINITY);
+    /// assert_is_positive_zero!(tf64::INFINITY.positive_diff(tf64::INFINITY));
+    /// ```
+    fn positive_diff(self, rhs: T) -> Self::Output;
+}
+
+/// This trait is used to specify the return type of the [`AbsDiff::abs_diff()`] function.
+pub trait AbsDiff<T> {
+    /// The resulting type after applying [`AbsDiff::abs_diff()`].
+    type Output;
+
+    /// Returns the absolute difference of `self` and `rhs`: `|self - rhs|`.
+    ///
+    /// The result is never negative, and it is `NaN` only for two infinity of the same sign.
+    /// It can overflow to infinity if the signs are different, so two finite numbers of the same sign
+    /// give a finite result, but not two [`NonNaNFinite`](crate::NonNaNFinite).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let a: PositiveFinite = 3.0.try_into().unwrap();
+    /// let b: PositiveFinite = 7.0.try_into().unwrap();
+    ///
+    /// let c: PositiveFinite = a.abs_diff(b);
+    /// assert_eq!(c, 4.0);
+    /// assert_eq!(b.abs_diff(a), 4.0);
+    ///
+    /// assert_eq!(tf64::MAX.abs_diff(tf64::MIN), f64::INFINITY);
+    /// assert!(tf64::INFINITY.abs_diff(tf64::INFINITY).is_nan());
+    /// ```
+    fn abs_diff(self, rhs: T) -> Self::Output;
+}
+
+/// This trait is used to specify the return type of the [`Clamp::clamp()`] function.
+pub trait Clamp<Min, Max> {
+    /// The resulting type after applying [`Clamp::clamp()`].
+    type Output;
+
+    /// Restrict a value to a certain interval.
+    ///
+    /// Returns `max` if `self` is greater than `max`, and `min` if `self` is less than `min`.
+    /// Otherwise this returns `self`.
+    ///
+    /// As the comparisons are done by value, `self` is returned if it is a zero
+    /// and a bound is a zero of the other sign: `(-0.0).clamp(0.0, 1.0)` is `-0.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let min: Positive = 0.0.try_into().unwrap();
+    /// let max: StrictlyPositiveFinite = 1.0.try_into().unwrap();
+    ///
+    /// let x: Positive = tf64::INFINITY.into();
+    /// let y: PositiveFinite = Clamp::clamp(x, min, max);
+    /// assert_eq!(y, 1.0);
+    ///
+    /// let x: NonNaN = (-2.0).try_into().unwrap();
+    /// // May be `-0.0`
+    /// let y: NonNaNFinite = Clamp::clamp(x, min, max);
+    /// assert_eq!(y, 0.0);
+    /// ```
+    ///
+    /// See [`f64::clamp()`] for more details.
+    fn clamp(self, min: Min, max: Max) -> Self::Output;
+}
+
 #[cfg(any(feature = "std", feature = "libm"))]
 /// This trait is used to specify the return type of the [`Copysign::copysign()`] function.
 pub trait Copysign<T> {
@@ -108,6 +367,21 @@ pub trait Copysign<T> {
     /// assert_eq!(y.copysign(d), -3.5);
     /// ```
     ///
+    /// The result keeps the guarantees on the magnitude of `self` (zero, infinity)
+    /// but its sign is the one of `rhs`. As neither operand can be `NaN`, the result can't either.
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let magnitude: StrictlyPositiveFinite = 2.0.try_into().unwrap();
+    /// let sign: NonNaN = (-1.0).try_into().unwrap();
+    ///
+    /// let x: NonZeroNonNaNFinite = magnitude.copysign(sign);
+    /// assert_eq!(x, -2.0);
+    ///
+    /// assert_is_negative_zero!(tf64::ZERO.copysign(sign));
+    /// assert_eq!(tf64::INFINITY.copysign(sign), f64::NEG_INFINITY);
+    /// ```
+    ///
     /// See [`f64::copysign()`] for more details.
     fn copysign(self, rhs: T) -> Self::Output;
 }
@@ -143,6 +417,46 @@ pub trait DivEuclid<T> {
     fn div_euclid(self, rhs: T) -> Self::Output;
 }
 
+#[cfg(any(feature = "std", feature = "libm"))]
+/// This trait is used to specify the return type of the [`RemEuclid::rem_euclid()`] function.
+pub trait RemEuclid<T> {
+    /// The resulting type after applying [`RemEuclid::rem_euclid()`].
+    type Output;
+
+    /// Calculates the least nonnegative remainder of `self (mod rhs)`.
+    ///
+    /// In particular, the return value `r` satisfies `0.0 <= r < rhs.abs()` in
+    /// most cases. However, due to a floating point round-off error it can
+    /// result in `r == rhs.abs()`, violating the mathematical definition, if
+    /// `self` is much smaller than `rhs.abs()` in magnitude and `self < 0.0`.
+    ///
+    /// As with [`f64::rem_euclid()`], the result is `-0.0` if `self` is `-0.0`
+    /// or a negative multiple of `rhs`, so it is only [`Positive`](crate::Positive)
+    /// if `self` can't be negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let a: NonNaNFinite = 7.0.try_into().unwrap();
+    /// let b: StrictlyPositiveFinite = 4.0.try_into().unwrap();
+    ///
+    /// assert_eq!(a.rem_euclid(b), 3.0);
+    /// assert_eq!((-a).rem_euclid(b), 1.0);
+    ///
+    /// let c: StrictlyPositiveFinite = 7.0.try_into().unwrap();
+    /// let d: PositiveFinite = c.rem_euclid(b);
+    /// assert_eq!(d, 3.0);
+    ///
+    /// let e: StrictlyNegativeFinite = (-6.0).try_into().unwrap();
+    /// let f: StrictlyPositiveFinite = 3.0.try_into().unwrap();
+    /// assert_is_negative_zero!(e.rem_euclid(f));
+    /// ```
+    ///
+    /// See [`f64::rem_euclid()`] for more details.
+    fn rem_euclid(self, rhs: T) -> Self::Output;
+}
+
 #[cfg(any(feature = "std", feature = "libm"))]
 /// This trait is used to specify the return type of the [`Atan2::atan2()`] function.
 pub trait Atan2<T> {
@@ -177,6 +491,27 @@ pub trait Atan2<T> {
     /// assert_relative_eq!(y2.atan2(x2), 3.0 * core::f64::consts::FRAC_PI_4);
     /// ```
     ///
+    /// The result is always finite and has the sign of `self`, including for zeros:
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// use core::f64::consts::{FRAC_PI_4, PI};
+    ///
+    /// let y: StrictlyPositive = 1.0.try_into().unwrap();
+    /// let x: StrictlyNegative = (-1.0).try_into().unwrap();
+    ///
+    /// let a: StrictlyPositiveFinite = y.atan2(x);
+    /// assert_relative_eq!(a, 3.0 * FRAC_PI_4);
+    ///
+    /// assert_eq!(tf64::ZERO.atan2(tf64::NEG_ZERO), PI);
+    /// assert_eq!(tf64::NEG_ZERO.atan2(tf64::NEG_ZERO), -PI);
+    /// assert_is_positive_zero!(tf64::ZERO.atan2(tf64::ZERO));
+    /// assert_is_negative_zero!(tf64::NEG_ZERO.atan2(tf64::ZERO));
+    ///
+    /// assert_relative_eq!(tf64::INFINITY.atan2(tf64::INFINITY), FRAC_PI_4);
+    /// assert_relative_eq!(tf64::NEG_INFINITY.atan2(tf64::NEG_INFINITY), -3.0 * FRAC_PI_4);
+    /// ```
+    ///
     /// See [`f64::atan2()`] for more details.
     fn atan2(self, rhs: T) -> Self::Output;
 }
@@ -187,6 +522,581 @@ pub trait Powf<T> {
     /// The resulting type after applying [`Powf::powf()`].
     type Output;
 
+    /// Raises a number to a floating point power.
+    ///
+    /// If the base can't be negative, the result is [`Positive`](crate::Positive):
+    /// zero and infinity can always be reached through underflow and overflow.
+    /// Otherwise, the result may be `NaN` (negative base with a non-integer exponent).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let a: StrictlyPositive = 4.0.try_into().unwrap();
+    /// let b: NonNaN = 0.5.try_into().unwrap();
+    ///
+    /// let c: Positive = a.powf(b);
+    /// assert_eq!(c, 2.0);
+    ///
+    /// let d: NonNaN = (-2.0).try_into().unwrap();
+    /// assert_is_nan!(d.powf(b));
+    ///
+    /// let e: NonNaN = (-1.0).try_into().unwrap();
+    /// assert_eq!(tf64::ZERO.powf(e), f64::INFINITY);
+    /// ```
+    ///
     /// See [`f64::powf()`] for more details.
     fn powf(self, rhs: T) -> Self::Output;
 }
+
+#[cfg(any(feature = "std", feature = "libm"))]
+/// This trait is used to specify the return type of the [`PowiConst::powi_const()`] function,
+/// for each class of exponents (see [`powi`](crate::powi)).
+pub trait PowiConst<C> {
+    /// The resulting type after applying [`PowiConst::powi_const()`].
+    type Output;
+
+    /// Raises a number to an integer power known at compile time.
+    ///
+    /// Unlike `powi`, whose result type must hold for every exponent, the result type
+    /// depends on the class of `N`: the result is always `1.0` for `0`,
+    /// never negative for an even exponent, and keeps the sign of the base for an odd one.
+    /// Any other power can overflow to infinity or be rounded to zero, so both are still possible.
+    ///
+    /// A negative exponent keeps the sign in the same way, but a strict type is not kept:
+    /// `(-2.0).powi(-3)` is strictly negative, but `f64::MIN.powi(-3)` is `-0.0`.
+    ///
+    /// `N` must be between `-64` and `64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let x: NonNaN = (-2.0).try_into().unwrap();
+    ///
+    /// let one: StrictlyPositiveFinite = x.powi_const::<0>();
+    /// let square: Positive = x.powi_const::<2>();
+    /// let cube: NonNaN = x.powi_const::<3>();
+    ///
+    /// assert_eq!(one, 1.0);
+    /// assert_eq!(square, 4.0);
+    /// assert_eq!(cube, -8.0);
+    ///
+    /// let y: StrictlyNegativeFinite = (-2.0).try_into().unwrap();
+    /// let cube: Negative = y.powi_const::<3>();
+    ///
+    /// assert_eq!(cube, -8.0);
+    /// assert_eq!(tf64::MIN.powi_const::<3>(), f64::NEG_INFINITY);
+    ///
+    /// let inverse_cube: Negative = y.powi_const::<-3>();
+    /// let inverse_square: Positive = y.powi_const::<-2>();
+    ///
+    /// assert_eq!(inverse_cube, -0.125);
+    /// assert_eq!(inverse_square, 0.25);
+    /// assert_is_negative_zero!(tf64::MIN.powi_const::<-3>());
+    /// ```
+    ///
+    /// See [`f64::powi()`] for more details.
+    fn powi_const<const N: i32>(self) -> Self::Output
+    where
+        crate::powi::Exponent<N>: crate::powi::ExponentClass<Class = C>;
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+/// This trait is used to specify the return type of the [`Log::log()`] function.
+pub trait Log<T> {
+    /// The resulting type after applying [`Log::log()`].
+    type Output;
+
+    /// Returns the logarithm of the number with respect to an arbitrary base.
+    ///
+    /// The result is `self.ln() / base.ln()`, so it may be `NaN` for every combination of types:
+    /// `1.0.log(1.0)` is `0.0 / 0.0`, and the logarithm of a negative number is `NaN`.
+    ///
+    /// The result might not be correctly rounded owing to implementation details;
+    /// `self.log2()` can produce more accurate results for base 2,
+    /// and `self.log10()` can produce more accurate results for base 10.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let x: StrictlyPositiveFinite = 8.0.try_into().unwrap();
+    /// let base: StrictlyPositiveFinite = 2.0.try_into().unwrap();
+    ///
+    /// assert_eq!(x.log(base), 3.0);
+    ///
+    /// let one: StrictlyPositiveFinite = 1.0.try_into().unwrap();
+    ///
+    /// assert_eq!(x.log(one), f64::INFINITY);
+    /// assert_is_nan!(one.log(one));
+    /// ```
+    ///
+    /// See [`f64::log()`] for more details.
+    fn log(self, base: T) -> Self::Output;
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+/// This trait is used to specify the return type of the [`MulAdd::mul_add()`] function.
+pub trait MulAdd<A, B> {
+    /// The resulting type after applying [`MulAdd::mul_add()`].
+    type Output;
+
+    /// Fused multiply-add. Computes `(self * a) + b` with only one rounding error,
+    /// yielding a more accurate result than an unfused multiply-add.
+    ///
+    /// The result type follows the rules of `(self * a) + b`, except that the product
+    /// isn't rounded: `inf - inf` is only possible if `self` or `a` is infinite.
+    /// The result can still overflow or be rounded to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let m: StrictlyPositiveFinite = 10.0.try_into().unwrap();
+    /// let x: StrictlyPositiveFinite = 4.0.try_into().unwrap();
+    /// let b: StrictlyPositiveFinite = 60.0.try_into().unwrap();
+    ///
+    /// let res: StrictlyPositive = m.mul_add(x, b);
+    /// assert_eq!(res, 100.0);
+    ///
+    /// let zero: PositiveFinite = 0.0.try_into().unwrap();
+    ///
+    /// // The product may be rounded to zero
+    /// let min: StrictlyPositiveFinite = tf64::MIN_POSITIVE.try_into().unwrap();
+    /// let res: Positive = min.mul_add(min, zero);
+    /// assert_eq!(res, 0.0);
+    ///
+    /// let max: StrictlyPositiveFinite = tf64::MAX.try_into().unwrap();
+    /// // Unlike `max * max + -inf`, it is not `NaN`
+    /// let res: NonNaN = max.mul_add(max, tf64::NEG_INFINITY);
+    /// assert_eq!(res, f64::NEG_INFINITY);
+    /// ```
+    ///
+    /// See [`f64::mul_add()`] for more details.
+    fn mul_add(self, a: A, b: B) -> Self::Output;
+}
+
+/// This trait is used to specify the return type of the [`CheckedAdd::checked_add()`] function.
+pub trait CheckedAdd<T> {
+    /// The resulting type after applying [`CheckedAdd::checked_add()`].
+    type Output;
+
+    /// Adds two finite numbers, returning an error instead of an infinite result.
+    ///
+    /// It is only implemented between finite types and the result is always finite.
+    ///
+    /// # Errors
+    /// Returns [`InvalidNumber::Infinite`] if the addition overflows,
+    /// or the reason why the result doesn't fit into the result type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let a: StrictlyPositiveFinite = 1.0.try_into().unwrap();
+    /// let b: PositiveFinite = 2.0.try_into().unwrap();
+    ///
+    /// let c: Result<StrictlyPositiveFinite, InvalidNumber> = a.checked_add(b);
+    /// assert_eq!(c, Ok(3.0.try_into().unwrap()));
+    ///
+    /// assert_eq!(tf64::MAX.checked_add(tf64::MAX), Err(InvalidNumber::Infinite));
+    /// ```
+    fn checked_add(self, rhs: T) -> Result<Self::Output, InvalidNumber>;
+}
+
+/// This trait is used to specify the return type of the [`CheckedSub::checked_sub()`] function.
+pub trait CheckedSub<T> {
+    /// The resulting type after applying [`CheckedSub::checked_sub()`].
+    type Output;
+
+    /// Subtracts two finite numbers, returning an error instead of an infinite result.
+    ///
+    /// It is only implemented between finite types and the result is always finite.
+    ///
+    /// # Errors
+    /// Returns [`InvalidNumber::Infinite`] if the subtraction overflows,
+    /// or the reason why the result doesn't fit into the result type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let a: StrictlyPositiveFinite = 1.0.try_into().unwrap();
+    /// let b: StrictlyNegativeFinite = (-2.0).try_into().unwrap();
+    ///
+    /// let c: Result<StrictlyPositiveFinite, InvalidNumber> = a.checked_sub(b);
+    /// assert_eq!(c, Ok(3.0.try_into().unwrap()));
+    ///
+    /// assert_eq!(tf64::MAX.checked_sub(tf64::MIN), Err(InvalidNumber::Infinite));
+    /// ```
+    fn checked_sub(self, rhs: T) -> Result<Self::Output, InvalidNumber>;
+}
+
+/// This trait is used to specify the return type of the [`CheckedMul::checked_mul()`] function.
+pub trait CheckedMul<T> {
+    /// The resulting type after applying [`CheckedMul::checked_mul()`].
+    type Output;
+
+    /// Multiplies two finite numbers, returning an error instead of an infinite result.
+    ///
+    /// It is only implemented between finite types and the result is always finite.
+    ///
+    /// # Errors
+    /// Returns [`InvalidNumber::Infinite`] if the multiplication overflows,
+    /// or the reason why the result doesn't fit into the result type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let a: StrictlyPositiveFinite = 2.0.try_into().unwrap();
+    /// let b: StrictlyNegativeFinite = (-3.0).try_into().unwrap();
+    ///
+    /// let c: Result<NegativeFinite, InvalidNumber> = a.checked_mul(b);
+    /// assert_eq!(c, Ok((-6.0).try_into().unwrap()));
+    ///
+    /// assert_eq!(tf64::MAX.checked_mul(a), Err(InvalidNumber::Infinite));
+    /// ```
+    fn checked_mul(self, rhs: T) -> Result<Self::Output, InvalidNumber>;
+}
+
+/// This trait is used to specify the return type of the [`CheckedDiv::checked_div()`] function.
+pub trait CheckedDiv<T> {
+    /// The resulting type after applying [`CheckedDiv::checked_div()`].
+    type Output;
+
+    /// Divides two finite numbers, returning an error instead of an infinite or `NaN` result.
+    ///
+    /// It is only implemented between finite types and the result is always finite.
+    ///
+    /// # Errors
+    /// Returns [`InvalidNumber::Infinite`] if the division overflows or if a non-zero
+    /// number is divided by zero, [`InvalidNumber::NaN`] if zero is divided by zero,
+    /// or the reason why the result doesn't fit into the result type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let a: StrictlyPositiveFinite = 6.0.try_into().unwrap();
+    /// let b: StrictlyNegativeFinite = (-3.0).try_into().unwrap();
+    ///
+    /// let c: Result<NegativeFinite, InvalidNumber> = a.checked_div(b);
+    /// assert_eq!(c, Ok((-2.0).try_into().unwrap()));
+    ///
+    /// let zero: PositiveFinite = 0.0.try_into().unwrap();
+    ///
+    /// assert_eq!(a.checked_div(zero), Err(InvalidNumber::Infinite));
+    /// assert_eq!(zero.checked_div(zero), Err(InvalidNumber::NaN));
+    /// ```
+    fn checked_div(self, rhs: T) -> Result<Self::Output, InvalidNumber>;
+}
+
+/// This trait is used to specify the return type of the [`SaturatingAdd::saturating_add()`] function.
+pub trait SaturatingAdd<T> {
+    /// The resulting type after applying [`SaturatingAdd::saturating_add()`].
+    type Output;
+
+    /// Adds two finite numbers, returning `MAX` or `MIN` instead of an infinite result.
+    ///
+    /// It is only implemented between finite types and the result is always finite.
+    /// The result type is the same as [`CheckedAdd::checked_add()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let a: StrictlyPositiveFinite = 1.0.try_into().unwrap();
+    /// let b: PositiveFinite = 2.0.try_into().unwrap();
+    ///
+    /// let c: StrictlyPositiveFinite = a.saturating_add(b);
+    /// assert_eq!(c, 3.0);
+    ///
+    /// assert_eq!(tf64::MAX.saturating_add(tf64::MAX), tf64::MAX);
+    /// assert_eq!(tf64::MIN.saturating_add(tf64::MIN), tf64::MIN);
+    /// ```
+    fn saturating_add(self, rhs: T) -> Self::Output;
+}
+
+/// This trait is used to specify the return type of the [`SaturatingSub::saturating_sub()`] function.
+pub trait SaturatingSub<T> {
+    /// The resulting type after applying [`SaturatingSub::saturating_sub()`].
+    type Output;
+
+    /// Subtracts two finite numbers, returning `MAX` or `MIN` instead of an infinite result.
+    ///
+    /// It is only implemented between finite types and the result is always finite.
+    /// The result type is the same as [`CheckedSub::checked_sub()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let a: StrictlyPositiveFinite = 1.0.try_into().unwrap();
+    /// let b: StrictlyNegativeFinite = (-2.0).try_into().unwrap();
+    ///
+    /// let c: StrictlyPositiveFinite = a.saturating_sub(b);
+    /// assert_eq!(c, 3.0);
+    ///
+    /// assert_eq!(tf64::MAX.saturating_sub(tf64::MIN), tf64::MAX);
+    /// ```
+    fn saturating_sub(self, rhs: T) -> Self::Output;
+}
+
+/// This trait is used to specify the return type of the [`SaturatingMul::saturating_mul()`] function.
+pub trait SaturatingMul<T> {
+    /// The resulting type after applying [`SaturatingMul::saturating_mul()`].
+    type Output;
+
+    /// Multiplies two finite numbers, returning `MAX` or `MIN` instead of an infinite result.
+    ///
+    /// It is only implemented between finite types and the result is always finite.
+    /// The result type is the same as [`CheckedMul::checked_mul()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let gain: StrictlyPositiveFinite = 2.0.try_into().unwrap();
+    /// let sample: NonNaNFinite = (-0.5).try_into().unwrap();
+    ///
+    /// let c: NonNaNFinite = sample.saturating_mul(gain);
+    /// assert_eq!(c, -1.0);
+    ///
+    /// assert_eq!(tf64::MIN.saturating_mul(gain), tf64::MIN);
+    /// ```
+    fn saturating_mul(self, rhs: T) -> Self::Output;
+}
+
+/// This trait is used to compute the distance between two numbers in units in the last place.
+pub trait UlpsBetween<T> {
+    /// Returns the number of representable floats between `self` and `rhs`:
+    /// positive if `rhs` is greater than `self`, negative if it is lower.
+    ///
+    /// `-0.0` and `+0.0` are the same number, so the distance between them is zero.
+    /// The result saturates at [`i64::MAX`] and [`i64::MIN`], which can only be reached
+    /// between two `f64` of opposite signs and huge magnitudes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let x: PositiveFinite = 1.0.try_into().unwrap();
+    /// let y: StrictlyPositiveFinite = (1.0 + f64::EPSILON).try_into().unwrap();
+    ///
+    /// assert_eq!(x.ulps_between(y), 1);
+    /// assert_eq!(y.ulps_between(x), -1);
+    /// assert_eq!(x.ulps_between(x), 0);
+    ///
+    /// assert_eq!(tf64::NEG_ZERO.ulps_between(tf64::ZERO), 0);
+    /// ```
+    fn ulps_between(self, rhs: T) -> i64;
+}
+
+/// This trait gathers the methods common to all the types, to write code generic over them.
+///
+/// # Examples
+///
+/// ```
+/// # use typed_floats::*;
+/// fn describe<T: TypedFloat>(x: T) -> String
+/// where
+///     T::Content: core::fmt::Display,
+/// {
+///     if x.is_finite() {
+///         format!("{}", x.get())
+///     } else {
+///         "inf".to_string()
+///     }
+/// }
+///
+/// let x: StrictlyPositive<f64> = 3.5.try_into().unwrap();
+/// let y: Positive<f32> = f32::INFINITY.try_into().unwrap();
+///
+/// assert_eq!(describe(x), "3.5");
+/// assert_eq!(describe(y), "inf");
+/// ```
+pub trait TypedFloat: Copy + core::fmt::Debug + PartialEq + PartialOrd + Sized {
+    /// The primitive type, `f32` or `f64`.
+    type Content: Copy;
+
+    /// The same type with a `f32`, e.g. `NonNaN<f32>` for `NonNaN<f64>`.
+    type F32: TypedFloat<Content = f32>;
+
+    /// The same type with a `f64`, e.g. `NonNaN<f64>` for `NonNaN<f32>`.
+    type F64: TypedFloat<Content = f64>;
+
+    /// Creates a new value from a primitive type.
+    ///
+    /// # Errors
+    /// Returns an error if the value is not valid
+    fn new(value: Self::Content) -> Result<Self, InvalidNumber>;
+
+    /// Creates a new value from a primitive type, or `None` if the value is not valid.
+    fn try_new(value: Self::Content) -> Option<Self>;
+
+    /// Returns the value as a primitive type.
+    fn get(&self) -> Self::Content;
+
+    /// Returns `true` if this value is `NaN`, which is never the case.
+    fn is_nan(&self) -> bool;
+
+    /// Returns `true` if this value is positive infinity or negative infinity.
+    fn is_infinite(&self) -> bool;
+
+    /// Returns `true` if this number is neither infinite nor `NaN`.
+    fn is_finite(&self) -> bool;
+
+    /// Returns `true` if the number is subnormal.
+    fn is_subnormal(&self) -> bool;
+
+    /// Returns `true` if the number is neither zero, infinite, subnormal, nor `NaN`.
+    fn is_normal(&self) -> bool;
+
+    /// Returns the floating point category of the number.
+    fn classify(&self) -> core::num::FpCategory;
+
+    /// Returns `true` if `self` has a positive sign, including `+0.0` and positive infinity.
+    fn is_sign_positive(&self) -> bool;
+
+    /// Returns `true` if `self` has a negative sign, including `-0.0` and negative infinity.
+    fn is_sign_negative(&self) -> bool;
+
+    /// Returns `true` if the number is `-0.0`.
+    fn is_negative_zero(&self) -> bool;
+
+    /// Returns `true` if the number is `+0.0`.
+    fn is_positive_zero(&self) -> bool;
+
+    /// Returns the ordering between `self` and `other`, with `-0.0 < +0.0`.
+    fn total_cmp(&self, other: &Self) -> core::cmp::Ordering;
+
+    /// Converts the value into the same type with a `f64`, which is always exact.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// fn widen<T: TypedFloat>(x: T) -> T::F64 {
+    ///     x.into_f64()
+    /// }
+    ///
+    /// let x: NonNaN<f32> = 0.1.try_into().unwrap();
+    /// let y: NonNaN<f64> = widen(x);
+    ///
+    /// assert_eq!(y, f64::from(0.1_f32));
+    /// ```
+    fn into_f64(self) -> Self::F64;
+
+    /// Converts the value into the same type with a `f32`, rounded to the nearest `f32`.
+    ///
+    /// # Errors
+    /// Returns an error if the rounded value is not valid, e.g. when a large value overflows to infinity
+    /// for a finite type, or a small one is rounded to zero for a non-zero type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let x: NonNaNFinite<f64> = 1e300.try_into().unwrap();
+    /// let y: NonNaNFinite<f64> = 0.5.try_into().unwrap();
+    ///
+    /// assert_eq!(x.try_into_f32(), Err(InvalidNumber::Infinite));
+    /// assert_eq!(y.try_into_f32(), Ok(NonNaNFinite::<f32>::new(0.5).unwrap()));
+    /// ```
+    fn try_into_f32(self) -> Result<Self::F32, InvalidNumber>;
+}
+
+/// This trait adds methods to the iterators over one of the types.
+pub trait TypedFloatIterator: Iterator {
+    /// Returns the minimum element of the iterator, or `None` if it is empty.
+    ///
+    /// The total ordering is used, so `-0.0` is lower than `+0.0`.
+    /// If several elements are equally minimum, the first one is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let values: Vec<NonNaNFinite> = [3.0, 0.0, -0.0, 5.0]
+    ///     .iter()
+    ///     .map(|&x| x.try_into().unwrap())
+    ///     .collect();
+    ///
+    /// let min = values.iter().copied().typed_min().unwrap();
+    ///
+    /// assert!(min.is_negative_zero());
+    ///
+    /// assert_eq!(Vec::<NonNaNFinite>::new().into_iter().typed_min(), None);
+    /// ```
+    fn typed_min(self) -> Option<Self::Item>;
+
+    /// Returns the maximum element of the iterator, or `None` if it is empty.
+    ///
+    /// The total ordering is used, so `+0.0` is greater than `-0.0`.
+    /// If several elements are equally maximum, the last one is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let values: Vec<NonNaN> = [-3.0, -0.0, 0.0, -5.0]
+    ///     .iter()
+    ///     .map(|&x| x.try_into().unwrap())
+    ///     .collect();
+    ///
+    /// let max = values.iter().copied().typed_max().unwrap();
+    ///
+    /// assert!(max.is_positive_zero());
+    /// ```
+    fn typed_max(self) -> Option<Self::Item>;
+}
+
+/// The iterator returned by [`PrimitiveFloatIterator::filter_valid()`].
+pub type FilterValid<I, T> = core::iter::FilterMap<I, fn(<I as Iterator>::Item) -> Option<T>>;
+
+/// The iterator returned by [`PrimitiveFloatIterator::try_typed()`].
+pub type TryTyped<I, T> = core::iter::Map<I, fn(<I as Iterator>::Item) -> Result<T, InvalidNumber>>;
+
+/// This trait adds methods to the iterators over a primitive type, to convert their elements into one of the types.
+pub trait PrimitiveFloatIterator: Iterator + Sized {
+    /// Converts the elements into `T`, skipping the ones that are not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let values = [3.0, f64::NAN, -1.0, 0.0, f64::INFINITY];
+    ///
+    /// let valid: Vec<PositiveFinite> = values.into_iter().filter_valid().collect();
+    ///
+    /// assert_eq!(valid, [3.0, 0.0]);
+    /// ```
+    fn filter_valid<T>(self) -> FilterValid<Self, T>
+    where
+        T: TypedFloat<Content = Self::Item>;
+
+    /// Converts the elements into `T`, with an error for the ones that are not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::*;
+    /// let values = [3.0, f64::NAN, -1.0];
+    ///
+    /// let results: Vec<Result<PositiveFinite, InvalidNumber>> = values.into_iter().try_typed().collect();
+    ///
+    /// assert_eq!(results[0], Ok(PositiveFinite::<f64>::new(3.0).unwrap()));
+    /// assert_eq!(results[1], Err(InvalidNumber::NaN));
+    /// assert_eq!(results[2], Err(InvalidNumber::Negative));
+    ///
+    /// // The first error stops the collection
+    /// let all: Result<Vec<PositiveFinite>, InvalidNumber> = values.into_iter().try_typed().collect();
+    ///
+    /// assert_eq!(all, Err(InvalidNumber::NaN));
+    /// ```
+    fn try_typed<T>(self) -> TryTyped<Self, T>
+    where
+        T: TypedFloat<Content = Self::Item>;
+}
@@ -154,6 +154,58 @@ macro_rules! accept_positive {
     };
 }
 
+// The methods of the floats, like the comparisons, can't be used in `const fn` with the MSRV,
+// so the checks are done on the bits.
+macro_rules! is_valid {
+    ($type:ident, $bits:expr, $sign_mask:expr, $inf_bits:expr) => {{
+        let bits = $bits;
+        let abs_bits = bits & !$sign_mask;
+        let is_negative = bits & $sign_mask != 0;
+
+        if abs_bits > $inf_bits {
+            // NaN
+            false
+        } else if abs_bits == $inf_bits && !accept_infinity!($type) {
+            false
+        } else if abs_bits == 0 && !accept_zero!($type) {
+            false
+        } else if is_negative {
+            accept_negative!($type)
+        } else {
+            accept_positive!($type)
+        }
+    }};
+}
+
+// `f32::to_bits` and `f64::to_bits` are not `const` with the MSRV
+union F32Bits {
+    float: f32,
+    bits: u32,
+}
+
+union F64Bits {
+    float: f64,
+    bits: u64,
+}
+
+pub const fn f32_to_bits(value: f32) -> u32 {
+    // # Safety
+    // `f32` and `u32` have the same size and any bit pattern is a valid `u32`.
+    unsafe { F32Bits { float: value }.bits }
+}
+
+pub const fn f64_to_bits(value: f64) -> u64 {
+    // # Safety
+    // `f64` and `u64` have the same size and any bit pattern is a valid `u64`.
+    unsafe { F64Bits { float: value }.bits }
+}
+
+pub const fn f64_from_bits(bits: u64) -> f64 {
+    // # Safety
+    // `f64` and `u64` have the same size and any bit pattern is a valid `f64`.
+    unsafe { F64Bits { bits }.float }
+}
+
 macro_rules! accept {
     ($type:ident) => {
         impl $type {
@@ -183,6 +235,14 @@ macro_rules! accept {
         }
 
         impl $type<f32> {
+            /// Returns `true` if `value` is valid for this type.
+            /// Unlike `new`, it can be used in `const` contexts.
+            #[inline]
+            #[must_use]
+            pub(crate) const fn is_valid(value: f32) -> bool {
+                is_valid!($type, f32_to_bits(value), 1 << 31, 0x7f80_0000)
+            }
+
             /// Creates a new value from a primitive type without checking that the value is valid
             ///
             /// # Safety
@@ -196,6 +256,14 @@ macro_rules! accept {
         }
 
         impl $type<f64> {
+            /// Returns `true` if `value` is valid for this type.
+            /// Unlike `new`, it can be used in `const` contexts.
+            #[inline]
+            #[must_use]
+            pub(crate) const fn is_valid(value: f64) -> bool {
+                is_valid!($type, f64_to_bits(value), 1 << 63, 0x7ff0_0000_0000_0000)
+            }
+
             /// Creates a new value from a primitive type without checking that the value is valid
             ///
             /// # Safety
@@ -207,6 +275,26 @@ macro_rules! accept {
                 Self(value)
             }
         }
+
+        #[cfg(feature = "half")]
+        impl $type<half::f16> {
+            /// Returns `true` if `value` is valid for this type.
+            #[inline]
+            #[must_use]
+            pub(crate) const fn is_valid(value: half::f16) -> bool {
+                is_valid!($type, value.to_bits(), 1 << 15, 0x7c00)
+            }
+        }
+
+        #[cfg(feature = "half")]
+        impl $type<half::bf16> {
+            /// Returns `true` if `value` is valid for this type.
+            #[inline]
+            #[must_use]
+            pub(crate) const fn is_valid(value: half::bf16) -> bool {
+                is_valid!($type, value.to_bits(), 1 << 15, 0x7f80)
+            }
+        }
     };
 }
 
@@ -0,0 +1,252 @@
+use crate::types::accept::{f64_from_bits, f64_to_bits};
+use crate::types::{Bounded, InvalidNumber, NonNaN, RangeError};
+
+// The bounds are `f64` for both `f32` and `f64`: the values are converted into `f64`
+// (exactly) to be compared, so an `f32` bound is never rounded.
+// Only the operations that can't leave the range are implemented:
+// to do some other math, convert it into a `NonNaN`.
+
+/// Returns the bits of a bound of [`Bounded`].
+///
+/// Unlike `f64::to_bits`, it can be used in `const` contexts with the MSRV.
+///
+/// # Examples
+///
+/// ```
+/// # use typed_floats::*;
+/// type Probability = Bounded<f64, { bound(0.0) }, { bound(1.0) }>;
+///
+/// assert_eq!(Probability::LO, 0.0);
+/// assert_eq!(Probability::HI, 1.0);
+/// ```
+#[inline]
+#[must_use]
+pub const fn bound(value: f64) -> u64 {
+    f64_to_bits(value)
+}
+
+impl<T, const LO_BITS: u64, const HI_BITS: u64> Bounded<T, LO_BITS, HI_BITS> {
+    /// The lower bound of the range
+    pub const LO: f64 = f64_from_bits(LO_BITS);
+
+    /// The upper bound of the range
+    pub const HI: f64 = f64_from_bits(HI_BITS);
+}
+
+macro_rules! impl_bounded {
+    ($float:ident, $zero_bits:expr, $to_f64:path) => {
+        impl<const LO_BITS: u64, const HI_BITS: u64> Bounded<$float, LO_BITS, HI_BITS> {
+            /// Creates a new value from a primitive type
+            /// It adds a little overhead compared to `new_unchecked`
+            /// because it checks that the value is valid
+            ///
+            /// # Errors
+            /// Returns an error if the value is not valid
+            #[inline]
+            pub fn new(value: $float) -> Result<Self, RangeError> {
+                if value.is_nan() {
+                    Err(InvalidNumber::NaN.into())
+                } else if Self::is_valid(value) {
+                    Ok(Self(value))
+                } else {
+                    Err(RangeError::OutOfRange)
+                }
+            }
+
+            /// Creates a new value from a primitive type with zero overhead (in release mode).
+            /// It is up to the caller to ensure that the value is valid
+            ///
+            /// # Safety
+            /// The caller must ensure that the value is valid.
+            /// It will panic in debug mode if the value is not valid,
+            /// but in release mode the behavior is undefined
+            #[inline]
+            #[must_use]
+            pub unsafe fn new_unchecked(value: $float) -> Self {
+                if !Self::is_valid(value) {
+                    debug_assert!(
+                        false,
+                        concat!("The value is not a valid Bounded<", stringify!($float), ">")
+                    );
+
+                    #[cfg(feature = "ensure_no_undefined_behavior")]
+                    panic!(concat!(
+                        "The value is not a valid Bounded<",
+                        stringify!($float),
+                        ">"
+                    ));
+
+                    #[cfg(all(
+                        feature = "compiler_hints",
+                        not(feature = "ensure_no_undefined_behavior")
+                    ))]
+                    unsafe {
+                        core::hint::unreachable_unchecked()
+                    }
+                }
+
+                Self(value)
+            }
+
+            /// Returns `true` if `value` is valid for this type.
+            #[inline]
+            fn is_valid(value: $float) -> bool {
+                // `NaN` is never in the range
+                let value = $to_f64(value);
+                Self::LO <= value && value <= Self::HI
+            }
+
+            /// Returns the value as a primitive type
+            #[inline]
+            #[must_use]
+            pub const fn get(&self) -> $float {
+                self.0
+            }
+
+            /// Consumes the value and returns it as a primitive type, like `get`
+            #[inline]
+            #[must_use]
+            pub const fn into_inner(self) -> $float {
+                self.0
+            }
+
+            /// Returns the number halfway between `self` and `rhs`.
+            ///
+            /// It is always between `self` and `rhs`, so in the range.
+            /// If `self` and `rhs` are opposite infinities, it returns `0.0`,
+            /// which is then always in the range.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            #[doc = concat!("type Range = Bounded<", stringify!($float), ", { bound(-10.0) }, { bound(10.0) }>;")]
+            ///
+            /// let a = Range::new(-10.0).unwrap();
+            /// let b = Range::new(4.0).unwrap();
+            ///
+            /// assert_eq!(a.midpoint(b), -3.0);
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn midpoint(self, rhs: Self) -> Self {
+                let (a, b) = (self.0, rhs.0);
+                let sum = a + b;
+
+                // The rounding is monotonic, so the result can't be outside of `[a, b]`
+                let value = if sum.is_nan() {
+                    // Opposite infinities, so both bounds are infinite
+                    0.0
+                } else if sum.is_infinite() && a.is_finite() && b.is_finite() {
+                    // Both are large enough to be halved without rounding
+                    (a / 2.0) + (b / 2.0)
+                } else {
+                    sum / 2.0
+                };
+
+                Self(value)
+            }
+        }
+
+        impl<const LO_BITS: u64, const HI_BITS: u64> TryFrom<$float>
+            for Bounded<$float, LO_BITS, HI_BITS>
+        {
+            type Error = RangeError;
+
+            #[inline]
+            fn try_from(value: $float) -> Result<Self, Self::Error> {
+                Self::new(value)
+            }
+        }
+
+        impl<const LO_BITS: u64, const HI_BITS: u64> From<Bounded<$float, LO_BITS, HI_BITS>>
+            for $float
+        {
+            #[inline]
+            fn from(value: Bounded<$float, LO_BITS, HI_BITS>) -> Self {
+                value.0
+            }
+        }
+
+        impl<const LO_BITS: u64, const HI_BITS: u64> From<Bounded<$float, LO_BITS, HI_BITS>>
+            for NonNaN<$float>
+        {
+            #[inline]
+            fn from(value: Bounded<$float, LO_BITS, HI_BITS>) -> Self {
+                // # Safety
+                // `NaN` is never in the range
+                unsafe { Self::new_unchecked(value.0) }
+            }
+        }
+
+        impl<const LO_BITS: u64, const HI_BITS: u64> Eq for Bounded<$float, LO_BITS, HI_BITS> {}
+
+        impl<const LO_BITS: u64, const HI_BITS: u64> PartialEq for Bounded<$float, LO_BITS, HI_BITS> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl<const LO_BITS: u64, const HI_BITS: u64> PartialEq<$float>
+            for Bounded<$float, LO_BITS, HI_BITS>
+        {
+            #[inline]
+            fn eq(&self, other: &$float) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl<const LO_BITS: u64, const HI_BITS: u64> Ord for Bounded<$float, LO_BITS, HI_BITS> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                // The values are never `NaN` and the zeros are equal,
+                // consistently with the other types
+                if self.0 < other.0 {
+                    core::cmp::Ordering::Less
+                } else if self.0 > other.0 {
+                    core::cmp::Ordering::Greater
+                } else {
+                    core::cmp::Ordering::Equal
+                }
+            }
+        }
+
+        impl<const LO_BITS: u64, const HI_BITS: u64> PartialOrd
+            for Bounded<$float, LO_BITS, HI_BITS>
+        {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<const LO_BITS: u64, const HI_BITS: u64> core::hash::Hash
+            for Bounded<$float, LO_BITS, HI_BITS>
+        {
+            #[inline]
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                let bits = if self.0 == 0.0 {
+                    // `+0.0` and `-0.0` are equal to they must have the same hash
+                    $zero_bits
+                } else {
+                    self.0.to_bits()
+                };
+
+                bits.hash(state);
+            }
+        }
+
+        impl<const LO_BITS: u64, const HI_BITS: u64> core::fmt::Display
+            for Bounded<$float, LO_BITS, HI_BITS>
+        {
+            #[inline]
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+    };
+}
+
+impl_bounded!(f32, 0u32, f64::from);
+impl_bounded!(f64, 0u64, core::convert::identity);
@@ -20,8 +20,12 @@ macro_rules! test_type {
 
             for &value in &values {
                 let v: Option<$type> = value.try_into().ok();
+                assert_eq!(<$type>::from_bits(value.to_bits()).ok(), v);
+                assert_eq!(<$type>::is_valid(value), v.is_some());
+                assert_eq!(<$type>::try_new(value), v);
                 if let Some(v) = v {
                     crate::assert_float_eq!(v.get(), value);
+                    assert_eq!(v.to_bits(), value.to_bits());
                     assert_eq!(v.is_nan(), false);
                     assert_eq!(v.is_infinite(), value.is_infinite());
                     assert_eq!(v.is_finite(), value.is_finite());
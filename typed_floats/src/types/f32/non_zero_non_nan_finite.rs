@@ -33,6 +33,31 @@ impl NonZeroNonNaNFinite<f32> {
         Ok(Self(value))
     }
 
+    /// Creates a new value from a primitive type
+    /// Unlike `new`, it can be used in `const` contexts
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::tf32::NonZeroNonNaNFinite;
+    /// const X: NonZeroNonNaNFinite = match NonZeroNonNaNFinite::try_new(3.0) {
+    ///     Some(x) => x,
+    ///     None => panic!(),
+    /// };
+    ///
+    /// assert_eq!(X, 3.0);
+    /// assert_eq!(NonZeroNonNaNFinite::try_new(f32::NAN), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn try_new(value: f32) -> Option<Self> {
+        if Self::is_valid(value) {
+            Some(Self(value))
+        } else {
+            None
+        }
+    }
+
     /// Creates a new value from a primitive type with zero overhead (in release mode).
     /// It is up to the caller to ensure that the value is valid
     ///
@@ -43,19 +68,24 @@ impl NonZeroNonNaNFinite<f32> {
     /// let x = unsafe { NonZeroNonNaNFinite::new_unchecked(3.0) };
     ///
     /// assert_eq!(x, 3.0);
+    ///
+    /// // It can be used in `const` contexts
+    /// const X: NonZeroNonNaNFinite = unsafe { NonZeroNonNaNFinite::new_unchecked(3.0) };
+    ///
+    /// assert_eq!(X, 3.0);
     /// ```
     /// # Safety
-    /// The caller must ensure that the value is valid.
+    /// The caller must ensure that the value is valid: it must not be `NaN`, zero nor infinite.
     /// It will panic in debug mode if the value is not valid,
     /// but in release mode the behavior is undefined
     #[inline]
     #[must_use]
-    pub unsafe fn new_unchecked(value: f32) -> Self {
-        if Self::new(value).is_err() {
-            debug_assert!(false, "{value} is not a valid NonZeroNonNaNFinite<f32>");
+    pub const unsafe fn new_unchecked(value: f32) -> Self {
+        if !Self::is_valid(value) {
+            debug_assert!(false, "The value is not a valid NonZeroNonNaNFinite<f32>");
 
             #[cfg(feature = "ensure_no_undefined_behavior")]
-            panic!("{value} is not a valid NonZeroNonNaNFinite<f32>");
+            panic!("The value is not a valid NonZeroNonNaNFinite<f32>");
 
             #[cfg(all(
                 feature = "compiler_hints",
@@ -71,6 +101,9 @@ impl NonZeroNonNaNFinite<f32> {
 
     /// Returns the value as a primitive type
     ///
+    /// `into_inner` and `into()` return the same value by consuming `self`,
+    /// and `as_f32` names the primitive type explicitly.
+    ///
     /// # Examples
     ///
     /// ```
@@ -78,6 +111,8 @@ impl NonZeroNonNaNFinite<f32> {
     ///
     /// let x = NonZeroNonNaNFinite::new(3.0).unwrap();
     ///
+    /// assert_eq!(x.get(), 3.0);
+    ///
     /// let y: f32 = x.into();
     ///
     /// assert_eq!(y, 3.0);
@@ -88,6 +123,137 @@ impl NonZeroNonNaNFinite<f32> {
         self.0
     }
 
+    /// Consumes the value and returns it as a primitive type
+    ///
+    /// It is the same as `get`, with the name used by `ordered-float`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_floats::tf32::NonZeroNonNaNFinite;
+    ///
+    /// let x = NonZeroNonNaNFinite::new(3.0).unwrap();
+    ///
+    /// assert_eq!(x.into_inner(), 3.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn into_inner(self) -> f32 {
+        self.0
+    }
+
+    /// Returns the value as a `f32`
+    ///
+    /// It is the same as `get`, but the primitive type is explicit in the name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_floats::tf32::NonZeroNonNaNFinite;
+    ///
+    /// let x = NonZeroNonNaNFinite::new(3.0).unwrap();
+    ///
+    /// let y = x.as_f32();
+    ///
+    /// assert_eq!(y, 3.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_f32(&self) -> f32 {
+        self.0
+    }
+
+    /// Returns the raw transmutation of the value to `u32`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_floats::tf32::NonZeroNonNaNFinite;
+    ///
+    /// let x = NonZeroNonNaNFinite::new(3.0).unwrap();
+    ///
+    /// assert_eq!(x.to_bits(), f32::to_bits(3.0));
+    /// ```
+    ///
+    /// See [`f32::to_bits()`] for more details.
+    #[inline]
+    #[must_use]
+    pub fn to_bits(&self) -> u32 {
+        self.0.to_bits()
+    }
+
+    /// Creates a new value from its raw `u32` representation
+    /// It checks that the value is valid, like `new`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_floats::tf32::NonZeroNonNaNFinite;
+    ///
+    /// let x = NonZeroNonNaNFinite::from_bits(f32::to_bits(3.0)).unwrap();
+    ///
+    /// assert_eq!(x, 3.0);
+    /// assert!(NonZeroNonNaNFinite::from_bits(f32::NAN.to_bits()).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the value is not valid
+    ///
+    /// See [`f32::from_bits()`] for more details.
+    #[inline]
+    pub fn from_bits(bits: u32) -> Result<Self, InvalidNumber> {
+        Self::new(f32::from_bits(bits))
+    }
+
+    /// Applies a function to the value and checks that the result is valid for the type `T`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_floats::tf32::{NonZeroNonNaNFinite, NonNaN};
+    ///
+    /// let x = NonZeroNonNaNFinite::new(3.0).unwrap();
+    ///
+    /// let y: NonNaN = x.map(|x| x - 5.0).unwrap();
+    ///
+    /// assert_eq!(y, -2.0);
+    /// assert!(x.map::<NonNaN>(|x| x * f32::NAN).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the result is not valid for the type `T`
+    #[inline]
+    pub fn map<T: TryFrom<f32, Error = InvalidNumber>>(
+        self,
+        f: impl FnOnce(f32) -> f32,
+    ) -> Result<T, InvalidNumber> {
+        T::try_from(f(self.0))
+    }
+
+    /// Returns the ordering between `self` and `other`, like [`f32::total_cmp()`].
+    ///
+    /// Unlike [`Ord::cmp()`], `-0.0` is less than `+0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use typed_floats::tf32::NonZeroNonNaNFinite;
+    ///
+    /// let x = NonZeroNonNaNFinite::new(3.0).unwrap();
+    /// let y = NonZeroNonNaNFinite::new(6.0).unwrap();
+    ///
+    /// assert_eq!(x.total_cmp(&y), Ordering::Less);
+    /// assert_eq!(x.total_cmp(&x), Ordering::Equal);
+    /// ```
+    ///
+    /// See [`f32::total_cmp()`] for more details.
+    #[inline]
+    #[must_use]
+    pub fn total_cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.0.total_cmp(&other.0)
+    }
+
     /// Returns `true` if this value is NaN.
     /// This is never the case for the provided types
     ///
@@ -20,8 +20,12 @@ macro_rules! test_type {
 
             for &value in &values {
                 let v: Option<$type> = value.try_into().ok();
+                assert_eq!(<$type>::from_bits(value.to_bits()).ok(), v);
+                assert_eq!(<$type>::is_valid(value), v.is_some());
+                assert_eq!(<$type>::try_new(value), v);
                 if let Some(v) = v {
                     crate::assert_float_eq!(v.get(), value);
+                    assert_eq!(v.to_bits(), value.to_bits());
                     assert_eq!(v.is_nan(), false);
                     assert_eq!(v.is_infinite(), value.is_infinite());
                     assert_eq!(v.is_finite(), value.is_finite());
@@ -60,4 +64,44 @@ mod tests {
     test_type!(strictly_negative_finite, StrictlyNegativeFinite);
     test_type!(strictly_positive, StrictlyPositive);
     test_type!(strictly_positive_finite, StrictlyPositiveFinite);
+
+    #[test]
+    fn new_unchecked_const() {
+        const TABLE: [StrictlyPositiveFinite; 3] = unsafe {
+            [
+                StrictlyPositiveFinite::new_unchecked(1.0),
+                StrictlyPositiveFinite::new_unchecked(2.0),
+                StrictlyPositiveFinite::new_unchecked(4.0),
+            ]
+        };
+
+        assert_eq!(TABLE[2], 4.0);
+    }
+
+    #[test]
+    fn try_new_const() {
+        const X: StrictlyPositive = match StrictlyPositive::try_new(2.0) {
+            Some(x) => x,
+            None => panic!(),
+        };
+        const ZERO: Option<StrictlyPositive> = StrictlyPositive::try_new(0.0);
+        const NEG_ZERO: Option<Positive> = Positive::try_new(-0.0);
+        const NAN: Option<NonNaN> = NonNaN::try_new(f64::NAN);
+        const INF: Option<PositiveFinite> = PositiveFinite::try_new(f64::INFINITY);
+        const NEG_INF: Option<Negative> = Negative::try_new(f64::NEG_INFINITY);
+
+        assert_eq!(X, 2.0);
+        assert_eq!(ZERO, None);
+        assert_eq!(NEG_ZERO, None);
+        assert_eq!(NAN, None);
+        assert_eq!(INF, None);
+        assert!(NEG_INF.is_some());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "The value is not a valid Positive<f64>")]
+    fn new_unchecked_invalid() {
+        let _ = unsafe { Positive::new_unchecked(-0.0) };
+    }
 }
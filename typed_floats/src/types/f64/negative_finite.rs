@@ -33,6 +33,31 @@ impl NegativeFinite<f64> {
         Ok(Self(value))
     }
 
+    /// Creates a new value from a primitive type
+    /// Unlike `new`, it can be used in `const` contexts
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::tf64::NegativeFinite;
+    /// const X: NegativeFinite = match NegativeFinite::try_new(-3.0) {
+    ///     Some(x) => x,
+    ///     None => panic!(),
+    /// };
+    ///
+    /// assert_eq!(X, -3.0);
+    /// assert_eq!(NegativeFinite::try_new(f64::NAN), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn try_new(value: f64) -> Option<Self> {
+        if Self::is_valid(value) {
+            Some(Self(value))
+        } else {
+            None
+        }
+    }
+
     /// Creates a new value from a primitive type with zero overhead (in release mode).
     /// It is up to the caller to ensure that the value is valid
     ///
@@ -43,19 +68,24 @@ impl NegativeFinite<f64> {
     /// let x = unsafe { NegativeFinite::new_unchecked(-3.0) };
     ///
     /// assert_eq!(x, -3.0);
+    ///
+    /// // It can be used in `const` contexts
+    /// const X: NegativeFinite = unsafe { NegativeFinite::new_unchecked(-3.0) };
+    ///
+    /// assert_eq!(X, -3.0);
     /// ```
     /// # Safety
-    /// The caller must ensure that the value is valid.
+    /// The caller must ensure that the value is valid: it must not be `NaN` nor infinite, and must have a negative sign (`+0.0` is not valid).
     /// It will panic in debug mode if the value is not valid,
     /// but in release mode the behavior is undefined
     #[inline]
     #[must_use]
-    pub unsafe fn new_unchecked(value: f64) -> Self {
-        if Self::new(value).is_err() || value > 0.0 {
-            debug_assert!(false, "{value} is not a valid NegativeFinite<f64>");
+    pub const unsafe fn new_unchecked(value: f64) -> Self {
+        if !Self::is_valid(value) {
+            debug_assert!(false, "The value is not a valid NegativeFinite<f64>");
 
             #[cfg(feature = "ensure_no_undefined_behavior")]
-            panic!("{value} is not a valid NegativeFinite<f64>");
+            panic!("The value is not a valid NegativeFinite<f64>");
 
             #[cfg(all(
                 feature = "compiler_hints",
@@ -71,6 +101,9 @@ impl NegativeFinite<f64> {
 
     /// Returns the value as a primitive type
     ///
+    /// `into_inner` and `into()` return the same value by consuming `self`,
+    /// and `as_f64` names the primitive type explicitly.
+    ///
     /// # Examples
     ///
     /// ```
@@ -78,6 +111,8 @@ impl NegativeFinite<f64> {
     ///
     /// let x = NegativeFinite::new(-3.0).unwrap();
     ///
+    /// assert_eq!(x.get(), -3.0);
+    ///
     /// let y: f64 = x.into();
     ///
     /// assert_eq!(y, -3.0);
@@ -88,6 +123,137 @@ impl NegativeFinite<f64> {
         self.0
     }
 
+    /// Consumes the value and returns it as a primitive type
+    ///
+    /// It is the same as `get`, with the name used by `ordered-float`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_floats::tf64::NegativeFinite;
+    ///
+    /// let x = NegativeFinite::new(-3.0).unwrap();
+    ///
+    /// assert_eq!(x.into_inner(), -3.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn into_inner(self) -> f64 {
+        self.0
+    }
+
+    /// Returns the value as a `f64`
+    ///
+    /// It is the same as `get`, but the primitive type is explicit in the name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_floats::tf64::NegativeFinite;
+    ///
+    /// let x = NegativeFinite::new(-3.0).unwrap();
+    ///
+    /// let y = x.as_f64();
+    ///
+    /// assert_eq!(y, -3.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_f64(&self) -> f64 {
+        self.0
+    }
+
+    /// Returns the raw transmutation of the value to `u64`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_floats::tf64::NegativeFinite;
+    ///
+    /// let x = NegativeFinite::new(-3.0).unwrap();
+    ///
+    /// assert_eq!(x.to_bits(), f64::to_bits(-3.0));
+    /// ```
+    ///
+    /// See [`f64::to_bits()`] for more details.
+    #[inline]
+    #[must_use]
+    pub fn to_bits(&self) -> u64 {
+        self.0.to_bits()
+    }
+
+    /// Creates a new value from its raw `u64` representation
+    /// It checks that the value is valid, like `new`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_floats::tf64::NegativeFinite;
+    ///
+    /// let x = NegativeFinite::from_bits(f64::to_bits(-3.0)).unwrap();
+    ///
+    /// assert_eq!(x, -3.0);
+    /// assert!(NegativeFinite::from_bits(f64::NAN.to_bits()).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the value is not valid
+    ///
+    /// See [`f64::from_bits()`] for more details.
+    #[inline]
+    pub fn from_bits(bits: u64) -> Result<Self, InvalidNumber> {
+        Self::new(f64::from_bits(bits))
+    }
+
+    /// Applies a function to the value and checks that the result is valid for the type `T`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_floats::tf64::{NegativeFinite, NonNaN};
+    ///
+    /// let x = NegativeFinite::new(-3.0).unwrap();
+    ///
+    /// let y: NonNaN = x.map(|x| x - 5.0).unwrap();
+    ///
+    /// assert_eq!(y, -8.0);
+    /// assert!(x.map::<NonNaN>(|x| x * f64::NAN).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the result is not valid for the type `T`
+    #[inline]
+    pub fn map<T: TryFrom<f64, Error = InvalidNumber>>(
+        self,
+        f: impl FnOnce(f64) -> f64,
+    ) -> Result<T, InvalidNumber> {
+        T::try_from(f(self.0))
+    }
+
+    /// Returns the ordering between `self` and `other`, like [`f64::total_cmp()`].
+    ///
+    /// Unlike [`Ord::cmp()`], `-0.0` is less than `+0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use typed_floats::tf64::NegativeFinite;
+    ///
+    /// let x = NegativeFinite::new(-3.0).unwrap();
+    /// let y = NegativeFinite::new(-6.0).unwrap();
+    ///
+    /// assert_eq!(x.total_cmp(&y), Ordering::Greater);
+    /// assert_eq!(x.total_cmp(&x), Ordering::Equal);
+    /// ```
+    ///
+    /// See [`f64::total_cmp()`] for more details.
+    #[inline]
+    #[must_use]
+    pub fn total_cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.0.total_cmp(&other.0)
+    }
+
     /// Returns `true` if this value is NaN.
     /// This is never the case for the provided types
     ///
@@ -25,6 +25,31 @@ impl NonNaN<f64> {
         Ok(Self(value))
     }
 
+    /// Creates a new value from a primitive type
+    /// Unlike `new`, it can be used in `const` contexts
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typed_floats::tf64::NonNaN;
+    /// const X: NonNaN = match NonNaN::try_new(3.0) {
+    ///     Some(x) => x,
+    ///     None => panic!(),
+    /// };
+    ///
+    /// assert_eq!(X, 3.0);
+    /// assert_eq!(NonNaN::try_new(f64::NAN), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn try_new(value: f64) -> Option<Self> {
+        if Self::is_valid(value) {
+            Some(Self(value))
+        } else {
+            None
+        }
+    }
+
     /// Creates a new value from a primitive type with zero overhead (in release mode).
     /// It is up to the caller to ensure that the value is valid
     ///
@@ -35,19 +60,24 @@ impl NonNaN<f64> {
     /// let x = unsafe { NonNaN::new_unchecked(3.0) };
     ///
     /// assert_eq!(x, 3.0);
+    ///
+    /// // It can be used in `const` contexts
+    /// const X: NonNaN = unsafe { NonNaN::new_unchecked(3.0) };
+    ///
+    /// assert_eq!(X, 3.0);
     /// ```
     /// # Safety
-    /// The caller must ensure that the value is valid.
+    /// The caller must ensure that the value is valid: it must not be `NaN`.
     /// It will panic in debug mode if the value is not valid,
     /// but in release mode the behavior is undefined
     #[inline]
     #[must_use]
-    pub unsafe fn new_unchecked(value: f64) -> Self {
-        if Self::new(value).is_err() {
-            debug_assert!(false, "{value} is not a valid NonNaN<f64>");
+    pub const unsafe fn new_unchecked(value: f64) -> Self {
+        if !Self::is_valid(value) {
+            debug_assert!(false, "The value is not a valid NonNaN<f64>");
 
             #[cfg(feature = "ensure_no_undefined_behavior")]
-            panic!("{value} is not a valid NonNaN<f64>");
+            panic!("The value is not a valid NonNaN<f64>");
 
             #[cfg(all(
                 feature = "compiler_hints",
@@ -63,6 +93,9 @@ impl NonNaN<f64> {
 
     /// Returns the value as a primitive type
     ///
+    /// `into_inner` and `into()` return the same value by consuming `self`,
+    /// and `as_f64` names the primitive type explicitly.
+    ///
     /// # Examples
     ///
     /// ```
@@ -70,6 +103,8 @@ impl NonNaN<f64> {
     ///
     /// let x = NonNaN::new(3.0).unwrap();
     ///
+    /// assert_eq!(x.get(), 3.0);
+    ///
     /// let y: f64 = x.into();
     ///
     /// assert_eq!(y, 3.0);
@@ -80,6 +115,143 @@ impl NonNaN<f64> {
         self.0
     }
 
+    /// Consumes the value and returns it as a primitive type
+    ///
+    /// It is the same as `get`, with the name used by `ordered-float`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_floats::tf64::NonNaN;
+    ///
+    /// let x = NonNaN::new(3.0).unwrap();
+    ///
+    /// assert_eq!(x.into_inner(), 3.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn into_inner(self) -> f64 {
+        self.0
+    }
+
+    /// Returns the value as a `f64`
+    ///
+    /// It is the same as `get`, but the primitive type is explicit in the name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_floats::tf64::NonNaN;
+    ///
+    /// let x = NonNaN::new(3.0).unwrap();
+    ///
+    /// let y = x.as_f64();
+    ///
+    /// assert_eq!(y, 3.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_f64(&self) -> f64 {
+        self.0
+    }
+
+    /// Returns the raw transmutation of the value to `u64`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_floats::tf64::NonNaN;
+    ///
+    /// let x = NonNaN::new(3.0).unwrap();
+    ///
+    /// assert_eq!(x.to_bits(), f64::to_bits(3.0));
+    /// ```
+    ///
+    /// See [`f64::to_bits()`] for more details.
+    #[inline]
+    #[must_use]
+    pub fn to_bits(&self) -> u64 {
+        self.0.to_bits()
+    }
+
+    /// Creates a new value from its raw `u64` representation
+    /// It checks that the value is valid, like `new`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_floats::tf64::NonNaN;
+    ///
+    /// let x = NonNaN::from_bits(f64::to_bits(3.0)).unwrap();
+    ///
+    /// assert_eq!(x, 3.0);
+    /// assert!(NonNaN::from_bits(f64::NAN.to_bits()).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the value is not valid
+    ///
+    /// See [`f64::from_bits()`] for more details.
+    #[inline]
+    pub fn from_bits(bits: u64) -> Result<Self, InvalidNumber> {
+        Self::new(f64::from_bits(bits))
+    }
+
+    /// Applies a function to the value and checks that the result is valid for the type `T`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_floats::tf64::NonNaN;
+    ///
+    /// let x = NonNaN::new(3.0).unwrap();
+    ///
+    /// let y: NonNaN = x.map(|x| x - 5.0).unwrap();
+    ///
+    /// assert_eq!(y, -2.0);
+    /// assert!(x.map::<NonNaN>(|x| x * f64::NAN).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the result is not valid for the type `T`
+    #[inline]
+    pub fn map<T: TryFrom<f64, Error = InvalidNumber>>(
+        self,
+        f: impl FnOnce(f64) -> f64,
+    ) -> Result<T, InvalidNumber> {
+        T::try_from(f(self.0))
+    }
+
+    /// Returns the ordering between `self` and `other`, like [`f64::total_cmp()`].
+    ///
+    /// Unlike [`Ord::cmp()`], `-0.0` is less than `+0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use typed_floats::tf64::NonNaN;
+    ///
+    /// let x = NonNaN::new(3.0).unwrap();
+    /// let y = NonNaN::new(6.0).unwrap();
+    ///
+    /// assert_eq!(x.total_cmp(&y), Ordering::Less);
+    /// assert_eq!(x.total_cmp(&x), Ordering::Equal);
+    ///
+    /// let neg_zero = NonNaN::new(-0.0).unwrap();
+    /// let zero = NonNaN::new(0.0).unwrap();
+    ///
+    /// assert_eq!(neg_zero, zero);
+    /// assert_eq!(neg_zero.total_cmp(&zero), Ordering::Less);
+    /// ```
+    ///
+    /// See [`f64::total_cmp()`] for more details.
+    #[inline]
+    #[must_use]
+    pub fn total_cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.0.total_cmp(&other.0)
+    }
+
     /// Returns `true` if this value is NaN.
     /// This is never the case for the provided types
     ///
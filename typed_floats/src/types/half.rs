@@ -0,0 +1,210 @@
+use crate::types::{
+    InvalidNumber, Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN,
+    NonZeroNonNaNFinite, Positive, PositiveFinite, StrictlyNegative, StrictlyNegativeFinite,
+    StrictlyPositive, StrictlyPositiveFinite,
+};
+
+// `half::f16` and `half::bf16` have no native arithmetic (it is done through `f32`),
+// so only the constructors, the comparisons and the conversions are implemented.
+// To do some math, convert them into the `f32` types: the conversion is exact
+// and keeps the guarantees of the type.
+
+macro_rules! impl_half {
+    ($type:ident, $float:ident) => {
+        impl $type<half::$float> {
+            /// Creates a new value from a primitive type
+            /// It adds a little overhead compared to `new_unchecked`
+            /// because it checks that the value is valid
+            ///
+            /// # Errors
+            /// Returns an error if the value is not valid
+            #[inline]
+            pub fn new(value: half::$float) -> Result<Self, InvalidNumber> {
+                // The conversion to `f32` is exact, so it fails for the same reason
+                $type::<f32>::new(value.to_f32()).map(|_| Self(value))
+            }
+
+            /// Creates a new value from a primitive type
+            /// Unlike `new`, it can be used in `const` contexts
+            #[inline]
+            #[must_use]
+            pub const fn try_new(value: half::$float) -> Option<Self> {
+                if Self::is_valid(value) {
+                    Some(Self(value))
+                } else {
+                    None
+                }
+            }
+
+            /// Creates a new value from a primitive type with zero overhead (in release mode).
+            /// It is up to the caller to ensure that the value is valid
+            ///
+            /// # Safety
+            /// The caller must ensure that the value is valid.
+            /// It will panic in debug mode if the value is not valid,
+            /// but in release mode the behavior is undefined
+            #[inline]
+            #[must_use]
+            pub const unsafe fn new_unchecked(value: half::$float) -> Self {
+                if !Self::is_valid(value) {
+                    debug_assert!(
+                        false,
+                        concat!(
+                            "The value is not a valid ",
+                            stringify!($type),
+                            "<",
+                            stringify!($float),
+                            ">"
+                        )
+                    );
+
+                    #[cfg(feature = "ensure_no_undefined_behavior")]
+                    panic!(concat!(
+                        "The value is not a valid ",
+                        stringify!($type),
+                        "<",
+                        stringify!($float),
+                        ">"
+                    ));
+
+                    #[cfg(all(
+                        feature = "compiler_hints",
+                        not(feature = "ensure_no_undefined_behavior")
+                    ))]
+                    unsafe {
+                        core::hint::unreachable_unchecked()
+                    }
+                }
+
+                Self(value)
+            }
+
+            /// Returns the value as a primitive type
+            #[inline]
+            #[must_use]
+            pub const fn get(&self) -> half::$float {
+                self.0
+            }
+
+            /// Consumes the value and returns it as a primitive type, like `get`
+            #[inline]
+            #[must_use]
+            pub const fn into_inner(self) -> half::$float {
+                self.0
+            }
+
+            /// Returns `true` if the number is positive zero.
+            #[inline]
+            #[must_use]
+            pub const fn is_positive_zero(&self) -> bool {
+                self.0.to_bits() == 0
+            }
+
+            /// Returns `true` if the number is negative zero.
+            #[inline]
+            #[must_use]
+            pub const fn is_negative_zero(&self) -> bool {
+                self.0.to_bits() == 0x8000
+            }
+        }
+
+        impl TryFrom<half::$float> for $type<half::$float> {
+            type Error = InvalidNumber;
+
+            #[inline]
+            fn try_from(value: half::$float) -> Result<Self, Self::Error> {
+                Self::new(value)
+            }
+        }
+
+        impl From<$type<half::$float>> for half::$float {
+            #[inline]
+            fn from(value: $type<half::$float>) -> Self {
+                value.0
+            }
+        }
+
+        impl From<$type<half::$float>> for $type<f32> {
+            #[inline]
+            fn from(value: $type<half::$float>) -> Self {
+                // # Safety
+                // The conversion to `f32` is exact, so the value is still valid
+                unsafe { Self::new_unchecked(value.0.to_f32()) }
+            }
+        }
+
+        impl From<$type<half::$float>> for $type<f64> {
+            #[inline]
+            fn from(value: $type<half::$float>) -> Self {
+                // # Safety
+                // The conversion to `f64` is exact, so the value is still valid
+                unsafe { Self::new_unchecked(value.0.to_f64()) }
+            }
+        }
+
+        impl Eq for $type<half::$float> {}
+
+        impl PartialEq for $type<half::$float> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl PartialEq<half::$float> for $type<half::$float> {
+            #[inline]
+            fn eq(&self, other: &half::$float) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl Ord for $type<half::$float> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                // The values are never `NaN` and the zeros are equal,
+                // consistently with the `f32` and `f64` types
+                if self.0 < other.0 {
+                    core::cmp::Ordering::Less
+                } else if self.0 > other.0 {
+                    core::cmp::Ordering::Greater
+                } else {
+                    core::cmp::Ordering::Equal
+                }
+            }
+        }
+
+        impl PartialOrd for $type<half::$float> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl core::fmt::Display for $type<half::$float> {
+            #[inline]
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+    };
+}
+
+macro_rules! impl_halfs {
+    ($type:ident) => {
+        impl_half!($type, f16);
+        impl_half!($type, bf16);
+    };
+}
+
+impl_halfs!(NonNaN);
+impl_halfs!(NonNaNFinite);
+impl_halfs!(NonZeroNonNaN);
+impl_halfs!(NonZeroNonNaNFinite);
+impl_halfs!(StrictlyPositive);
+impl_halfs!(StrictlyNegative);
+impl_halfs!(Positive);
+impl_halfs!(Negative);
+impl_halfs!(StrictlyPositiveFinite);
+impl_halfs!(StrictlyNegativeFinite);
+impl_halfs!(PositiveFinite);
+impl_halfs!(NegativeFinite);
@@ -0,0 +1,72 @@
+use crate::{
+    Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN, NonZeroNonNaNFinite, Positive,
+    PositiveFinite, StrictlyNegative, StrictlyNegativeFinite, StrictlyPositive,
+    StrictlyPositiveFinite,
+};
+
+// The comparisons are done on the primitive values, with the same tolerances,
+// so they behave exactly like the ones of `f32` and `f64`.
+
+macro_rules! impl_approx {
+    ($type:ident, $float:ident) => {
+        impl approx::AbsDiffEq for $type<$float> {
+            type Epsilon = <$float as approx::AbsDiffEq>::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> Self::Epsilon {
+                <$float as approx::AbsDiffEq>::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                approx::AbsDiffEq::abs_diff_eq(&self.0, &other.0, epsilon)
+            }
+        }
+
+        impl approx::RelativeEq for $type<$float> {
+            #[inline]
+            fn default_max_relative() -> Self::Epsilon {
+                <$float as approx::RelativeEq>::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                approx::RelativeEq::relative_eq(&self.0, &other.0, epsilon, max_relative)
+            }
+        }
+
+        impl approx::UlpsEq for $type<$float> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                <$float as approx::UlpsEq>::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                approx::UlpsEq::ulps_eq(&self.0, &other.0, epsilon, max_ulps)
+            }
+        }
+    };
+    ($type:ident) => {
+        impl_approx!($type, f32);
+        impl_approx!($type, f64);
+    };
+}
+
+impl_approx!(NonNaN);
+impl_approx!(NonNaNFinite);
+impl_approx!(NonZeroNonNaN);
+impl_approx!(NonZeroNonNaNFinite);
+impl_approx!(StrictlyPositive);
+impl_approx!(StrictlyNegative);
+impl_approx!(Positive);
+impl_approx!(Negative);
+impl_approx!(StrictlyPositiveFinite);
+impl_approx!(StrictlyNegativeFinite);
+impl_approx!(PositiveFinite);
+impl_approx!(NegativeFinite);
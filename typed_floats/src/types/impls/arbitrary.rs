@@ -0,0 +1,77 @@
+use crate::{
+    Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN, NonZeroNonNaNFinite, Positive,
+    PositiveFinite, StrictlyNegative, StrictlyNegativeFinite, StrictlyPositive,
+    StrictlyPositiveFinite,
+};
+
+// The bits of a float are consumed, then mapped deterministically into the domain of the type:
+// - the sign is forced if the type accepts only one sign
+// - `NaN` becomes infinity, or `MAX` if infinity is not accepted
+// - infinity becomes `MAX` if it is not accepted
+// - zero becomes the smallest subnormal if it is not accepted
+// So any input is decoded into a valid value, and the edge cases are reachable from short inputs
+// (an empty input gives a zero, or the smallest subnormal).
+
+macro_rules! impl_arbitrary {
+    ($type:ident, $float:ident, $bits:ident) => {
+        impl<'a> arbitrary::Arbitrary<'a> for $type<$float> {
+            fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                const SIGN_MASK: $bits = 1 << ($bits::BITS - 1);
+                let inf_bits = $float::INFINITY.to_bits();
+
+                let bits = <$bits as arbitrary::Arbitrary<'a>>::arbitrary(u)?;
+
+                let sign = if !$type::accept_negative() {
+                    0
+                } else if !$type::accept_positive() {
+                    SIGN_MASK
+                } else {
+                    bits & SIGN_MASK
+                };
+
+                let mut abs_bits = bits & !SIGN_MASK;
+
+                if abs_bits > inf_bits {
+                    // NaN
+                    abs_bits = inf_bits;
+                }
+
+                if abs_bits == inf_bits && !$type::accept_infinity() {
+                    // MAX
+                    abs_bits = inf_bits - 1;
+                }
+
+                if abs_bits == 0 && !$type::accept_zero() {
+                    // Smallest subnormal
+                    abs_bits = 1;
+                }
+
+                // # Safety
+                // The value has been mapped into the domain of the type
+                Ok(unsafe { Self::new_unchecked($float::from_bits(sign | abs_bits)) })
+            }
+
+            #[inline]
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                <$bits as arbitrary::Arbitrary<'a>>::size_hint(depth)
+            }
+        }
+    };
+    ($type:ident) => {
+        impl_arbitrary!($type, f32, u32);
+        impl_arbitrary!($type, f64, u64);
+    };
+}
+
+impl_arbitrary!(NonNaN);
+impl_arbitrary!(NonNaNFinite);
+impl_arbitrary!(NonZeroNonNaN);
+impl_arbitrary!(NonZeroNonNaNFinite);
+impl_arbitrary!(StrictlyPositive);
+impl_arbitrary!(StrictlyNegative);
+impl_arbitrary!(Positive);
+impl_arbitrary!(Negative);
+impl_arbitrary!(StrictlyPositiveFinite);
+impl_arbitrary!(StrictlyNegativeFinite);
+impl_arbitrary!(PositiveFinite);
+impl_arbitrary!(NegativeFinite);
@@ -0,0 +1,83 @@
+use crate::{
+    Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN, NonZeroNonNaNFinite, Positive,
+    PositiveFinite, StrictlyNegative, StrictlyNegativeFinite, StrictlyPositive,
+    StrictlyPositiveFinite,
+};
+
+// `Borrow` requires `Eq`, `Ord` and `Hash` to behave the same on the borrowed value.
+// This is the case because `NaN` is not a possible value and the zeros are equal,
+// like with the primitive types.
+
+macro_rules! impl_as_ref {
+    ($test:ident, $type:ident) => {
+        impl AsRef<f32> for $type<f32> {
+            #[inline]
+            fn as_ref(&self) -> &f32 {
+                &self.0
+            }
+        }
+
+        impl core::borrow::Borrow<f32> for $type<f32> {
+            #[inline]
+            fn borrow(&self) -> &f32 {
+                &self.0
+            }
+        }
+
+        impl AsRef<f64> for $type<f64> {
+            #[inline]
+            fn as_ref(&self) -> &f64 {
+                &self.0
+            }
+        }
+
+        impl core::borrow::Borrow<f64> for $type<f64> {
+            #[inline]
+            fn borrow(&self) -> &f64 {
+                &self.0
+            }
+        }
+
+        #[test]
+        fn $test() {
+            use core::borrow::Borrow;
+
+            let values_f32 = crate::tf32::TEST_VALUES;
+
+            for &value in &values_f32 {
+                if let Ok(t) = $type::<f32>::new(value) {
+                    let as_ref: &f32 = t.as_ref();
+                    let borrowed: &f32 = t.borrow();
+
+                    assert_eq!(as_ref.to_bits(), value.to_bits());
+                    assert_eq!(borrowed.to_bits(), value.to_bits());
+                }
+            }
+
+            let values_f64 = crate::tf64::TEST_VALUES;
+
+            for &value in &values_f64 {
+                if let Ok(t) = $type::<f64>::new(value) {
+                    let as_ref: &f64 = t.as_ref();
+                    let borrowed: &f64 = t.borrow();
+
+                    assert_eq!(as_ref.to_bits(), value.to_bits());
+                    assert_eq!(borrowed.to_bits(), value.to_bits());
+                }
+            }
+        }
+    };
+}
+
+impl_as_ref!(non_nan, NonNaN);
+impl_as_ref!(non_zero_non_nan, NonZeroNonNaN);
+impl_as_ref!(non_nan_finite, NonNaNFinite);
+impl_as_ref!(non_zero_non_nan_finite, NonZeroNonNaNFinite);
+impl_as_ref!(positive, Positive);
+impl_as_ref!(negative, Negative);
+impl_as_ref!(positive_finite, PositiveFinite);
+impl_as_ref!(negative_finite, NegativeFinite);
+impl_as_ref!(strictly_positive, StrictlyPositive);
+impl_as_ref!(strictly_negative, StrictlyNegative);
+impl_as_ref!(strictly_positive_finite, StrictlyPositiveFinite);
+impl_as_ref!(strictly_negative_finite, StrictlyNegativeFinite);
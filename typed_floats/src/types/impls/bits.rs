@@ -0,0 +1,50 @@
+use crate::types::accept::{f32_to_bits, f64_to_bits};
+use crate::{
+    Negative, NegativeFinite, NonZeroNonNaN, NonZeroNonNaNFinite, StrictlyNegative,
+    StrictlyNegativeFinite, StrictlyPositive, StrictlyPositiveFinite,
+};
+use core::num::{NonZeroU32, NonZeroU64};
+
+// Only `+0.0` has all its bits set to zero, so the bits of the types that don't accept it are never zero.
+// It includes `Negative` and `NegativeFinite`, as the sign bit of `-0.0` is set.
+
+macro_rules! impl_to_nonzero_bits {
+    ($type:ident, $float:ident, $to_bits:ident, $bits:ident, $example:expr) => {
+        impl $type<$float> {
+            /// Returns the raw transmutation of the value, which is never zero as `+0.0` is not valid.
+            ///
+            /// It can be used as the key of a map or wherever a niche is useful, e.g. `Option<NonZeroU64>`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            #[doc = concat!("let x = ", stringify!($type), "::<", stringify!($float), ">::new(", stringify!($example), ").unwrap();")]
+            ///
+            #[doc = concat!("assert_eq!(x.to_nonzero_bits().get(), ", stringify!($float), "::to_bits(", stringify!($example), "));")]
+            /// ```
+            ///
+            #[doc = concat!("See [`", stringify!($float), "::to_bits()`] for more details.")]
+            #[inline]
+            #[must_use]
+            pub const fn to_nonzero_bits(self) -> $bits {
+                // # Safety
+                // The type doesn't accept `+0.0`, the only float whose bits are zero.
+                unsafe { $bits::new_unchecked($to_bits(self.get())) }
+            }
+        }
+    };
+    ($type:ident, $example:expr) => {
+        impl_to_nonzero_bits!($type, f32, f32_to_bits, NonZeroU32, $example);
+        impl_to_nonzero_bits!($type, f64, f64_to_bits, NonZeroU64, $example);
+    };
+}
+
+impl_to_nonzero_bits!(NonZeroNonNaN, 2.0);
+impl_to_nonzero_bits!(NonZeroNonNaNFinite, 2.0);
+impl_to_nonzero_bits!(Negative, -2.0);
+impl_to_nonzero_bits!(NegativeFinite, -2.0);
+impl_to_nonzero_bits!(StrictlyPositive, 2.0);
+impl_to_nonzero_bits!(StrictlyNegative, -2.0);
+impl_to_nonzero_bits!(StrictlyPositiveFinite, 2.0);
+impl_to_nonzero_bits!(StrictlyNegativeFinite, -2.0);
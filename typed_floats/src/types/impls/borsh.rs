@@ -0,0 +1,58 @@
+use crate::{
+    InvalidNumber, Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN,
+    NonZeroNonNaNFinite, Positive, PositiveFinite, StrictlyNegative, StrictlyNegativeFinite,
+    StrictlyPositive, StrictlyPositiveFinite,
+};
+
+// The values are written as the little-endian bytes of the float, like `f32` and `f64`.
+// They are read as bits, as `borsh` rejects `NaN` with its own message,
+// so that the error always names the invariant that failed.
+
+fn invalid_data(error: &InvalidNumber) -> borsh::io::Error {
+    let message = match error {
+        InvalidNumber::NaN => "Number is NaN",
+        InvalidNumber::Zero => "Number is zero",
+        InvalidNumber::Negative => "Number is negative",
+        InvalidNumber::Positive => "Number is positive",
+        InvalidNumber::Infinite => "Number is infinite",
+    };
+
+    borsh::io::Error::new(borsh::io::ErrorKind::InvalidData, message)
+}
+
+macro_rules! impl_borsh {
+    ($type:ident, $float:ident, $bits:ident) => {
+        impl borsh::BorshSerialize for $type<$float> {
+            #[inline]
+            fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+                writer.write_all(&self.get().to_le_bytes())
+            }
+        }
+
+        impl borsh::BorshDeserialize for $type<$float> {
+            #[inline]
+            fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+                let bits = <$bits as borsh::BorshDeserialize>::deserialize_reader(reader)?;
+
+                Self::new($float::from_bits(bits)).map_err(|error| invalid_data(&error))
+            }
+        }
+    };
+    ($type:ident) => {
+        impl_borsh!($type, f32, u32);
+        impl_borsh!($type, f64, u64);
+    };
+}
+
+impl_borsh!(NonNaN);
+impl_borsh!(NonNaNFinite);
+impl_borsh!(NonZeroNonNaN);
+impl_borsh!(NonZeroNonNaNFinite);
+impl_borsh!(StrictlyPositive);
+impl_borsh!(StrictlyNegative);
+impl_borsh!(Positive);
+impl_borsh!(Negative);
+impl_borsh!(StrictlyPositiveFinite);
+impl_borsh!(StrictlyNegativeFinite);
+impl_borsh!(PositiveFinite);
+impl_borsh!(NegativeFinite);
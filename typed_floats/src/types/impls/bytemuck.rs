@@ -0,0 +1,71 @@
+use crate::{
+    Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN, NonZeroNonNaNFinite, Positive,
+    PositiveFinite, StrictlyNegative, StrictlyNegativeFinite, StrictlyPositive,
+    StrictlyPositiveFinite,
+};
+
+// `bytemuck::Pod` and `bytemuck::AnyBitPattern` can't be implemented:
+// they allow to create a value from any bit pattern (`NaN` included),
+// which would break the invariants of the types.
+// Instead, `bytemuck::CheckedBitPattern` is implemented to check the values
+// when casting from the primitive type.
+
+macro_rules! impl_bytemuck {
+    ($type:ident) => {
+        // # Safety
+        // The types are `#[repr(transparent)]` over `f32`/`f64`,
+        // so they have no padding and all their bytes are initialized.
+        unsafe impl bytemuck::NoUninit for $type<f32> {}
+        unsafe impl bytemuck::NoUninit for $type<f64> {}
+
+        // # Safety
+        // The types are `#[repr(transparent)]` over `f32`/`f64`,
+        // and `is_valid` returns `true` only for the values that are valid for this type.
+        unsafe impl bytemuck::CheckedBitPattern for $type<f32> {
+            type Bits = f32;
+
+            #[inline]
+            fn is_valid_bit_pattern(bits: &f32) -> bool {
+                Self::is_valid(*bits)
+            }
+        }
+
+        unsafe impl bytemuck::CheckedBitPattern for $type<f64> {
+            type Bits = f64;
+
+            #[inline]
+            fn is_valid_bit_pattern(bits: &f64) -> bool {
+                Self::is_valid(*bits)
+            }
+        }
+    };
+}
+
+impl_bytemuck!(NonNaN);
+impl_bytemuck!(NonNaNFinite);
+impl_bytemuck!(NonZeroNonNaN);
+impl_bytemuck!(NonZeroNonNaNFinite);
+impl_bytemuck!(StrictlyPositive);
+impl_bytemuck!(StrictlyNegative);
+impl_bytemuck!(Positive);
+impl_bytemuck!(Negative);
+impl_bytemuck!(StrictlyPositiveFinite);
+impl_bytemuck!(StrictlyNegativeFinite);
+impl_bytemuck!(PositiveFinite);
+impl_bytemuck!(NegativeFinite);
+
+// The all-zero bit pattern is `+0.0`, so `bytemuck::Zeroable` is only implemented
+// for the types accepting it (the negative types only accept `-0.0`).
+macro_rules! impl_zeroable {
+    ($type:ident) => {
+        // # Safety
+        // `+0.0` is a valid value for this type.
+        unsafe impl bytemuck::Zeroable for $type<f32> {}
+        unsafe impl bytemuck::Zeroable for $type<f64> {}
+    };
+}
+
+impl_zeroable!(NonNaN);
+impl_zeroable!(NonNaNFinite);
+impl_zeroable!(Positive);
+impl_zeroable!(PositiveFinite);
@@ -0,0 +1,255 @@
+use crate::{
+    InvalidNumber, Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN,
+    NonZeroNonNaNFinite, Positive, PositiveFinite, StrictlyNegative, StrictlyNegativeFinite,
+    StrictlyPositive, StrictlyPositiveFinite,
+};
+
+// `NaN` and the values of the wrong sign (including the zero of the wrong sign) are mapped to `$zero`,
+// the infinity of the right sign to `$inf`, and every other value is kept.
+
+macro_rules! impl_clamping_from {
+    (positive, $type:ident, $float:ident, $zero:expr, $inf:expr, $mapping:literal) => {
+        impl $type<$float> {
+            /// Creates a new value from a primitive type,
+            /// mapping the invalid values to the closest valid one:
+            #[doc = $mapping]
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            #[doc = concat!("let x = ", stringify!($type), "::<", stringify!($float), ">::clamping_from(3.0);")]
+            ///
+            /// assert_eq!(x, 3.0);
+            ///
+            #[doc = concat!("let y = ", stringify!($type), "::<", stringify!($float), ">::clamping_from(-3.0);")]
+            ///
+            #[doc = concat!("assert_eq!(y, ", stringify!($zero), ");")]
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn clamping_from(value: $float) -> Self {
+                let value = if value.is_nan() || value <= 0.0 {
+                    $zero
+                } else if value.is_infinite() {
+                    $inf
+                } else {
+                    value
+                };
+
+                // # Safety
+                // `value` is either a valid value of `self` or one of the constants above.
+                unsafe { Self::new_unchecked(value) }
+            }
+        }
+    };
+    (negative, $type:ident, $float:ident, $zero:expr, $inf:expr, $mapping:literal) => {
+        impl $type<$float> {
+            /// Creates a new value from a primitive type,
+            /// mapping the invalid values to the closest valid one:
+            #[doc = $mapping]
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            #[doc = concat!("let x = ", stringify!($type), "::<", stringify!($float), ">::clamping_from(-3.0);")]
+            ///
+            /// assert_eq!(x, -3.0);
+            ///
+            #[doc = concat!("let y = ", stringify!($type), "::<", stringify!($float), ">::clamping_from(3.0);")]
+            ///
+            #[doc = concat!("assert_eq!(y, ", stringify!($zero), ");")]
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn clamping_from(value: $float) -> Self {
+                let value = if value.is_nan() || value >= 0.0 {
+                    $zero
+                } else if value.is_infinite() {
+                    $inf
+                } else {
+                    value
+                };
+
+                // # Safety
+                // `value` is either a valid value of `self` or one of the constants above.
+                unsafe { Self::new_unchecked(value) }
+            }
+        }
+    };
+    ($sign:ident, $type:ident, $mapping:literal, $($float:ident => ($zero:expr, $inf:expr)),*) => {
+        $(
+            impl_clamping_from!($sign, $type, $float, $zero, $inf, $mapping);
+        )*
+    };
+}
+
+impl_clamping_from!(
+    positive,
+    Positive,
+    " `NaN` and the negative values (including `-0.0` and `-inf`) are mapped to `+0.0`.",
+    f32 => (0.0, f32::INFINITY),
+    f64 => (0.0, f64::INFINITY)
+);
+impl_clamping_from!(
+    positive,
+    PositiveFinite,
+    " `NaN` and the negative values (including `-0.0` and `-inf`) are mapped to `+0.0`, and `+inf` to `MAX`.",
+    f32 => (0.0, f32::MAX),
+    f64 => (0.0, f64::MAX)
+);
+impl_clamping_from!(
+    positive,
+    StrictlyPositive,
+    " `NaN`, the zeros and the negative values (including `-inf`) are mapped to `MIN_POSITIVE`.",
+    f32 => (f32::MIN_POSITIVE, f32::INFINITY),
+    f64 => (f64::MIN_POSITIVE, f64::INFINITY)
+);
+impl_clamping_from!(
+    positive,
+    StrictlyPositiveFinite,
+    " `NaN`, the zeros and the negative values (including `-inf`) are mapped to `MIN_POSITIVE`, and `+inf` to `MAX`.",
+    f32 => (f32::MIN_POSITIVE, f32::MAX),
+    f64 => (f64::MIN_POSITIVE, f64::MAX)
+);
+impl_clamping_from!(
+    negative,
+    Negative,
+    " `NaN` and the positive values (including `+0.0` and `+inf`) are mapped to `-0.0`.",
+    f32 => (-0.0, f32::NEG_INFINITY),
+    f64 => (-0.0, f64::NEG_INFINITY)
+);
+impl_clamping_from!(
+    negative,
+    NegativeFinite,
+    " `NaN` and the positive values (including `+0.0` and `+inf`) are mapped to `-0.0`, and `-inf` to `MIN`.",
+    f32 => (-0.0, f32::MIN),
+    f64 => (-0.0, f64::MIN)
+);
+impl_clamping_from!(
+    negative,
+    StrictlyNegative,
+    " `NaN`, the zeros and the positive values (including `+inf`) are mapped to `-MIN_POSITIVE`.",
+    f32 => (-f32::MIN_POSITIVE, f32::NEG_INFINITY),
+    f64 => (-f64::MIN_POSITIVE, f64::NEG_INFINITY)
+);
+impl_clamping_from!(
+    negative,
+    StrictlyNegativeFinite,
+    " `NaN`, the zeros and the positive values (including `+inf`) are mapped to `-MIN_POSITIVE`, and `-inf` to `MIN`.",
+    f32 => (-f32::MIN_POSITIVE, f32::MIN),
+    f64 => (-f64::MIN_POSITIVE, f64::MIN)
+);
+
+// The types that accept an infinity can be converted into their finite counterpart,
+// either by failing on the infinities or by mapping `+inf` to `MAX` and `-inf` to `MIN`,
+// as every other value is already valid.
+
+macro_rules! impl_to_finite {
+    ($type:ident, $finite:ident, $float:ident, $inf:expr, $max:expr) => {
+        impl $type<$float> {
+            #[doc = concat!("Converts the value into a [`", stringify!($finite), "`].")]
+            ///
+            /// # Errors
+            /// Returns [`InvalidNumber::Infinite`] if the value is infinite.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            #[doc = concat!("let x = ", stringify!($type), "::<", stringify!($float), ">::new(", stringify!($inf), ").unwrap();")]
+            ///
+            /// assert_eq!(x.finite(), Err(InvalidNumber::Infinite));
+            /// ```
+            #[inline]
+            pub fn finite(self) -> Result<$finite<$float>, InvalidNumber> {
+                let value = self.get();
+
+                if value.is_infinite() {
+                    return Err(InvalidNumber::Infinite);
+                }
+
+                // # Safety
+                // `self` is neither `NaN` nor infinite, and keeps its other properties.
+                Ok(unsafe { $finite::<$float>::new_unchecked(value) })
+            }
+
+            #[doc = concat!("Converts the value into a [`", stringify!($finite), "`], mapping `+inf` to `MAX` and `-inf` to `MIN`.")]
+            ///
+            /// The finite values are kept unchanged.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            #[doc = concat!("let x = ", stringify!($type), "::<", stringify!($float), ">::new(", stringify!($inf), ").unwrap();")]
+            ///
+            #[doc = concat!("let y: ", stringify!($finite), "<", stringify!($float), "> = x.clamp_to_finite();")]
+            ///
+            #[doc = concat!("assert_eq!(y, ", stringify!($max), ");")]
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn clamp_to_finite(self) -> $finite<$float> {
+                let value = self.get();
+
+                let value = if value.is_infinite() {
+                    if value.is_sign_positive() {
+                        $float::MAX
+                    } else {
+                        $float::MIN
+                    }
+                } else {
+                    value
+                };
+
+                // # Safety
+                // `self` is not `NaN`, and its infinities are mapped to the finite values of the same sign.
+                unsafe { $finite::<$float>::new_unchecked(value) }
+            }
+        }
+    };
+    ($type:ident, $finite:ident, $($float:ident => ($inf:expr, $max:expr)),*) => {
+        $(
+            impl_to_finite!($type, $finite, $float, $inf, $max);
+        )*
+    };
+}
+
+impl_to_finite!(
+    NonNaN,
+    NonNaNFinite,
+    f32 => (f32::NEG_INFINITY, f32::MIN),
+    f64 => (f64::NEG_INFINITY, f64::MIN)
+);
+impl_to_finite!(
+    NonZeroNonNaN,
+    NonZeroNonNaNFinite,
+    f32 => (f32::INFINITY, f32::MAX),
+    f64 => (f64::INFINITY, f64::MAX)
+);
+impl_to_finite!(
+    Positive,
+    PositiveFinite,
+    f32 => (f32::INFINITY, f32::MAX),
+    f64 => (f64::INFINITY, f64::MAX)
+);
+impl_to_finite!(
+    Negative,
+    NegativeFinite,
+    f32 => (f32::NEG_INFINITY, f32::MIN),
+    f64 => (f64::NEG_INFINITY, f64::MIN)
+);
+impl_to_finite!(
+    StrictlyPositive,
+    StrictlyPositiveFinite,
+    f32 => (f32::INFINITY, f32::MAX),
+    f64 => (f64::INFINITY, f64::MAX)
+);
+impl_to_finite!(
+    StrictlyNegative,
+    StrictlyNegativeFinite,
+    f32 => (f32::NEG_INFINITY, f32::MIN),
+    f64 => (f64::NEG_INFINITY, f64::MIN)
+);
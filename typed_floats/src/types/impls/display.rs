@@ -9,14 +9,42 @@ macro_rules! impl_display {
         impl core::fmt::Display for $type<f32> {
             #[inline]
             fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                write!(f, "{}", self.0)
+                core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl core::fmt::LowerExp for $type<f32> {
+            #[inline]
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::LowerExp::fmt(&self.0, f)
+            }
+        }
+
+        impl core::fmt::UpperExp for $type<f32> {
+            #[inline]
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::UpperExp::fmt(&self.0, f)
             }
         }
 
         impl core::fmt::Display for $type<f64> {
             #[inline]
             fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                write!(f, "{}", self.0)
+                core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl core::fmt::LowerExp for $type<f64> {
+            #[inline]
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::LowerExp::fmt(&self.0, f)
+            }
+        }
+
+        impl core::fmt::UpperExp for $type<f64> {
+            #[inline]
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::UpperExp::fmt(&self.0, f)
             }
         }
 
@@ -26,9 +54,13 @@ macro_rules! impl_display {
 
             for &value in &values_f32 {
                 if let Ok(t) = $type::<f32>::new(value) {
-                    let str1 = format!("{}", t);
-                    let str2 = format!("{}", value);
-                    assert_eq!(str1, str2);
+                    assert_eq!(format!("{}", t), format!("{}", value));
+                    assert_eq!(format!("{:.2}", t), format!("{:.2}", value));
+                    assert_eq!(format!("{:>12}", t), format!("{:>12}", value));
+                    assert_eq!(format!("{:+}", t), format!("{:+}", value));
+                    assert_eq!(format!("{:e}", t), format!("{:e}", value));
+                    assert_eq!(format!("{:.3E}", t), format!("{:.3E}", value));
+                    assert_eq!(format!("{:*^20.1e}", t), format!("{:*^20.1e}", value));
                 }
             }
 
@@ -36,9 +68,13 @@ macro_rules! impl_display {
 
             for &value in &values_f64 {
                 if let Ok(t) = $type::<f64>::new(value) {
-                    let str1 = format!("{}", t);
-                    let str2 = format!("{}", value);
-                    assert_eq!(str1, str2);
+                    assert_eq!(format!("{}", t), format!("{}", value));
+                    assert_eq!(format!("{:.2}", t), format!("{:.2}", value));
+                    assert_eq!(format!("{:>12}", t), format!("{:>12}", value));
+                    assert_eq!(format!("{:+}", t), format!("{:+}", value));
+                    assert_eq!(format!("{:e}", t), format!("{:e}", value));
+                    assert_eq!(format!("{:.3E}", t), format!("{:.3E}", value));
+                    assert_eq!(format!("{:*^20.1e}", t), format!("{:*^20.1e}", value));
                 }
             }
         }
@@ -0,0 +1,63 @@
+use crate::{
+    Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN, NonZeroNonNaNFinite, Positive,
+    PositiveFinite, StrictlyNegative, StrictlyNegativeFinite, StrictlyPositive,
+    StrictlyPositiveFinite,
+};
+
+// The mantissa has the sign of the number and is zero or infinite only if the number is,
+// so it is always valid for the same type as the number.
+
+macro_rules! impl_frexp {
+    ($type:ident, $float:ident, $frexp:ident) => {
+        impl $type<$float> {
+            /// Breaks the number into a normalized fraction and an integral power of two.
+            ///
+            /// The fraction has the sign of `self` and an absolute value in `[0.5, 1.0)`,
+            /// except for zero and infinity which are returned unchanged with an exponent of `0`.
+            /// It is the inverse of `ldexp`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            /// let x: StrictlyPositiveFinite = 12.0.try_into().unwrap();
+            ///
+            /// let (mantissa, exp) = x.frexp();
+            ///
+            /// assert_eq!(mantissa, 0.75);
+            /// assert_eq!(exp, 4);
+            /// assert_eq!(mantissa.ldexp(exp), x);
+            ///
+            /// let y: NonNaN = (-0.0).try_into().unwrap();
+            ///
+            /// assert_eq!(y.frexp(), (y, 0));
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn frexp(self) -> (Self, i32) {
+                let (mantissa, exp) = libm::$frexp(self.get());
+
+                // # Safety
+                // The mantissa is valid for the same type as `self`
+                (unsafe { Self::new_unchecked(mantissa) }, exp)
+            }
+        }
+    };
+    ($type:ident) => {
+        impl_frexp!($type, f32, frexpf);
+        impl_frexp!($type, f64, frexp);
+    };
+}
+
+impl_frexp!(NonNaN);
+impl_frexp!(NonNaNFinite);
+impl_frexp!(NonZeroNonNaN);
+impl_frexp!(NonZeroNonNaNFinite);
+impl_frexp!(StrictlyPositive);
+impl_frexp!(StrictlyNegative);
+impl_frexp!(Positive);
+impl_frexp!(Negative);
+impl_frexp!(StrictlyPositiveFinite);
+impl_frexp!(StrictlyNegativeFinite);
+impl_frexp!(PositiveFinite);
+impl_frexp!(NegativeFinite);
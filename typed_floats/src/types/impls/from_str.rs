@@ -69,3 +69,23 @@ impl_from_str!(strictly_positive, StrictlyPositive);
 impl_from_str!(strictly_negative, StrictlyNegative);
 impl_from_str!(strictly_positive_finite, StrictlyPositiveFinite);
 impl_from_str!(strictly_negative_finite, StrictlyNegativeFinite);
+
+#[test]
+fn errors() {
+    use crate::InvalidNumber;
+
+    let res = "-1".parse::<Positive<f64>>();
+    assert!(matches!(
+        res,
+        Err(FromStrError::InvalidNumber(InvalidNumber::Negative))
+    ));
+
+    let res = "abc".parse::<Positive<f64>>();
+    assert!(matches!(res, Err(FromStrError::ParseFloatError(_))));
+
+    let res = "NaN".parse::<NonNaN<f32>>();
+    assert!(matches!(
+        res,
+        Err(FromStrError::InvalidNumber(InvalidNumber::NaN))
+    ));
+}
@@ -1,7 +1,7 @@
 use crate::{
     InvalidNumber, Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN,
-    NonZeroNonNaNFinite, Positive, PositiveFinite, StrictlyNegative, StrictlyNegativeFinite,
-    StrictlyPositive, StrictlyPositiveFinite,
+    NonZeroNonNaNFinite, Positive, PositiveFinite, PreservingError, StrictlyNegative,
+    StrictlyNegativeFinite, StrictlyPositive, StrictlyPositiveFinite,
 };
 
 macro_rules! impl_from {
@@ -40,6 +40,102 @@ macro_rules! impl_from {
             }
         }
 
+        impl From<$type<f32>> for $type<f64> {
+            #[inline]
+            fn from(value: $type<f32>) -> Self {
+                // # Safety
+                // The conversion to `f64` is exact, so the value is still valid
+                unsafe { Self::new_unchecked(f64::from(value.0)) }
+            }
+        }
+
+        // The conversion to `f32` may overflow to infinity or be rounded to zero
+        impl TryFrom<$type<f64>> for $type<f32> {
+            type Error = InvalidNumber;
+
+            #[inline]
+            #[allow(clippy::cast_possible_truncation)] // The result is checked by `new`
+            fn try_from(value: $type<f64>) -> Result<Self, Self::Error> {
+                Self::new(value.0 as f32)
+            }
+        }
+
+        impl $type<f32> {
+            /// Creates a new value from a `f64`, rounded to the nearest `f32`.
+            ///
+            /// It is not implemented as `TryFrom<f64>`, which would make `try_from` ambiguous for the default `f64` types.
+            ///
+            /// # Errors
+            /// Returns an error if the rounded value is not valid, e.g. when a small value is rounded to zero
+            /// or a large one overflows to infinity.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            #[doc = concat!("let x = ", stringify!($type), "::<f32>::try_from_f64(1e300);")]
+            #[doc = concat!("let y = ", stringify!($type), "::<f32>::try_from_f64(f64::NAN);")]
+            ///
+            #[doc = concat!("assert_eq!(x.ok(), ", stringify!($type), "::<f32>::new(f32::INFINITY).ok());")]
+            /// assert_eq!(y, Err(InvalidNumber::NaN));
+            /// ```
+            #[inline]
+            #[allow(clippy::cast_possible_truncation)] // The result is checked by `new`
+            pub fn try_from_f64(value: f64) -> Result<Self, InvalidNumber> {
+                Self::new(value as f32)
+            }
+
+            /// Creates a new value from a primitive type, like `new`,
+            /// but a `NaN` is reported with its bits, which keep its payload.
+            ///
+            /// # Errors
+            /// Returns [`PreservingError::NaN`] with the bits of the value if it is `NaN`,
+            /// and [`PreservingError::InvalidNumber`] with the same error as `new` if it is not valid otherwise.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            /// let nan = f32::from_bits(0x7fc0_0123);
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($type), "::<f32>::try_from_preserving(nan), Err(PreservingError::NaN(0x7fc0_0123)));")]
+            /// ```
+            #[inline]
+            pub fn try_from_preserving(value: f32) -> Result<Self, PreservingError<u32>> {
+                if value.is_nan() {
+                    return Err(PreservingError::NaN(value.to_bits()));
+                }
+
+                Ok(Self::new(value)?)
+            }
+        }
+
+        impl $type<f64> {
+            /// Creates a new value from a primitive type, like `new`,
+            /// but a `NaN` is reported with its bits, which keep its payload.
+            ///
+            /// # Errors
+            /// Returns [`PreservingError::NaN`] with the bits of the value if it is `NaN`,
+            /// and [`PreservingError::InvalidNumber`] with the same error as `new` if it is not valid otherwise.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            /// let nan = f64::from_bits(0x7ff8_0000_0000_0123);
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($type), "::<f64>::try_from_preserving(nan), Err(PreservingError::NaN(0x7ff8_0000_0000_0123)));")]
+            /// ```
+            #[inline]
+            pub fn try_from_preserving(value: f64) -> Result<Self, PreservingError<u64>> {
+                if value.is_nan() {
+                    return Err(PreservingError::NaN(value.to_bits()));
+                }
+
+                Ok(Self::new(value)?)
+            }
+        }
+
         #[test]
         fn $test() {
             let values_f32 = crate::tf32::TEST_VALUES;
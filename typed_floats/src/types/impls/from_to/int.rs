@@ -38,6 +38,26 @@ macro_rules! impl_try_from_int {
     };
 }
 
+// `u128::MAX` is greater than `f32::MAX`, so the conversion can overflow to infinity
+macro_rules! impl_from_int_f64_try_from_f32 {
+    ($type:ident,$int:ident) => {
+        impl TryFrom<$int> for $type<f32> {
+            type Error = InvalidNumber;
+
+            #[inline]
+            fn try_from(value: $int) -> Result<Self, Self::Error> {
+                Self::new(value as f32)
+            }
+        }
+        impl From<$int> for $type<f64> {
+            #[inline]
+            fn from(value: $int) -> Self {
+                unsafe { Self::new_unchecked(value as f64) }
+            }
+        }
+    };
+}
+
 #[cfg(test)]
 macro_rules! impl_test {
     ($type:ident, $int:ident, $uint:ident) => {
@@ -78,6 +98,8 @@ macro_rules! impl_tests {
         impl_test!($type, i16, u16);
         impl_test!($type, i32, u32);
         impl_test!($type, i64, u64);
+        impl_test!($type, i128, u128);
+        impl_test!($type, isize, usize);
     };
 }
 
@@ -109,7 +131,7 @@ macro_rules! impl_try_from_ints {
 
 // https://doc.rust-lang.org/1.49.0/reference/expressions/operator-expr.html#type-cast-expressions
 
-// with the current set of numeric types, overflow can only happen on u128 as f64
+// with the current set of numeric types, overflow can only happen on u128 as f32
 
 // from signed integers
 mod ints {
@@ -119,30 +141,79 @@ mod ints {
         StrictlyPositive, StrictlyPositiveFinite,
     };
 
-    impl_from_ints!(non_nan, NonNaN, i8, i16, i32, i64);
-    impl_try_from_ints!(non_zero_non_nan, NonZeroNonNaN, i8, i16, i32, i64);
-    impl_from_ints!(non_nan_finite, NonNaNFinite, i8, i16, i32, i64);
+    impl_from_ints!(non_nan, NonNaN, i8, i16, i32, i64, i128, isize);
+    impl_try_from_ints!(
+        non_zero_non_nan,
+        NonZeroNonNaN,
+        i8,
+        i16,
+        i32,
+        i64,
+        i128,
+        isize
+    );
+    impl_from_ints!(non_nan_finite, NonNaNFinite, i8, i16, i32, i64, i128, isize);
     impl_try_from_ints!(
         non_zero_non_nan_finite,
         NonZeroNonNaNFinite,
         i8,
         i16,
         i32,
-        i64
+        i64,
+        i128,
+        isize
+    );
+    impl_try_from_ints!(positive, Positive, i8, i16, i32, i64, i128, isize);
+    impl_try_from_ints!(negative, Negative, i8, i16, i32, i64, i128, isize);
+    impl_try_from_ints!(
+        positive_finite,
+        PositiveFinite,
+        i8,
+        i16,
+        i32,
+        i64,
+        i128,
+        isize
+    );
+    impl_try_from_ints!(
+        negative_finite,
+        NegativeFinite,
+        i8,
+        i16,
+        i32,
+        i64,
+        i128,
+        isize
+    );
+    impl_try_from_ints!(
+        strictly_positive,
+        StrictlyPositive,
+        i8,
+        i16,
+        i32,
+        i64,
+        i128,
+        isize
+    );
+    impl_try_from_ints!(
+        strictly_negative,
+        StrictlyNegative,
+        i8,
+        i16,
+        i32,
+        i64,
+        i128,
+        isize
     );
-    impl_try_from_ints!(positive, Positive, i8, i16, i32, i64);
-    impl_try_from_ints!(negative, Negative, i8, i16, i32, i64);
-    impl_try_from_ints!(positive_finite, PositiveFinite, i8, i16, i32, i64);
-    impl_try_from_ints!(negative_finite, NegativeFinite, i8, i16, i32, i64);
-    impl_try_from_ints!(strictly_positive, StrictlyPositive, i8, i16, i32, i64);
-    impl_try_from_ints!(strictly_negative, StrictlyNegative, i8, i16, i32, i64);
     impl_try_from_ints!(
         strictly_positive_finite,
         StrictlyPositiveFinite,
         i8,
         i16,
         i32,
-        i64
+        i64,
+        i128,
+        isize
     );
     impl_try_from_ints!(
         strictly_negative_finite,
@@ -150,7 +221,9 @@ mod ints {
         i8,
         i16,
         i32,
-        i64
+        i64,
+        i128,
+        isize
     );
 }
 
@@ -163,30 +236,72 @@ mod uints {
         StrictlyPositive, StrictlyPositiveFinite,
     };
 
-    impl_from_ints!(non_nan, NonNaN, u8, u16, u32, u64);
-    impl_try_from_ints!(non_zero_non_nan, NonZeroNonNaN, u8, u16, u32, u64);
-    impl_from_ints!(non_nan_finite, NonNaNFinite, u8, u16, u32, u64);
+    impl_from_ints!(non_nan, NonNaN, u8, u16, u32, u64, u128, usize);
+    impl_try_from_ints!(
+        non_zero_non_nan,
+        NonZeroNonNaN,
+        u8,
+        u16,
+        u32,
+        u64,
+        u128,
+        usize
+    );
+    impl_from_ints!(non_nan_finite, NonNaNFinite, u8, u16, u32, u64, usize);
+    impl_from_int_f64_try_from_f32!(NonNaNFinite, u128);
     impl_try_from_ints!(
         non_zero_non_nan_finite,
         NonZeroNonNaNFinite,
         u8,
         u16,
         u32,
-        u64
+        u64,
+        u128,
+        usize
+    );
+    impl_from_ints!(positive, Positive, u8, u16, u32, u64, u128, usize);
+    impl_try_from_ints!(negative, Negative, u8, u16, u32, u64, u128, usize);
+    impl_from_ints!(positive_finite, PositiveFinite, u8, u16, u32, u64, usize);
+    impl_from_int_f64_try_from_f32!(PositiveFinite, u128);
+    impl_try_from_ints!(
+        negative_finite,
+        NegativeFinite,
+        u8,
+        u16,
+        u32,
+        u64,
+        u128,
+        usize
+    );
+    impl_try_from_ints!(
+        strictly_positive,
+        StrictlyPositive,
+        u8,
+        u16,
+        u32,
+        u64,
+        u128,
+        usize
+    );
+    impl_try_from_ints!(
+        strictly_negative,
+        StrictlyNegative,
+        u8,
+        u16,
+        u32,
+        u64,
+        u128,
+        usize
     );
-    impl_from_ints!(positive, Positive, u8, u16, u32, u64);
-    impl_try_from_ints!(negative, Negative, u8, u16, u32, u64);
-    impl_from_ints!(positive_finite, PositiveFinite, u8, u16, u32, u64);
-    impl_try_from_ints!(negative_finite, NegativeFinite, u8, u16, u32, u64);
-    impl_try_from_ints!(strictly_positive, StrictlyPositive, u8, u16, u32, u64);
-    impl_try_from_ints!(strictly_negative, StrictlyNegative, u8, u16, u32, u64);
     impl_try_from_ints!(
         strictly_positive_finite,
         StrictlyPositiveFinite,
         u8,
         u16,
         u32,
-        u64
+        u64,
+        u128,
+        usize
     );
     impl_try_from_ints!(
         strictly_negative_finite,
@@ -194,6 +309,34 @@ mod uints {
         u8,
         u16,
         u32,
-        u64
+        u64,
+        u128,
+        usize
     );
+
+    #[test]
+    fn u128_overflow() {
+        assert_eq!(
+            PositiveFinite::<f32>::try_from(u128::MAX),
+            Err(InvalidNumber::Infinite)
+        );
+        assert_eq!(
+            StrictlyPositiveFinite::<f32>::try_from(u128::MAX),
+            Err(InvalidNumber::Infinite)
+        );
+        assert_eq!(Positive::<f32>::from(u128::MAX), f32::INFINITY);
+        assert_eq!(PositiveFinite::<f64>::from(u128::MAX), u128::MAX as f64);
+
+        // The largest `u128` that doesn't round up to infinity
+        let max = u128::MAX - (1 << 103);
+
+        assert_eq!(
+            PositiveFinite::<f32>::try_from(max),
+            PositiveFinite::<f32>::try_from(f32::MAX)
+        );
+        assert_eq!(
+            PositiveFinite::<f32>::try_from(max + 1),
+            Err(InvalidNumber::Infinite)
+        );
+    }
 }
@@ -1,3 +1,4 @@
 mod floats;
 mod int;
 mod non_zero_int;
+mod to_int;
@@ -38,6 +38,26 @@ macro_rules! impl_try_from_int {
     };
 }
 
+// `NonZeroU128::MAX` is greater than `f32::MAX`, so the conversion can overflow to infinity
+macro_rules! impl_from_int_f64_try_from_f32 {
+    ($type:ident,$int:ident) => {
+        impl TryFrom<$int> for $type<f32> {
+            type Error = InvalidNumber;
+
+            #[inline]
+            fn try_from(value: $int) -> Result<Self, Self::Error> {
+                Self::new(value.get() as f32)
+            }
+        }
+        impl From<$int> for $type<f64> {
+            #[inline]
+            fn from(value: $int) -> Self {
+                unsafe { Self::new_unchecked(value.get() as f64) }
+            }
+        }
+    };
+}
+
 macro_rules! impl_from_ints {
     ($test:ident, $type:ident, $($int:ident),*) => {
         $(
@@ -99,19 +119,21 @@ macro_rules! impl_test {
 #[cfg(test)]
 macro_rules! impl_tests {
     ($type:ident) => {
-        use core::num::{NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8};
-        use core::num::{NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8};
+        use core::num::{NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize};
+        use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
 
         impl_test!($type, i8, u8, NonZeroI8, NonZeroU8);
         impl_test!($type, i16, u16, NonZeroI16, NonZeroU16);
         impl_test!($type, i32, u32, NonZeroI32, NonZeroU32);
         impl_test!($type, i64, u64, NonZeroI64, NonZeroU64);
+        impl_test!($type, i128, u128, NonZeroI128, NonZeroU128);
+        impl_test!($type, isize, usize, NonZeroIsize, NonZeroUsize);
     };
 }
 
 // https://doc.rust-lang.org/1.49.0/reference/expressions/operator-expr.html#type-cast-expressions
 
-// with the current set of numeric types, overflow can only happen on u128 as f64
+// with the current set of numeric types, overflow can only happen on u128 as f32
 
 // from non-zero signed integers
 
@@ -122,16 +144,27 @@ mod ints {
         StrictlyPositive, StrictlyPositiveFinite,
     };
 
-    use core::num::{NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8};
+    use core::num::{NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize};
 
-    impl_from_ints!(non_nan, NonNaN, NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64);
+    impl_from_ints!(
+        non_nan,
+        NonNaN,
+        NonZeroI8,
+        NonZeroI16,
+        NonZeroI32,
+        NonZeroI64,
+        NonZeroI128,
+        NonZeroIsize
+    );
     impl_from_ints!(
         non_zero_non_nan,
         NonZeroNonNaN,
         NonZeroI8,
         NonZeroI16,
         NonZeroI32,
-        NonZeroI64
+        NonZeroI64,
+        NonZeroI128,
+        NonZeroIsize
     );
     impl_from_ints!(
         non_nan_finite,
@@ -139,25 +172,49 @@ mod ints {
         NonZeroI8,
         NonZeroI16,
         NonZeroI32,
-        NonZeroI64
+        NonZeroI64,
+        NonZeroI128,
+        NonZeroIsize
     );
-    impl_try_from_ints!(
+    impl_from_ints!(
         non_zero_non_nan_finite,
         NonZeroNonNaNFinite,
         NonZeroI8,
         NonZeroI16,
         NonZeroI32,
-        NonZeroI64
+        NonZeroI64,
+        NonZeroI128,
+        NonZeroIsize
+    );
+    impl_try_from_ints!(
+        positive,
+        Positive,
+        NonZeroI8,
+        NonZeroI16,
+        NonZeroI32,
+        NonZeroI64,
+        NonZeroI128,
+        NonZeroIsize
+    );
+    impl_try_from_ints!(
+        negative,
+        Negative,
+        NonZeroI8,
+        NonZeroI16,
+        NonZeroI32,
+        NonZeroI64,
+        NonZeroI128,
+        NonZeroIsize
     );
-    impl_try_from_ints!(positive, Positive, NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64);
-    impl_try_from_ints!(negative, Negative, NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64);
     impl_try_from_ints!(
         positive_finite,
         PositiveFinite,
         NonZeroI8,
         NonZeroI16,
         NonZeroI32,
-        NonZeroI64
+        NonZeroI64,
+        NonZeroI128,
+        NonZeroIsize
     );
     impl_try_from_ints!(
         negative_finite,
@@ -165,7 +222,9 @@ mod ints {
         NonZeroI8,
         NonZeroI16,
         NonZeroI32,
-        NonZeroI64
+        NonZeroI64,
+        NonZeroI128,
+        NonZeroIsize
     );
     impl_try_from_ints!(
         strictly_positive,
@@ -173,7 +232,9 @@ mod ints {
         NonZeroI8,
         NonZeroI16,
         NonZeroI32,
-        NonZeroI64
+        NonZeroI64,
+        NonZeroI128,
+        NonZeroIsize
     );
     impl_try_from_ints!(
         strictly_negative,
@@ -181,7 +242,9 @@ mod ints {
         NonZeroI8,
         NonZeroI16,
         NonZeroI32,
-        NonZeroI64
+        NonZeroI64,
+        NonZeroI128,
+        NonZeroIsize
     );
     impl_try_from_ints!(
         strictly_positive_finite,
@@ -189,7 +252,9 @@ mod ints {
         NonZeroI8,
         NonZeroI16,
         NonZeroI32,
-        NonZeroI64
+        NonZeroI64,
+        NonZeroI128,
+        NonZeroIsize
     );
     impl_try_from_ints!(
         strictly_negative_finite,
@@ -197,8 +262,23 @@ mod ints {
         NonZeroI8,
         NonZeroI16,
         NonZeroI32,
-        NonZeroI64
+        NonZeroI64,
+        NonZeroI128,
+        NonZeroIsize
     );
+
+    #[test]
+    fn signed_into_non_zero_finite() {
+        let (Some(a), Some(b)) = (NonZeroI16::new(-5), NonZeroI128::new(i128::MIN)) else {
+            unreachable!()
+        };
+
+        let a: NonZeroNonNaNFinite = a.into();
+        let b: NonZeroNonNaNFinite<f32> = b.into();
+
+        assert_eq!(a, -5.0);
+        assert_eq!(b, i128::MIN as f32);
+    }
 }
 
 // from non-zero unsigned integers
@@ -210,16 +290,27 @@ mod uints {
         StrictlyPositive, StrictlyPositiveFinite,
     };
 
-    use core::num::{NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8};
+    use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
 
-    impl_from_ints!(non_nan, NonNaN, NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64);
+    impl_from_ints!(
+        non_nan,
+        NonNaN,
+        NonZeroU8,
+        NonZeroU16,
+        NonZeroU32,
+        NonZeroU64,
+        NonZeroU128,
+        NonZeroUsize
+    );
     impl_from_ints!(
         non_zero_non_nan,
         NonZeroNonNaN,
         NonZeroU8,
         NonZeroU16,
         NonZeroU32,
-        NonZeroU64
+        NonZeroU64,
+        NonZeroU128,
+        NonZeroUsize
     );
     impl_from_ints!(
         non_nan_finite,
@@ -227,33 +318,59 @@ mod uints {
         NonZeroU8,
         NonZeroU16,
         NonZeroU32,
-        NonZeroU64
+        NonZeroU64,
+        NonZeroUsize
     );
+    impl_from_int_f64_try_from_f32!(NonNaNFinite, NonZeroU128);
     impl_from_ints!(
         non_zero_non_nan_finite,
         NonZeroNonNaNFinite,
         NonZeroU8,
         NonZeroU16,
         NonZeroU32,
-        NonZeroU64
+        NonZeroU64,
+        NonZeroUsize
+    );
+    impl_from_int_f64_try_from_f32!(NonZeroNonNaNFinite, NonZeroU128);
+    impl_from_ints!(
+        positive,
+        Positive,
+        NonZeroU8,
+        NonZeroU16,
+        NonZeroU32,
+        NonZeroU64,
+        NonZeroU128,
+        NonZeroUsize
+    );
+    impl_try_from_ints!(
+        negative,
+        Negative,
+        NonZeroU8,
+        NonZeroU16,
+        NonZeroU32,
+        NonZeroU64,
+        NonZeroU128,
+        NonZeroUsize
     );
-    impl_from_ints!(positive, Positive, NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64);
-    impl_try_from_ints!(negative, Negative, NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64);
     impl_from_ints!(
         positive_finite,
         PositiveFinite,
         NonZeroU8,
         NonZeroU16,
         NonZeroU32,
-        NonZeroU64
+        NonZeroU64,
+        NonZeroUsize
     );
+    impl_from_int_f64_try_from_f32!(PositiveFinite, NonZeroU128);
     impl_try_from_ints!(
         negative_finite,
         NegativeFinite,
         NonZeroU8,
         NonZeroU16,
         NonZeroU32,
-        NonZeroU64
+        NonZeroU64,
+        NonZeroU128,
+        NonZeroUsize
     );
     impl_from_ints!(
         strictly_positive,
@@ -261,7 +378,9 @@ mod uints {
         NonZeroU8,
         NonZeroU16,
         NonZeroU32,
-        NonZeroU64
+        NonZeroU64,
+        NonZeroU128,
+        NonZeroUsize
     );
     impl_try_from_ints!(
         strictly_negative,
@@ -269,7 +388,9 @@ mod uints {
         NonZeroU8,
         NonZeroU16,
         NonZeroU32,
-        NonZeroU64
+        NonZeroU64,
+        NonZeroU128,
+        NonZeroUsize
     );
     impl_from_ints!(
         strictly_positive_finite,
@@ -277,14 +398,32 @@ mod uints {
         NonZeroU8,
         NonZeroU16,
         NonZeroU32,
-        NonZeroU64
+        NonZeroU64,
+        NonZeroUsize
     );
+    impl_from_int_f64_try_from_f32!(StrictlyPositiveFinite, NonZeroU128);
     impl_try_from_ints!(
         strictly_negative_finite,
         StrictlyNegativeFinite,
         NonZeroU8,
         NonZeroU16,
         NonZeroU32,
-        NonZeroU64
+        NonZeroU64,
+        NonZeroU128,
+        NonZeroUsize
     );
+
+    #[test]
+    fn u128_overflow() {
+        let Some(max) = NonZeroU128::new(u128::MAX) else {
+            unreachable!()
+        };
+
+        assert_eq!(
+            StrictlyPositiveFinite::<f32>::try_from(max),
+            Err(InvalidNumber::Infinite)
+        );
+        assert_eq!(StrictlyPositive::<f32>::from(max), f32::INFINITY);
+        assert_eq!(StrictlyPositiveFinite::<f64>::from(max), u128::MAX as f64);
+    }
 }
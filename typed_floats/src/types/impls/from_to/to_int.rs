@@ -0,0 +1,80 @@
+use crate::{
+    IntegerConversionError, Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN,
+    NonZeroNonNaNFinite, Positive, PositiveFinite, StrictlyNegative, StrictlyNegativeFinite,
+    StrictlyPositive, StrictlyPositiveFinite,
+};
+
+// `fract()` is not available without `std` or `libm`, so the value is converted
+// and converted back: within the range of the integer, the conversion is only
+// exact for integers.
+
+macro_rules! impl_try_into_int {
+    ($type:ident, $float:ident, $int:ident) => {
+        impl TryFrom<$type<$float>> for $int {
+            type Error = IntegerConversionError;
+
+            #[inline]
+            // The casts are checked and the comparison must be exact
+            #[allow(clippy::float_cmp, clippy::cast_possible_truncation)]
+            #[allow(
+                clippy::cast_precision_loss,
+                clippy::cast_sign_loss,
+                clippy::cast_lossless
+            )]
+            fn try_from(value: $type<$float>) -> Result<Self, Self::Error> {
+                let value = value.get();
+
+                // Both bounds are powers of two (or zero), so they are exact.
+                // `MAX + 1` may be infinite for `f32`, which is still a valid bound
+                let min = $int::MIN as $float;
+                let max = ($int::MAX / 2 + 1) as $float * 2.0;
+
+                if value < min || value >= max {
+                    return Err(IntegerConversionError::OutOfRange);
+                }
+
+                let int = value as $int;
+
+                if int as $float == value {
+                    Ok(int)
+                } else {
+                    Err(IntegerConversionError::NotAnInteger)
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_try_into_ints {
+    ($type:ident) => {
+        impl_try_into_ints!($type, f32);
+        impl_try_into_ints!($type, f64);
+    };
+    ($type:ident, $float:ident) => {
+        impl_try_into_int!($type, $float, i8);
+        impl_try_into_int!($type, $float, i16);
+        impl_try_into_int!($type, $float, i32);
+        impl_try_into_int!($type, $float, i64);
+        impl_try_into_int!($type, $float, i128);
+        impl_try_into_int!($type, $float, isize);
+        impl_try_into_int!($type, $float, u8);
+        impl_try_into_int!($type, $float, u16);
+        impl_try_into_int!($type, $float, u32);
+        impl_try_into_int!($type, $float, u64);
+        impl_try_into_int!($type, $float, u128);
+        impl_try_into_int!($type, $float, usize);
+    };
+}
+
+impl_try_into_ints!(NonNaN);
+impl_try_into_ints!(NonNaNFinite);
+impl_try_into_ints!(NonZeroNonNaN);
+impl_try_into_ints!(NonZeroNonNaNFinite);
+impl_try_into_ints!(StrictlyPositive);
+impl_try_into_ints!(StrictlyNegative);
+impl_try_into_ints!(Positive);
+impl_try_into_ints!(Negative);
+impl_try_into_ints!(StrictlyPositiveFinite);
+impl_try_into_ints!(StrictlyNegativeFinite);
+impl_try_into_ints!(PositiveFinite);
+impl_try_into_ints!(NegativeFinite);
@@ -0,0 +1,64 @@
+use crate::{
+    Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN, NonZeroNonNaNFinite, Positive,
+    PositiveFinite, Sign, StrictlyNegative, StrictlyNegativeFinite, StrictlyPositive,
+    StrictlyPositiveFinite,
+};
+
+// `ln_gamma` is `+inf` at the poles (zero and the negative integers) and at both infinities,
+// and it is finite everywhere else, so it is never `NaN`.
+// It can be negative (e.g. `ln_gamma(1.5)`), so the result is a `NonNaN` for all types.
+
+macro_rules! impl_ln_gamma {
+    ($type:ident, $float:ident, $lgamma_r:ident) => {
+        impl $type<$float> {
+            /// Computes the natural logarithm of the absolute value of the gamma function of `self`,
+            /// and the sign of the gamma function.
+            ///
+            /// The result is `+inf` for zero, the negative integers and the infinities.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            /// let x: StrictlyNegativeFinite = (-0.5).try_into().unwrap();
+            ///
+            /// let (ln_gamma, sign): (NonNaN, Sign) = x.ln_gamma();
+            ///
+            /// assert!((ln_gamma.get() - 1.2655121234846454).abs() < 1e-10);
+            /// assert_eq!(sign, Sign::Negative);
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn ln_gamma(self) -> (NonNaN<$float>, Sign) {
+                let (ln_gamma, sign) = libm::$lgamma_r(self.get());
+
+                let sign = if sign < 0 {
+                    Sign::Negative
+                } else {
+                    Sign::Positive
+                };
+
+                // # Safety
+                // `ln_gamma` is never `NaN` when `self` is not `NaN`
+                (unsafe { NonNaN::<$float>::new_unchecked(ln_gamma) }, sign)
+            }
+        }
+    };
+    ($type:ident) => {
+        impl_ln_gamma!($type, f32, lgammaf_r);
+        impl_ln_gamma!($type, f64, lgamma_r);
+    };
+}
+
+impl_ln_gamma!(NonNaN);
+impl_ln_gamma!(NonNaNFinite);
+impl_ln_gamma!(NonZeroNonNaN);
+impl_ln_gamma!(NonZeroNonNaNFinite);
+impl_ln_gamma!(StrictlyPositive);
+impl_ln_gamma!(StrictlyNegative);
+impl_ln_gamma!(Positive);
+impl_ln_gamma!(Negative);
+impl_ln_gamma!(StrictlyPositiveFinite);
+impl_ln_gamma!(StrictlyNegativeFinite);
+impl_ln_gamma!(PositiveFinite);
+impl_ln_gamma!(NegativeFinite);
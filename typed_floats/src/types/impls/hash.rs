@@ -64,22 +64,8 @@ impl core::hash::Hash for NonNaNFinite<f64> {
     }
 }
 
-macro_rules! impl_hash {
+macro_rules! test_hash {
     ($test:ident, $type:ident) => {
-        impl core::hash::Hash for $type<f32> {
-            #[inline]
-            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
-                self.0.to_bits().hash(state);
-            }
-        }
-
-        impl core::hash::Hash for $type<f64> {
-            #[inline]
-            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
-                self.0.to_bits().hash(state);
-            }
-        }
-
         #[cfg(test)]
         mod $test {
             extern crate std;
@@ -140,12 +126,12 @@ macro_rules! impl_hash {
             fn f64() {
                 let mut hash_set = std::collections::HashSet::new();
 
-                let neg_zero = $type::<f32>::new(-0.0);
-                let pos_zero = $type::<f32>::new(0.0);
+                let neg_zero = $type::<f64>::new(-0.0);
+                let pos_zero = $type::<f64>::new(0.0);
                 let accept_both_zeroes = neg_zero.is_ok() && pos_zero.is_ok();
                 if accept_both_zeroes {
-                    let pos_one = $type::<f32>::new(1.0);
-                    let neg_one = $type::<f32>::new(-1.0);
+                    let pos_one = $type::<f64>::new(1.0);
+                    let neg_one = $type::<f64>::new(-1.0);
 
                     hash_set.insert(neg_zero.unwrap());
                     hash_set.insert(pos_zero.unwrap());
@@ -189,6 +175,29 @@ macro_rules! impl_hash {
     };
 }
 
+macro_rules! impl_hash {
+    ($test:ident, $type:ident) => {
+        impl core::hash::Hash for $type<f32> {
+            #[inline]
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.0.to_bits().hash(state);
+            }
+        }
+
+        impl core::hash::Hash for $type<f64> {
+            #[inline]
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.0.to_bits().hash(state);
+            }
+        }
+
+        test_hash!($test, $type);
+    };
+}
+
+test_hash!(non_nan, NonNaN);
+test_hash!(non_nan_finite, NonNaNFinite);
+
 impl_hash!(non_zero_non_nan, NonZeroNonNaN);
 impl_hash!(non_zero_non_nan_finite, NonZeroNonNaNFinite);
 impl_hash!(positive, Positive);
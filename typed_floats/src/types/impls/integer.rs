@@ -0,0 +1,87 @@
+use crate::{
+    Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN, NonZeroNonNaNFinite, Positive,
+    PositiveFinite, StrictlyNegative, StrictlyNegativeFinite, StrictlyPositive,
+    StrictlyPositiveFinite,
+};
+
+// `fract()` is not available without `std` or `libm`. From `2^(MANTISSA_DIGITS - 1)` and above,
+// every finite float is an integer. Below it, the value fits in an `i64` and the conversion
+// back is only exact for integers.
+
+macro_rules! impl_integer {
+    ($type:ident, $float:ident, $integer:expr, $fractional:expr) => {
+        impl $type<$float> {
+            /// Returns `true` if the number is finite and has no fractional part.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            #[doc = concat!("let x = ", stringify!($type), "::<", stringify!($float), ">::new(", stringify!($integer), ").unwrap();")]
+            #[doc = concat!("let y = ", stringify!($type), "::<", stringify!($float), ">::new(", stringify!($fractional), ").unwrap();")]
+            ///
+            /// assert!(x.is_integer());
+            /// assert!(!y.is_integer());
+            /// ```
+            #[inline]
+            #[must_use]
+            // The cast is exact for integers below the limit and the comparison must be exact
+            #[allow(clippy::float_cmp, clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+            pub fn is_integer(self) -> bool {
+                const LIMIT: $float = (1_u64 << ($float::MANTISSA_DIGITS - 1)) as $float;
+
+                let value = self.get();
+
+                if !value.is_finite() {
+                    return false;
+                }
+
+                if value >= LIMIT || value <= -LIMIT {
+                    return true;
+                }
+
+                (value as i64) as $float == value
+            }
+
+            /// Converts the number into an integer, if it is an integer in the range of `I`.
+            ///
+            /// It is a shorthand for `I::try_from(self).ok()`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            #[doc = concat!("let x = ", stringify!($type), "::<", stringify!($float), ">::new(", stringify!($integer), ").unwrap();")]
+            #[doc = concat!("let y = ", stringify!($type), "::<", stringify!($float), ">::new(", stringify!($fractional), ").unwrap();")]
+            ///
+            #[doc = concat!("assert_eq!(x.as_exact_int::<i32>(), Some(", stringify!($integer), " as i32));")]
+            /// assert_eq!(y.as_exact_int::<i32>(), None);
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn as_exact_int<I>(self) -> Option<I>
+            where
+                I: TryFrom<Self>,
+            {
+                I::try_from(self).ok()
+            }
+        }
+    };
+    ($type:ident, $integer:expr, $fractional:expr) => {
+        impl_integer!($type, f32, $integer, $fractional);
+        impl_integer!($type, f64, $integer, $fractional);
+    };
+}
+
+impl_integer!(NonNaN, 2.0, 2.5);
+impl_integer!(NonZeroNonNaN, 2.0, 2.5);
+impl_integer!(NonNaNFinite, 2.0, 2.5);
+impl_integer!(NonZeroNonNaNFinite, 2.0, 2.5);
+impl_integer!(Positive, 2.0, 2.5);
+impl_integer!(Negative, -2.0, -2.5);
+impl_integer!(PositiveFinite, 2.0, 2.5);
+impl_integer!(NegativeFinite, -2.0, -2.5);
+impl_integer!(StrictlyPositive, 2.0, 2.5);
+impl_integer!(StrictlyNegative, -2.0, -2.5);
+impl_integer!(StrictlyPositiveFinite, 2.0, 2.5);
+impl_integer!(StrictlyNegativeFinite, -2.0, -2.5);
@@ -0,0 +1,183 @@
+use crate::{
+    Negative, NegativeFinite, NonNaN, NonZeroNonNaNFinite, Positive, PositiveFinite,
+    StrictlyNegative, StrictlyNegativeFinite, StrictlyPositive, StrictlyPositiveFinite,
+};
+
+// The sum of numbers of the same sign can't be `NaN`: `+inf` and `-inf` are never added together.
+macro_rules! impl_sum {
+    ($output:ident, $zero:expr, $($type:ident),*) => {
+        $(
+            impl core::iter::Sum<$type<f32>> for $output<f32> {
+                #[inline]
+                fn sum<I: Iterator<Item = $type<f32>>>(iter: I) -> Self {
+                    let sum = iter.fold($zero, |acc, x| acc + x.get());
+
+                    // # Safety
+                    // This is safe because the sum of values of the same sign can't be `NaN`
+                    // and keeps that sign.
+                    unsafe { Self::new_unchecked(sum) }
+                }
+            }
+
+            impl core::iter::Sum<$type<f64>> for $output<f64> {
+                #[inline]
+                fn sum<I: Iterator<Item = $type<f64>>>(iter: I) -> Self {
+                    let sum = iter.fold($zero, |acc, x| acc + x.get());
+
+                    // # Safety
+                    // This is safe because the sum of values of the same sign can't be `NaN`
+                    // and keeps that sign.
+                    unsafe { Self::new_unchecked(sum) }
+                }
+            }
+
+            impl<'a> core::iter::Sum<&'a $type<f32>> for $output<f32> {
+                #[inline]
+                fn sum<I: Iterator<Item = &'a $type<f32>>>(iter: I) -> Self {
+                    iter.copied().sum()
+                }
+            }
+
+            impl<'a> core::iter::Sum<&'a $type<f64>> for $output<f64> {
+                #[inline]
+                fn sum<I: Iterator<Item = &'a $type<f64>>>(iter: I) -> Self {
+                    iter.copied().sum()
+                }
+            }
+        )*
+    };
+}
+
+// The product of finite and non-zero numbers can't be `NaN`:
+// once it underflows to zero it stays zero, and once it overflows to infinity it stays infinite.
+macro_rules! impl_product {
+    ($output:ident, $($type:ident),*) => {
+        $(
+            impl core::iter::Product<$type<f32>> for $output<f32> {
+                #[inline]
+                fn product<I: Iterator<Item = $type<f32>>>(iter: I) -> Self {
+                    let product = iter.fold(1.0, |acc, x| acc * x.get());
+
+                    // # Safety
+                    // This is safe because the product of finite and non-zero values can't be `NaN`.
+                    unsafe { Self::new_unchecked(product) }
+                }
+            }
+
+            impl core::iter::Product<$type<f64>> for $output<f64> {
+                #[inline]
+                fn product<I: Iterator<Item = $type<f64>>>(iter: I) -> Self {
+                    let product = iter.fold(1.0, |acc, x| acc * x.get());
+
+                    // # Safety
+                    // This is safe because the product of finite and non-zero values can't be `NaN`.
+                    unsafe { Self::new_unchecked(product) }
+                }
+            }
+
+            impl<'a> core::iter::Product<&'a $type<f32>> for $output<f32> {
+                #[inline]
+                fn product<I: Iterator<Item = &'a $type<f32>>>(iter: I) -> Self {
+                    iter.copied().product()
+                }
+            }
+
+            impl<'a> core::iter::Product<&'a $type<f64>> for $output<f64> {
+                #[inline]
+                fn product<I: Iterator<Item = &'a $type<f64>>>(iter: I) -> Self {
+                    iter.copied().product()
+                }
+            }
+        )*
+    };
+}
+
+impl_sum!(
+    Positive,
+    0.0,
+    Positive,
+    PositiveFinite,
+    StrictlyPositive,
+    StrictlyPositiveFinite
+);
+impl_sum!(
+    Negative,
+    -0.0,
+    Negative,
+    NegativeFinite,
+    StrictlyNegative,
+    StrictlyNegativeFinite
+);
+
+impl_product!(Positive, StrictlyPositiveFinite);
+impl_product!(NonNaN, StrictlyNegativeFinite, NonZeroNonNaNFinite);
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use crate::*;
+    use std::vec::Vec; // Required for the tests to compile in no_std mode
+
+    #[test]
+    fn sum() {
+        let values = tf64::TEST_VALUES
+            .iter()
+            .filter_map(|&x| tf64::PositiveFinite::new(x).ok())
+            .collect::<Vec<_>>();
+
+        let expected = values.iter().map(tf64::PositiveFinite::get).sum::<f64>();
+
+        let sum: tf64::Positive = values.iter().sum();
+        assert_eq!(sum, expected);
+
+        let sum: tf64::Positive = values.into_iter().sum();
+        assert_eq!(sum, expected);
+
+        let empty: tf64::Negative = Vec::<tf64::StrictlyNegative>::new().into_iter().sum();
+        assert!(empty.is_negative_zero());
+
+        let values = tf32::TEST_VALUES
+            .iter()
+            .filter_map(|&x| tf32::StrictlyNegative::new(x).ok())
+            .collect::<Vec<_>>();
+
+        let sum: tf32::Negative = values.iter().sum();
+        assert_eq!(sum, f32::NEG_INFINITY);
+    }
+
+    #[test]
+    fn product() {
+        let values = tf64::TEST_VALUES
+            .iter()
+            .filter_map(|&x| tf64::StrictlyPositiveFinite::new(x).ok())
+            .collect::<Vec<_>>();
+
+        let product: tf64::Positive = values.iter().product();
+        assert_eq!(
+            product,
+            values
+                .iter()
+                .map(tf64::StrictlyPositiveFinite::get)
+                .product::<f64>()
+        );
+
+        let empty: tf64::Positive = Vec::<tf64::StrictlyPositiveFinite>::new()
+            .into_iter()
+            .product();
+        assert_eq!(empty, 1.0);
+
+        let values = tf32::TEST_VALUES
+            .iter()
+            .filter_map(|&x| tf32::NonZeroNonNaNFinite::new(x).ok())
+            .collect::<Vec<_>>();
+
+        let product: tf32::NonNaN = values.iter().product();
+        assert_eq!(
+            product,
+            values
+                .iter()
+                .map(tf32::NonZeroNonNaNFinite::get)
+                .product::<f32>()
+        );
+    }
+}
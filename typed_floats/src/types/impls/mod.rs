@@ -1,7 +1,48 @@
+#[cfg(feature = "approx")]
+mod approx;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+mod as_ref;
+mod bits;
+#[cfg(feature = "borsh")]
+mod borsh;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+mod clamping;
 mod default;
 mod display;
 mod eq;
+#[cfg(feature = "libm")]
+mod frexp;
 mod from_str;
 mod from_to;
+#[cfg(feature = "libm")]
+mod gamma;
 mod hash;
+mod integer;
+mod iter;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod norm;
+#[cfg(feature = "num-traits")]
+mod num_traits;
 mod ord;
+#[cfg(feature = "ordered-float")]
+mod ordered_float;
+mod predicates;
+#[cfg(feature = "proptest")]
+mod proptest;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "rkyv")]
+mod rkyv;
+#[cfg(feature = "schemars")]
+mod schemars;
+mod set;
+mod sign;
+#[cfg(feature = "simd")]
+mod simd;
+mod slice;
+mod typed_float;
+mod ulps;
@@ -0,0 +1,59 @@
+use crate::{
+    Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN, NonZeroNonNaNFinite, Positive,
+    PositiveFinite, StrictlyNegative, StrictlyNegativeFinite, StrictlyPositive,
+    StrictlyPositiveFinite,
+};
+
+// `nalgebra::Scalar` is implemented for every `Clone + PartialEq + Debug` type, so the types can already be
+// the elements of a matrix, and the operators are available when the type is closed under them
+// (e.g. `Positive + Positive` is a `Positive`). `SubsetOf` allows to convert a whole matrix, e.g. with `cast`
+// or `try_convert`. `ComplexField` and `RealField` are not implemented: no type is closed under all their operations.
+
+macro_rules! impl_subset_of {
+    ($type:ident, $float:ident) => {
+        impl simba::scalar::SubsetOf<$float> for $type<$float> {
+            #[inline]
+            fn to_superset(&self) -> $float {
+                self.get()
+            }
+
+            #[inline]
+            #[allow(clippy::panic)] // The trait doesn't allow to return an error
+            fn from_superset_unchecked(element: &$float) -> Self {
+                // It is safe to call, so the value is still checked
+                match Self::new(*element) {
+                    Ok(value) => value,
+                    Err(_) => panic!(concat!(
+                        "The value is not a valid ",
+                        stringify!($type),
+                        "<",
+                        stringify!($float),
+                        ">"
+                    )),
+                }
+            }
+
+            #[inline]
+            fn is_in_subset(element: &$float) -> bool {
+                Self::new(*element).is_ok()
+            }
+        }
+    };
+    ($type:ident) => {
+        impl_subset_of!($type, f32);
+        impl_subset_of!($type, f64);
+    };
+}
+
+impl_subset_of!(NonNaN);
+impl_subset_of!(NonNaNFinite);
+impl_subset_of!(NonZeroNonNaN);
+impl_subset_of!(NonZeroNonNaNFinite);
+impl_subset_of!(StrictlyPositive);
+impl_subset_of!(StrictlyNegative);
+impl_subset_of!(Positive);
+impl_subset_of!(Negative);
+impl_subset_of!(StrictlyPositiveFinite);
+impl_subset_of!(StrictlyNegativeFinite);
+impl_subset_of!(PositiveFinite);
+impl_subset_of!(NegativeFinite);
@@ -0,0 +1,72 @@
+use crate::{
+    NegativeFinite, NonNaNFinite, NonZeroNonNaNFinite, Positive, PositiveFinite,
+    StrictlyNegativeFinite, StrictlyPositiveFinite,
+};
+
+#[cfg(all(feature = "libm", not(feature = "std")))]
+use num_traits::Float;
+
+// As with `hypot`, the squares are computed relative to the largest magnitude, so they can't
+// overflow nor be rounded to zero. The result only overflows if the norm itself is greater than `MAX`.
+// It is only implemented for the finite types, as an infinite element would give `inf / inf`.
+
+macro_rules! impl_l2_norm {
+    ($type:ident, $float:ident, $example:expr) => {
+        impl $type<$float> {
+            /// Computes the Euclidean norm of the slice, `sqrt(Σ xᵢ²)`.
+            ///
+            /// The result is never `NaN` nor negative, and it is `+0.0` for an empty slice.
+            /// The squares are summed relative to the largest magnitude so that they can't overflow,
+            /// but the result is `+inf` if the norm is greater than `MAX`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            #[doc = concat!("let values: [", stringify!($float), "; 2] = [3.0 * ", stringify!($example), ", 4.0 * ", stringify!($example), "];")]
+            #[doc = concat!("let values = ", stringify!($type), "::<", stringify!($float), ">::try_from_slice(&values).unwrap();")]
+            ///
+            #[doc = concat!("let norm: Positive<", stringify!($float), "> = ", stringify!($type), "::<", stringify!($float), ">::l2_norm(values);")]
+            /// assert_eq!(norm, 5.0);
+            ///
+            #[doc = concat!("assert_is_positive_zero!(", stringify!($type), "::<", stringify!($float), ">::l2_norm(&[]));")]
+            /// ```
+            #[inline]
+            #[must_use]
+            #[allow(clippy::suboptimal_flops)] // `mul_add` is not available without `std`
+            pub fn l2_norm(slice: &[Self]) -> Positive<$float> {
+                let max = slice
+                    .iter()
+                    .fold(0.0, |max: $float, x| max.max(x.get().abs()));
+
+                let norm = if max > 0.0 {
+                    let sum = slice.iter().fold(0.0, |sum: $float, x| {
+                        let x = x.get() / max;
+
+                        sum + x * x
+                    });
+
+                    max * sum.sqrt()
+                } else {
+                    0.0
+                };
+
+                // # Safety
+                // `max` is positive and finite, so each scaled square is in `[0, 1]`
+                // and their sum is finite: the product can only be positive or `+inf`.
+                unsafe { Positive::<$float>::new_unchecked(norm) }
+            }
+        }
+    };
+    ($type:ident, $example:expr) => {
+        impl_l2_norm!($type, f32, $example);
+        impl_l2_norm!($type, f64, $example);
+    };
+}
+
+impl_l2_norm!(NonNaNFinite, 1.0);
+impl_l2_norm!(NonZeroNonNaNFinite, 1.0);
+impl_l2_norm!(PositiveFinite, 1.0);
+impl_l2_norm!(NegativeFinite, -1.0);
+impl_l2_norm!(StrictlyPositiveFinite, 1.0);
+impl_l2_norm!(StrictlyNegativeFinite, -1.0);
@@ -0,0 +1,128 @@
+use crate::{
+    Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN, NonZeroNonNaNFinite, Positive,
+    PositiveFinite, StrictlyNegative, StrictlyNegativeFinite, StrictlyPositive,
+    StrictlyPositiveFinite,
+};
+
+// `num_traits::Float` (and `Num`, required by it) can't be implemented:
+// the arithmetic operators must return `Self`, but the types of this crate
+// return the type that can hold the result (`inf - inf` is `NaN`, `MAX + MAX` is `inf`, ...),
+// and `Float::nan()` can't return a valid value.
+// Only the conversions, that are always sound, are implemented.
+
+macro_rules! impl_num_traits {
+    ($type:ident) => {
+        impl num_traits::ToPrimitive for $type<f32> {
+            #[inline]
+            fn to_i64(&self) -> Option<i64> {
+                num_traits::ToPrimitive::to_i64(&self.0)
+            }
+
+            #[inline]
+            fn to_u64(&self) -> Option<u64> {
+                num_traits::ToPrimitive::to_u64(&self.0)
+            }
+
+            #[inline]
+            fn to_f32(&self) -> Option<f32> {
+                Some(self.0)
+            }
+
+            #[inline]
+            fn to_f64(&self) -> Option<f64> {
+                Some(f64::from(self.0))
+            }
+        }
+
+        impl num_traits::ToPrimitive for $type<f64> {
+            #[inline]
+            fn to_i64(&self) -> Option<i64> {
+                num_traits::ToPrimitive::to_i64(&self.0)
+            }
+
+            #[inline]
+            fn to_u64(&self) -> Option<u64> {
+                num_traits::ToPrimitive::to_u64(&self.0)
+            }
+
+            #[inline]
+            fn to_f32(&self) -> Option<f32> {
+                num_traits::ToPrimitive::to_f32(&self.0)
+            }
+
+            #[inline]
+            fn to_f64(&self) -> Option<f64> {
+                Some(self.0)
+            }
+        }
+
+        impl num_traits::NumCast for $type<f32> {
+            /// Returns `None` if the value is not valid for this type
+            #[inline]
+            fn from<N: num_traits::ToPrimitive>(n: N) -> Option<Self> {
+                n.to_f32().and_then(|value| Self::new(value).ok())
+            }
+        }
+
+        impl num_traits::NumCast for $type<f64> {
+            /// Returns `None` if the value is not valid for this type
+            #[inline]
+            fn from<N: num_traits::ToPrimitive>(n: N) -> Option<Self> {
+                n.to_f64().and_then(|value| Self::new(value).ok())
+            }
+        }
+    };
+}
+
+impl_num_traits!(NonNaN);
+impl_num_traits!(NonNaNFinite);
+impl_num_traits!(NonZeroNonNaN);
+impl_num_traits!(NonZeroNonNaNFinite);
+impl_num_traits!(StrictlyPositive);
+impl_num_traits!(StrictlyNegative);
+impl_num_traits!(Positive);
+impl_num_traits!(Negative);
+impl_num_traits!(StrictlyPositiveFinite);
+impl_num_traits!(StrictlyNegativeFinite);
+impl_num_traits!(PositiveFinite);
+impl_num_traits!(NegativeFinite);
+
+// `num_traits::Zero` requires `Add<Output = Self>`, which is only the case for
+// `Positive` and `Negative` (the strict variants are closed under addition
+// but can't hold zero).
+// `num_traits::One` requires `Mul<Output = Self>`, which is never the case
+// (`MAX * MAX` overflows and `MIN_POSITIVE * MIN_POSITIVE` underflows).
+macro_rules! impl_zero {
+    ($type:ident, $zero:expr) => {
+        impl num_traits::Zero for $type<f32> {
+            #[inline]
+            fn zero() -> Self {
+                // # Safety
+                // This is safe because the value is valid for that type.
+                unsafe { Self::new_unchecked($zero) }
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                self.0 == 0.0
+            }
+        }
+
+        impl num_traits::Zero for $type<f64> {
+            #[inline]
+            fn zero() -> Self {
+                // # Safety
+                // This is safe because the value is valid for that type.
+                unsafe { Self::new_unchecked($zero) }
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                self.0 == 0.0
+            }
+        }
+    };
+}
+
+impl_zero!(Positive, 0.0);
+impl_zero!(Negative, -0.0);
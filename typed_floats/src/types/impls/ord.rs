@@ -6,6 +6,9 @@ use crate::{
     StrictlyPositiveFinite,
 };
 
+// `-0.0` and `+0.0` are `Equal`, to be consistent with `PartialEq` and `Hash`
+// (which is required by `Ord`), and with the primitives.
+// `total_cmp` is not used as it would need `-0.0 != +0.0`.
 macro_rules! impl_ord {
     ($test:ident, $type:ident) => {
         impl Ord for $type<f32> {
@@ -190,3 +193,45 @@ impl_ord!(strictly_positive, StrictlyPositive);
 impl_ord!(strictly_negative, StrictlyNegative);
 impl_ord!(strictly_positive_finite, StrictlyPositiveFinite);
 impl_ord!(strictly_negative_finite, StrictlyNegativeFinite);
+
+#[cfg(test)]
+mod zeros {
+    extern crate std;
+    use crate::*;
+    use std::vec::Vec; // Required for the tests to compile in no_std mode
+
+    #[test]
+    fn zeros_are_equal() {
+        let neg_zero: tf64::NonNaN = tf64::NEG_ZERO.into();
+        let pos_zero: tf64::NonNaN = tf64::ZERO.into();
+        let neg_one: tf64::NonNaN = as_const!(NonNaN, -1.0);
+
+        assert_eq!(neg_zero.cmp(&pos_zero), core::cmp::Ordering::Equal);
+        assert_eq!(
+            neg_zero.partial_cmp(&pos_zero),
+            Some(core::cmp::Ordering::Equal)
+        );
+        assert_eq!(neg_zero, pos_zero);
+
+        // The sort is stable, so equal values keep their order
+        let mut values = Vec::from([pos_zero, neg_one, neg_zero]);
+        values.sort();
+
+        assert!(matches!(
+            values.as_slice(),
+            [a, b, c] if *a == -1.0 && b.is_positive_zero() && c.is_negative_zero()
+        ));
+    }
+
+    #[test]
+    fn sort_without_comparator() {
+        let mut values = [3.0, 1.0, f64::INFINITY, 2.0]
+            .iter()
+            .filter_map(|&x| tf64::StrictlyPositive::new(x).ok())
+            .collect::<Vec<_>>();
+
+        values.sort();
+
+        assert_eq!(values, [1.0, 2.0, 3.0, f64::INFINITY]);
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{
+    InvalidNumber, Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN,
+    NonZeroNonNaNFinite, Positive, PositiveFinite, StrictlyNegative, StrictlyNegativeFinite,
+    StrictlyPositive, StrictlyPositiveFinite,
+};
+
+// All the types of this crate are never `NaN`, so they can always be converted into `NotNan`.
+// The reverse conversion can fail as `NotNan` accepts any sign, zero and infinity.
+
+macro_rules! impl_ordered_float {
+    ($type:ident, $float:ident) => {
+        impl From<$type<$float>> for ordered_float::NotNan<$float> {
+            #[inline]
+            fn from(value: $type<$float>) -> Self {
+                // # Safety
+                // The value is never `NaN`
+                unsafe { Self::new_unchecked(value.get()) }
+            }
+        }
+
+        impl TryFrom<ordered_float::NotNan<$float>> for $type<$float> {
+            type Error = InvalidNumber;
+
+            #[inline]
+            fn try_from(value: ordered_float::NotNan<$float>) -> Result<Self, Self::Error> {
+                Self::new(value.into_inner())
+            }
+        }
+    };
+    ($type:ident) => {
+        impl_ordered_float!($type, f32);
+        impl_ordered_float!($type, f64);
+    };
+}
+
+impl_ordered_float!(NonNaN);
+impl_ordered_float!(NonNaNFinite);
+impl_ordered_float!(NonZeroNonNaN);
+impl_ordered_float!(NonZeroNonNaNFinite);
+impl_ordered_float!(StrictlyPositive);
+impl_ordered_float!(StrictlyNegative);
+impl_ordered_float!(Positive);
+impl_ordered_float!(Negative);
+impl_ordered_float!(StrictlyPositiveFinite);
+impl_ordered_float!(StrictlyNegativeFinite);
+impl_ordered_float!(PositiveFinite);
+impl_ordered_float!(NegativeFinite);
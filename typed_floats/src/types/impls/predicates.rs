@@ -0,0 +1,93 @@
+use crate::types::accept::{f32_to_bits, f64_to_bits};
+use crate::{
+    Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN, NonZeroNonNaNFinite, Positive,
+    PositiveFinite, StrictlyNegative, StrictlyNegativeFinite, StrictlyPositive,
+    StrictlyPositiveFinite,
+};
+
+// The comparisons of floats can't be used in `const fn` with the MSRV, so the sign bit
+// and the other bits are checked separately. `NaN` is never valid so it doesn't need to be handled.
+
+macro_rules! impl_predicates {
+    ($type:ident, $float:ident, $to_bits:ident, $sign_mask:expr, $example:expr) => {
+        impl $type<$float> {
+            /// Returns `true` if the number is greater than zero, including `+inf`.
+            ///
+            /// Unlike a comparison, it can be used in `const` contexts.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            #[doc = concat!("const X: ", stringify!($type), "<", stringify!($float), "> = unsafe { ", stringify!($type), "::<", stringify!($float), ">::new_unchecked(", stringify!($example), ") };")]
+            /// const IS_STRICTLY_POSITIVE: bool = X.is_strictly_positive();
+            ///
+            #[doc = concat!("assert_eq!(IS_STRICTLY_POSITIVE, ", stringify!($example), " > 0.0);")]
+            /// ```
+            #[inline]
+            #[must_use]
+            pub const fn is_strictly_positive(self) -> bool {
+                let bits = $to_bits(self.get());
+
+                bits & $sign_mask == 0 && bits != 0
+            }
+
+            /// Returns `true` if the number is lower than zero, including `-inf`.
+            ///
+            /// Unlike a comparison, it can be used in `const` contexts.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            #[doc = concat!("const X: ", stringify!($type), "<", stringify!($float), "> = unsafe { ", stringify!($type), "::<", stringify!($float), ">::new_unchecked(", stringify!($example), ") };")]
+            /// const IS_STRICTLY_NEGATIVE: bool = X.is_strictly_negative();
+            ///
+            #[doc = concat!("assert_eq!(IS_STRICTLY_NEGATIVE, ", stringify!($example), " < 0.0);")]
+            /// ```
+            #[inline]
+            #[must_use]
+            pub const fn is_strictly_negative(self) -> bool {
+                let bits = $to_bits(self.get());
+
+                bits & $sign_mask != 0 && bits & !$sign_mask != 0
+            }
+
+            /// Returns `true` if the number is `+0.0` or `-0.0`.
+            ///
+            /// Unlike a comparison, it can be used in `const` contexts.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            #[doc = concat!("const X: ", stringify!($type), "<", stringify!($float), "> = unsafe { ", stringify!($type), "::<", stringify!($float), ">::new_unchecked(", stringify!($example), ") };")]
+            /// const IS_ZERO: bool = X.is_zero();
+            ///
+            /// assert!(!IS_ZERO);
+            /// ```
+            #[inline]
+            #[must_use]
+            pub const fn is_zero(self) -> bool {
+                $to_bits(self.get()) & !$sign_mask == 0
+            }
+        }
+    };
+    ($type:ident, $example:expr) => {
+        impl_predicates!($type, f32, f32_to_bits, 1 << 31, $example);
+        impl_predicates!($type, f64, f64_to_bits, 1 << 63, $example);
+    };
+}
+
+impl_predicates!(NonNaN, 2.0);
+impl_predicates!(NonZeroNonNaN, 2.0);
+impl_predicates!(NonNaNFinite, 2.0);
+impl_predicates!(NonZeroNonNaNFinite, 2.0);
+impl_predicates!(Positive, 2.0);
+impl_predicates!(Negative, -2.0);
+impl_predicates!(PositiveFinite, 2.0);
+impl_predicates!(NegativeFinite, -2.0);
+impl_predicates!(StrictlyPositive, 2.0);
+impl_predicates!(StrictlyNegative, -2.0);
+impl_predicates!(StrictlyPositiveFinite, 2.0);
+impl_predicates!(StrictlyNegativeFinite, -2.0);
@@ -0,0 +1,83 @@
+use crate::{
+    Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN, NonZeroNonNaNFinite, Positive,
+    PositiveFinite, StrictlyNegative, StrictlyNegativeFinite, StrictlyPositive,
+    StrictlyPositiveFinite,
+};
+use proptest::prelude::*;
+
+// The values are generated from the float strategies of `proptest` restricted to the
+// signs and classes accepted by each type, so the shrinking stays in the domain of the type.
+// The edge cases (zeros, `MIN_POSITIVE`, `MAX`, infinities) are also generated explicitly,
+// as they are unlikely to be generated otherwise.
+
+macro_rules! impl_proptest {
+    ($type:ident, $float:ident) => {
+        impl Arbitrary for $type<$float> {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+                use proptest::num::$float::{INFINITE, NEGATIVE, NORMAL, POSITIVE, SUBNORMAL, ZERO};
+
+                let mut flags = NORMAL | SUBNORMAL;
+
+                if $type::accept_positive() {
+                    flags |= POSITIVE;
+                }
+                if $type::accept_negative() {
+                    flags |= NEGATIVE;
+                }
+                if $type::accept_zero() {
+                    flags |= ZERO;
+                }
+                if $type::accept_infinity() {
+                    flags |= INFINITE;
+                }
+
+                let edge_cases = [
+                    0.0,
+                    -0.0,
+                    1.0,
+                    -1.0,
+                    $float::MIN_POSITIVE,
+                    -$float::MIN_POSITIVE,
+                    $float::MAX,
+                    $float::MIN,
+                    $float::INFINITY,
+                    $float::NEG_INFINITY,
+                ]
+                .into_iter()
+                .filter(|&x| Self::is_valid(x))
+                .collect::<Vec<_>>();
+
+                prop_oneof![
+                    1 => proptest::sample::select(edge_cases),
+                    9 => flags,
+                ]
+                .prop_map(|x| {
+                    // # Safety
+                    // The edge cases are filtered and the flags only allow valid values
+                    unsafe { Self::new_unchecked(x) }
+                })
+                .boxed()
+            }
+        }
+    };
+    ($type:ident) => {
+        impl_proptest!($type, f32);
+        impl_proptest!($type, f64);
+    };
+}
+
+impl_proptest!(NonNaN);
+impl_proptest!(NonNaNFinite);
+impl_proptest!(NonZeroNonNaN);
+impl_proptest!(NonZeroNonNaNFinite);
+impl_proptest!(StrictlyPositive);
+impl_proptest!(StrictlyNegative);
+impl_proptest!(Positive);
+impl_proptest!(Negative);
+impl_proptest!(StrictlyPositiveFinite);
+impl_proptest!(StrictlyNegativeFinite);
+impl_proptest!(PositiveFinite);
+impl_proptest!(NegativeFinite);
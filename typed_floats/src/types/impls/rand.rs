@@ -0,0 +1,51 @@
+use crate::{
+    Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN, NonZeroNonNaNFinite, Positive,
+    PositiveFinite, StrictlyNegative, StrictlyNegativeFinite, StrictlyPositive,
+    StrictlyPositiveFinite,
+};
+use rand::distributions::{Distribution, OpenClosed01, Standard};
+
+// `Standard` samples the values uniformly in the domain of each type:
+// - in `[0, 1)` (like `f32`/`f64`) for the types accepting `+0.0`
+// - in `(0, 1]` (like `OpenClosed01`) for the strictly positive types and the non-zero types
+// - in `(-1, -0]` for the negative types
+// - in `[-1, 0)` for the strictly negative types
+
+macro_rules! impl_rand {
+    ($type:ident, $distribution:ident $(, $neg:tt)?) => {
+        impl Distribution<$type<f32>> for Standard {
+            #[inline]
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> $type<f32> {
+                let x: f32 = $distribution.sample(rng);
+
+                // # Safety
+                // `x` is in `[0, 1)` or `(0, 1]`, so the value is valid for this type
+                unsafe { $type::<f32>::new_unchecked($($neg)? x) }
+            }
+        }
+
+        impl Distribution<$type<f64>> for Standard {
+            #[inline]
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> $type<f64> {
+                let x: f64 = $distribution.sample(rng);
+
+                // # Safety
+                // `x` is in `[0, 1)` or `(0, 1]`, so the value is valid for this type
+                unsafe { $type::<f64>::new_unchecked($($neg)? x) }
+            }
+        }
+    };
+}
+
+impl_rand!(NonNaN, Standard);
+impl_rand!(NonNaNFinite, Standard);
+impl_rand!(Positive, Standard);
+impl_rand!(PositiveFinite, Standard);
+impl_rand!(NonZeroNonNaN, OpenClosed01);
+impl_rand!(NonZeroNonNaNFinite, OpenClosed01);
+impl_rand!(StrictlyPositive, OpenClosed01);
+impl_rand!(StrictlyPositiveFinite, OpenClosed01);
+impl_rand!(Negative, Standard, -);
+impl_rand!(NegativeFinite, Standard, -);
+impl_rand!(StrictlyNegative, OpenClosed01, -);
+impl_rand!(StrictlyNegativeFinite, OpenClosed01, -);
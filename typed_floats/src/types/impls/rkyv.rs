@@ -0,0 +1,77 @@
+use crate::{
+    InvalidNumber, Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN,
+    NonZeroNonNaNFinite, Positive, PositiveFinite, StrictlyNegative, StrictlyNegativeFinite,
+    StrictlyPositive, StrictlyPositiveFinite,
+};
+
+// Like `f32` and `f64`, the types are archived as themselves: the archived value is the bare float,
+// so it can be accessed without copying. Any bit pattern can be read from an archive,
+// so `CheckBytes` checks that the value is valid for the type.
+
+macro_rules! impl_rkyv {
+    ($type:ident, $float:ident) => {
+        impl rkyv::Archive for $type<$float> {
+            type Archived = Self;
+            type Resolver = ();
+
+            #[inline]
+            unsafe fn resolve(&self, _pos: usize, (): Self::Resolver, out: *mut Self::Archived) {
+                // # Safety
+                // The caller guarantees that `out` is valid for writes.
+                unsafe { out.write(*self) }
+            }
+        }
+
+        impl<S: rkyv::Fallible + ?Sized> rkyv::Serialize<S> for $type<$float> {
+            #[inline]
+            fn serialize(&self, _serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+                Ok(())
+            }
+        }
+
+        impl<D: rkyv::Fallible + ?Sized> rkyv::Deserialize<Self, D> for $type<$float> {
+            #[inline]
+            fn deserialize(&self, _deserializer: &mut D) -> Result<Self, D::Error> {
+                Ok(*self)
+            }
+        }
+
+        impl<C: ?Sized> rkyv::bytecheck::CheckBytes<C> for $type<$float> {
+            type Error = InvalidNumber;
+
+            #[inline]
+            unsafe fn check_bytes<'a>(
+                value: *const Self,
+                _context: &mut C,
+            ) -> Result<&'a Self, Self::Error> {
+                // # Safety
+                // The caller guarantees that `value` is aligned and points to enough bytes,
+                // and `Self` is `#[repr(transparent)]` over a float, for which any bit pattern is valid.
+                let float = unsafe { value.cast::<$float>().read() };
+
+                Self::new(float)?;
+
+                // # Safety
+                // The value has been checked.
+                Ok(unsafe { &*value })
+            }
+        }
+    };
+    ($type:ident) => {
+        impl_rkyv!($type, f32);
+        impl_rkyv!($type, f64);
+    };
+}
+
+impl_rkyv!(NonNaN);
+impl_rkyv!(NonNaNFinite);
+impl_rkyv!(NonZeroNonNaN);
+impl_rkyv!(NonZeroNonNaNFinite);
+impl_rkyv!(StrictlyPositive);
+impl_rkyv!(StrictlyNegative);
+impl_rkyv!(Positive);
+impl_rkyv!(Negative);
+impl_rkyv!(StrictlyPositiveFinite);
+impl_rkyv!(StrictlyNegativeFinite);
+impl_rkyv!(PositiveFinite);
+impl_rkyv!(NegativeFinite);
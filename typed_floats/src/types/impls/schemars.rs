@@ -0,0 +1,100 @@
+use crate::{
+    Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN, NonZeroNonNaNFinite, Positive,
+    PositiveFinite, StrictlyNegative, StrictlyNegativeFinite, StrictlyPositive,
+    StrictlyPositiveFinite,
+};
+use schemars::schema::{InstanceType, Metadata, NumberValidation, Schema, SchemaObject};
+
+// JSON numbers can't be `NaN` or infinite, but a large number like `1e400` would be parsed as infinite,
+// so the finite types are bounded by `MIN` and `MAX`.
+// Excluding only zero can't be expressed with the bounds, so it is only in the description.
+
+macro_rules! impl_schemars {
+    ($type:ident, $float:ident, $format:literal) => {
+        impl schemars::JsonSchema for $type<$float> {
+            fn is_referenceable() -> bool {
+                false
+            }
+
+            fn schema_name() -> String {
+                concat!(stringify!($type), "_", stringify!($float)).to_owned()
+            }
+
+            fn json_schema(_gen: &mut schemars::gen::SchemaGenerator) -> Schema {
+                let accept_inf = $type::accept_infinity();
+                let accept_zero = $type::accept_zero();
+                let accept_negative = $type::accept_negative();
+                let accept_positive = $type::accept_positive();
+
+                let mut description = String::from("A floating point number that is not NaN");
+
+                if !accept_inf {
+                    description.push_str(", not infinite");
+                }
+
+                if !accept_zero {
+                    description.push_str(", not zero");
+                }
+
+                if !accept_negative {
+                    description.push_str(", with a positive sign");
+                }
+
+                if !accept_positive {
+                    description.push_str(", with a negative sign");
+                }
+
+                let mut number = NumberValidation::default();
+
+                if !accept_negative {
+                    if accept_zero {
+                        number.minimum = Some(0.0);
+                    } else {
+                        number.exclusive_minimum = Some(0.0);
+                    }
+                } else if !accept_inf {
+                    number.minimum = Some(f64::from($float::MIN));
+                }
+
+                if !accept_positive {
+                    if accept_zero {
+                        number.maximum = Some(0.0);
+                    } else {
+                        number.exclusive_maximum = Some(0.0);
+                    }
+                } else if !accept_inf {
+                    number.maximum = Some(f64::from($float::MAX));
+                }
+
+                SchemaObject {
+                    metadata: Some(Box::new(Metadata {
+                        description: Some(description),
+                        ..Default::default()
+                    })),
+                    instance_type: Some(InstanceType::Number.into()),
+                    format: Some($format.to_owned()),
+                    number: Some(Box::new(number)),
+                    ..Default::default()
+                }
+                .into()
+            }
+        }
+    };
+    ($type:ident) => {
+        impl_schemars!($type, f32, "float");
+        impl_schemars!($type, f64, "double");
+    };
+}
+
+impl_schemars!(NonNaN);
+impl_schemars!(NonNaNFinite);
+impl_schemars!(NonZeroNonNaN);
+impl_schemars!(NonZeroNonNaNFinite);
+impl_schemars!(StrictlyPositive);
+impl_schemars!(StrictlyNegative);
+impl_schemars!(Positive);
+impl_schemars!(Negative);
+impl_schemars!(StrictlyPositiveFinite);
+impl_schemars!(StrictlyNegativeFinite);
+impl_schemars!(PositiveFinite);
+impl_schemars!(NegativeFinite);
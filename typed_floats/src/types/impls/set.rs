@@ -0,0 +1,52 @@
+use crate::{
+    InvalidNumber, Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN,
+    NonZeroNonNaNFinite, Positive, PositiveFinite, StrictlyNegative, StrictlyNegativeFinite,
+    StrictlyPositive, StrictlyPositiveFinite,
+};
+
+macro_rules! impl_try_set {
+    ($type:ident, $float:ident, $valid:expr, $invalid:expr) => {
+        impl $type<$float> {
+            /// Replaces the value in place, if the new value is valid.
+            ///
+            /// # Errors
+            /// Returns an error if the new value is not valid, in which case the current value is kept.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            #[doc = concat!("let mut x = ", stringify!($type), "::<", stringify!($float), ">::new(", stringify!($valid), ").unwrap();")]
+            ///
+            #[doc = concat!("assert!(x.try_set(", stringify!($valid), " * 2.0).is_ok());")]
+            #[doc = concat!("assert_eq!(x, ", stringify!($valid), " * 2.0);")]
+            ///
+            #[doc = concat!("assert!(x.try_set(", stringify!($invalid), ").is_err());")]
+            #[doc = concat!("assert_eq!(x, ", stringify!($valid), " * 2.0);")]
+            /// ```
+            #[inline]
+            pub fn try_set(&mut self, value: $float) -> Result<(), InvalidNumber> {
+                *self = Self::new(value)?;
+
+                Ok(())
+            }
+        }
+    };
+    ($type:ident, $valid:expr, $invalid:expr) => {
+        impl_try_set!($type, f32, $valid, $invalid);
+        impl_try_set!($type, f64, $valid, $invalid);
+    };
+}
+
+impl_try_set!(NonNaN, 1.0, (0.0 / 0.0));
+impl_try_set!(NonZeroNonNaN, 1.0, 0.0);
+impl_try_set!(NonNaNFinite, 1.0, (1.0 / 0.0));
+impl_try_set!(NonZeroNonNaNFinite, 1.0, 0.0);
+impl_try_set!(Positive, 1.0, -1.0);
+impl_try_set!(Negative, -1.0, 1.0);
+impl_try_set!(PositiveFinite, 1.0, -1.0);
+impl_try_set!(NegativeFinite, -1.0, 1.0);
+impl_try_set!(StrictlyPositive, 1.0, 0.0);
+impl_try_set!(StrictlyNegative, -1.0, -0.0);
+impl_try_set!(StrictlyPositiveFinite, 1.0, 0.0);
+impl_try_set!(StrictlyNegativeFinite, -1.0, -0.0);
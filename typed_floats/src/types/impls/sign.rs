@@ -0,0 +1,164 @@
+use crate::{
+    Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN, NonZeroNonNaNFinite, Positive,
+    PositiveFinite, Sign, StrictlyNegative, StrictlyNegativeFinite, StrictlyPositive,
+    StrictlyPositiveFinite,
+};
+
+// The magnitude is the absolute value, so it is valid for the positive type accepting
+// the same zero and infinity as `self`. Negating a number only flips its sign bit,
+// so it is exact and keeps the signed zeros.
+
+macro_rules! impl_split_sign {
+    ($type:ident, $magnitude:ident, $float:ident, $sign:ident, $example:literal) => {
+        impl $type<$float> {
+            /// Splits the number into its sign and its magnitude (absolute value).
+            ///
+            /// The sign of the zeros is kept, so the number can be rebuilt with `with_sign`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            #[doc = concat!("let x = ", stringify!($type), "::<", stringify!($float), ">::new(", stringify!($example), ").unwrap();")]
+            ///
+            /// let (sign, magnitude) = x.split_sign();
+            ///
+            #[doc = concat!("assert_eq!(sign, Sign::", stringify!($sign), ");")]
+            /// assert!(magnitude.is_sign_positive());
+            /// assert_eq!(magnitude.with_sign(sign).get().to_bits(), x.get().to_bits());
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn split_sign(self) -> (Sign, $magnitude<$float>) {
+                let value = self.get();
+
+                let sign = if value.is_sign_negative() {
+                    Sign::Negative
+                } else {
+                    Sign::Positive
+                };
+
+                let magnitude = match sign {
+                    Sign::Negative => -value,
+                    Sign::Positive => value,
+                };
+
+                // # Safety
+                // The magnitude is positive and can be zero or infinite only if `self` can.
+                (sign, unsafe { $magnitude::<$float>::new_unchecked(magnitude) })
+            }
+        }
+    };
+    ($type:ident, $magnitude:ident, $sign:ident, $example:literal) => {
+        impl_split_sign!($type, $magnitude, f32, $sign, $example);
+        impl_split_sign!($type, $magnitude, f64, $sign, $example);
+    };
+}
+
+macro_rules! impl_with_sign {
+    ($type:ident, $output:ident, $float:ident) => {
+        impl $type<$float> {
+            /// Returns the number with the magnitude of `self` and the given sign.
+            ///
+            /// It is the inverse of `split_sign`, including for `-0.0`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            #[doc = concat!("let x = ", stringify!($type), "::<", stringify!($float), ">::try_from(", stringify!($float), "::MAX).unwrap();")]
+            ///
+            #[doc = concat!("assert_eq!(x.with_sign(Sign::Negative), ", stringify!($float), "::MIN);")]
+            #[doc = concat!("assert_eq!(x.with_sign(Sign::Positive), ", stringify!($float), "::MAX);")]
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn with_sign(self, sign: Sign) -> $output<$float> {
+                let value = match sign {
+                    Sign::Negative => -self.get(),
+                    Sign::Positive => self.get(),
+                };
+
+                // # Safety
+                // The value can be zero or infinite only if `self` can, with any sign.
+                unsafe { $output::<$float>::new_unchecked(value) }
+            }
+        }
+    };
+    ($type:ident, $output:ident) => {
+        impl_with_sign!($type, $output, f32);
+        impl_with_sign!($type, $output, f64);
+    };
+}
+
+impl_split_sign!(NonNaN, Positive, Negative, -0.0);
+impl_split_sign!(NonNaNFinite, PositiveFinite, Negative, -0.0);
+impl_split_sign!(NonZeroNonNaN, StrictlyPositive, Negative, -2.0);
+impl_split_sign!(NonZeroNonNaNFinite, StrictlyPositiveFinite, Negative, -2.0);
+impl_split_sign!(StrictlyPositive, StrictlyPositive, Positive, 2.0);
+impl_split_sign!(StrictlyNegative, StrictlyPositive, Negative, -2.0);
+impl_split_sign!(Positive, Positive, Positive, 0.0);
+impl_split_sign!(Negative, Positive, Negative, -0.0);
+impl_split_sign!(
+    StrictlyPositiveFinite,
+    StrictlyPositiveFinite,
+    Positive,
+    2.0
+);
+impl_split_sign!(
+    StrictlyNegativeFinite,
+    StrictlyPositiveFinite,
+    Negative,
+    -2.0
+);
+impl_split_sign!(PositiveFinite, PositiveFinite, Positive, 0.0);
+impl_split_sign!(NegativeFinite, PositiveFinite, Negative, -0.0);
+
+impl_with_sign!(Positive, NonNaN);
+impl_with_sign!(PositiveFinite, NonNaNFinite);
+impl_with_sign!(StrictlyPositive, NonZeroNonNaN);
+impl_with_sign!(StrictlyPositiveFinite, NonZeroNonNaNFinite);
+
+// Only `NonNaN` and `NonNaNFinite` accept both zeros: the other types accepting zero
+// accept only the one of their sign, so they are already canonical.
+
+macro_rules! impl_canonicalize {
+    ($type:ident, $float:ident, $sign_mask:expr) => {
+        impl $type<$float> {
+            /// Returns the same value, with `-0.0` mapped to `+0.0`.
+            ///
+            /// As `-0.0 == +0.0`, it gives a single representation to equal values,
+            /// e.g. to compare or hash their bits.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            #[doc = concat!("let x = ", stringify!($type), "::<", stringify!($float), ">::new(-0.0).unwrap();")]
+            ///
+            /// assert_is_positive_zero!(x.canonicalize());
+            ///
+            #[doc = concat!("let y = ", stringify!($type), "::<", stringify!($float), ">::new(-2.0).unwrap();")]
+            ///
+            /// assert_eq!(y.canonicalize(), -2.0);
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn canonicalize(self) -> Self {
+                // Only `-0.0` has its sign bit set and every other bit cleared
+                if self.0.to_bits() == $sign_mask {
+                    Self(0.0)
+                } else {
+                    self
+                }
+            }
+        }
+    };
+    ($type:ident) => {
+        impl_canonicalize!($type, f32, 1 << 31);
+        impl_canonicalize!($type, f64, 1 << 63);
+    };
+}
+
+impl_canonicalize!(NonNaN);
+impl_canonicalize!(NonNaNFinite);
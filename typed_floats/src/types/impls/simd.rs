@@ -0,0 +1,90 @@
+use crate::{
+    InvalidNumber, Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN,
+    NonZeroNonNaNFinite, Positive, PositiveFinite, StrictlyNegative, StrictlyNegativeFinite,
+    StrictlyPositive, StrictlyPositiveFinite,
+};
+
+// `core::simd` is not stable, so the slice is checked by chunks of `LANES` elements,
+// without any branch inside a chunk: the checks are done on the bits and combined with `&` and `|`,
+// which lets the compiler vectorize the loop. Only the first invalid chunk is checked again, element by element,
+// to find the index of the first invalid element and the reason why it is invalid.
+
+const LANES: usize = 16;
+
+macro_rules! impl_validate_slice {
+    ($type:ident, $float:ident, $sign_mask:expr, $valid:expr, $invalid:expr) => {
+        impl $type<$float> {
+            /// Checks that every element of the slice is valid, by chunks that can be vectorized.
+            ///
+            /// It returns the same result as `try_from_slice`, but is faster for large slices.
+            ///
+            /// # Errors
+            /// Returns the index of the first invalid element and the reason why it is invalid.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            #[doc = concat!("let mut values: Vec<", stringify!($float), "> = vec![", stringify!($valid), "; 1000];")]
+            #[doc = concat!("assert_eq!(", stringify!($type), "::<", stringify!($float), ">::validate_slice(&values), Ok(()));")]
+            ///
+            #[doc = concat!("values[123] = ", stringify!($invalid), ";")]
+            #[doc = concat!("let (index, _) = ", stringify!($type), "::<", stringify!($float), ">::validate_slice(&values).unwrap_err();")]
+            ///
+            /// assert_eq!(index, 123);
+            /// ```
+            #[inline]
+            pub fn validate_slice(slice: &[$float]) -> Result<(), (usize, InvalidNumber)> {
+                let inf_bits = $float::INFINITY.to_bits();
+
+                let is_valid = |value: $float| {
+                    let bits = value.to_bits();
+                    let abs_bits = bits & !$sign_mask;
+                    let is_negative = bits & $sign_mask != 0;
+
+                    (abs_bits <= inf_bits)
+                        & ($type::accept_infinity() | (abs_bits != inf_bits))
+                        & ($type::accept_zero() | (abs_bits != 0))
+                        & ((is_negative & $type::accept_negative())
+                            | (!is_negative & $type::accept_positive()))
+                };
+
+                let chunks = slice.chunks_exact(LANES);
+                let remainder = chunks.remainder();
+
+                for (chunk_index, chunk) in chunks.enumerate() {
+                    let valid = chunk.iter().fold(true, |valid, &value| valid & is_valid(value));
+
+                    if !valid {
+                        let offset = chunk_index * LANES;
+
+                        return Self::try_from_slice(chunk).map(|_| ()).map_err(|(index, error)| (offset + index, error));
+                    }
+                }
+
+                let offset = slice.len() - remainder.len();
+
+                Self::try_from_slice(remainder)
+                    .map(|_| ())
+                    .map_err(|(index, error)| (offset + index, error))
+            }
+        }
+    };
+    ($type:ident, $valid:expr, $invalid:expr) => {
+        impl_validate_slice!($type, f32, 1 << 31, $valid, $invalid);
+        impl_validate_slice!($type, f64, 1 << 63, $valid, $invalid);
+    };
+}
+
+impl_validate_slice!(NonNaN, 1.0, 0.0 / 0.0);
+impl_validate_slice!(NonNaNFinite, 1.0, 1.0 / 0.0);
+impl_validate_slice!(NonZeroNonNaN, 1.0, 0.0);
+impl_validate_slice!(NonZeroNonNaNFinite, 1.0, 0.0);
+impl_validate_slice!(StrictlyPositive, 1.0, 0.0);
+impl_validate_slice!(StrictlyNegative, -1.0, 0.0);
+impl_validate_slice!(Positive, 1.0, -1.0);
+impl_validate_slice!(Negative, -1.0, 1.0);
+impl_validate_slice!(StrictlyPositiveFinite, 1.0, 0.0);
+impl_validate_slice!(StrictlyNegativeFinite, -1.0, 0.0);
+impl_validate_slice!(PositiveFinite, 1.0, -1.0);
+impl_validate_slice!(NegativeFinite, -1.0, 1.0);
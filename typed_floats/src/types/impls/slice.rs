@@ -0,0 +1,106 @@
+use crate::{
+    InvalidNumber, Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN,
+    NonZeroNonNaNFinite, Positive, PositiveFinite, StrictlyNegative, StrictlyNegativeFinite,
+    StrictlyPositive, StrictlyPositiveFinite,
+};
+
+// All the types are `#[repr(transparent)]` over their primitive type, which is guaranteed,
+// so a slice of valid primitives can be viewed as a slice of the typed float without copying, and vice versa.
+
+macro_rules! impl_slice {
+    ($type:ident, $float:ident, $valid:expr, $invalid:expr) => {
+        impl $type<$float> {
+            /// Checks that every element of the slice is valid and views it as a slice of `Self`, without copying.
+            ///
+            /// # Errors
+            /// Returns the index of the first invalid element and the reason why it is invalid.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            #[doc = concat!("let values: [", stringify!($float), "; 2] = [", stringify!($valid), ", ", stringify!($valid), "];")]
+            #[doc = concat!("let typed = ", stringify!($type), "::<", stringify!($float), ">::try_from_slice(&values).unwrap();")]
+            ///
+            /// assert_eq!(typed.len(), 2);
+            /// assert_eq!(typed[0], values[0]);
+            ///
+            #[doc = concat!("let values: [", stringify!($float), "; 2] = [", stringify!($valid), ", ", stringify!($invalid), "];")]
+            #[doc = concat!("let (index, _) = ", stringify!($type), "::<", stringify!($float), ">::try_from_slice(&values).unwrap_err();")]
+            ///
+            /// assert_eq!(index, 1);
+            /// ```
+            #[inline]
+            pub fn try_from_slice(slice: &[$float]) -> Result<&[Self], (usize, InvalidNumber)> {
+                for (index, &value) in slice.iter().enumerate() {
+                    if let Err(error) = Self::new(value) {
+                        return Err((index, error));
+                    }
+                }
+
+                // # Safety
+                // Every element has been checked.
+                Ok(unsafe { Self::from_slice_unchecked(slice) })
+            }
+
+            /// Views a slice of primitives as a slice of `Self`, without copying nor checking the elements.
+            ///
+            /// # Safety
+            /// Every element of the slice must be a valid value of `Self`.
+            /// Otherwise, the behavior is undefined, as for `new_unchecked`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            #[doc = concat!("let values: [", stringify!($float), "; 2] = [", stringify!($valid), ", ", stringify!($valid), "];")]
+            #[doc = concat!("let typed = unsafe { ", stringify!($type), "::<", stringify!($float), ">::from_slice_unchecked(&values) };")]
+            ///
+            /// assert_eq!(typed[1], values[1]);
+            /// ```
+            #[inline]
+            #[must_use]
+            pub const unsafe fn from_slice_unchecked(slice: &[$float]) -> &[Self] {
+                // # Safety
+                // `Self` is `#[repr(transparent)]` over `$float` and the caller guarantees that every element is valid.
+                unsafe { core::slice::from_raw_parts(slice.as_ptr().cast::<Self>(), slice.len()) }
+            }
+
+            /// Views a slice of `Self` as a slice of primitives, without copying.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            #[doc = concat!("let values: [", stringify!($float), "; 2] = [", stringify!($valid), ", ", stringify!($valid), "];")]
+            #[doc = concat!("let typed = ", stringify!($type), "::<", stringify!($float), ">::try_from_slice(&values).unwrap();")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($type), "::<", stringify!($float), ">::as_primitive_slice(typed), &values);")]
+            /// ```
+            #[inline]
+            #[must_use]
+            pub const fn as_primitive_slice(slice: &[Self]) -> &[$float] {
+                // # Safety
+                // `Self` is `#[repr(transparent)]` over `$float`, and any valid `Self` is a valid `$float`.
+                unsafe { core::slice::from_raw_parts(slice.as_ptr().cast::<$float>(), slice.len()) }
+            }
+        }
+    };
+    ($type:ident, $valid:expr, $invalid:expr) => {
+        impl_slice!($type, f32, $valid, $invalid);
+        impl_slice!($type, f64, $valid, $invalid);
+    };
+}
+
+impl_slice!(NonNaN, 1.0, (0.0 / 0.0));
+impl_slice!(NonZeroNonNaN, 1.0, 0.0);
+impl_slice!(NonNaNFinite, 1.0, (1.0 / 0.0));
+impl_slice!(NonZeroNonNaNFinite, 1.0, 0.0);
+impl_slice!(Positive, 1.0, -1.0);
+impl_slice!(Negative, -1.0, 1.0);
+impl_slice!(PositiveFinite, 1.0, -1.0);
+impl_slice!(NegativeFinite, -1.0, 1.0);
+impl_slice!(StrictlyPositive, 1.0, 0.0);
+impl_slice!(StrictlyNegative, -1.0, -0.0);
+impl_slice!(StrictlyPositiveFinite, 1.0, 0.0);
+impl_slice!(StrictlyNegativeFinite, -1.0, -0.0);
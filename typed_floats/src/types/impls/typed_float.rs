@@ -0,0 +1,150 @@
+use crate::{
+    FilterValid, InvalidNumber, Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN,
+    NonZeroNonNaNFinite, Positive, PositiveFinite, PrimitiveFloatIterator, StrictlyNegative,
+    StrictlyNegativeFinite, StrictlyPositive, StrictlyPositiveFinite, TryTyped, TypedFloat,
+    TypedFloatIterator,
+};
+
+// Every method forwards to the inherent method of the same name.
+
+macro_rules! impl_typed_float {
+    ($type:ident, $float:ident) => {
+        impl TypedFloat for $type<$float> {
+            type Content = $float;
+            type F32 = $type<f32>;
+            type F64 = $type<f64>;
+
+            #[inline]
+            fn new(value: $float) -> Result<Self, InvalidNumber> {
+                Self::new(value)
+            }
+
+            #[inline]
+            fn try_new(value: $float) -> Option<Self> {
+                Self::try_new(value)
+            }
+
+            #[inline]
+            fn get(&self) -> $float {
+                Self::get(self)
+            }
+
+            #[inline]
+            fn is_nan(&self) -> bool {
+                Self::is_nan(self)
+            }
+
+            #[inline]
+            fn is_infinite(&self) -> bool {
+                Self::is_infinite(self)
+            }
+
+            #[inline]
+            fn is_finite(&self) -> bool {
+                Self::is_finite(self)
+            }
+
+            #[inline]
+            fn is_subnormal(&self) -> bool {
+                Self::is_subnormal(self)
+            }
+
+            #[inline]
+            fn is_normal(&self) -> bool {
+                Self::is_normal(self)
+            }
+
+            #[inline]
+            fn classify(&self) -> core::num::FpCategory {
+                Self::classify(self)
+            }
+
+            #[inline]
+            fn is_sign_positive(&self) -> bool {
+                Self::is_sign_positive(self)
+            }
+
+            #[inline]
+            fn is_sign_negative(&self) -> bool {
+                Self::is_sign_negative(self)
+            }
+
+            #[inline]
+            fn is_negative_zero(&self) -> bool {
+                Self::is_negative_zero(self)
+            }
+
+            #[inline]
+            fn is_positive_zero(&self) -> bool {
+                Self::is_positive_zero(self)
+            }
+
+            #[inline]
+            fn total_cmp(&self, other: &Self) -> core::cmp::Ordering {
+                Self::total_cmp(self, other)
+            }
+
+            #[inline]
+            fn into_f64(self) -> $type<f64> {
+                self.into()
+            }
+
+            #[inline]
+            fn try_into_f32(self) -> Result<$type<f32>, InvalidNumber> {
+                // The conversion to `f64` is exact, so a `f32` is converted back to itself
+                $type::<f32>::try_from_f64(f64::from(self.get()))
+            }
+        }
+    };
+    ($type:ident) => {
+        impl_typed_float!($type, f32);
+        impl_typed_float!($type, f64);
+    };
+}
+
+impl_typed_float!(NonNaN);
+impl_typed_float!(NonNaNFinite);
+impl_typed_float!(NonZeroNonNaN);
+impl_typed_float!(NonZeroNonNaNFinite);
+impl_typed_float!(StrictlyPositive);
+impl_typed_float!(StrictlyNegative);
+impl_typed_float!(Positive);
+impl_typed_float!(Negative);
+impl_typed_float!(StrictlyPositiveFinite);
+impl_typed_float!(StrictlyNegativeFinite);
+impl_typed_float!(PositiveFinite);
+impl_typed_float!(NegativeFinite);
+
+impl<I> TypedFloatIterator for I
+where
+    I: Iterator,
+    I::Item: TypedFloat,
+{
+    #[inline]
+    fn typed_min(self) -> Option<Self::Item> {
+        self.min_by(TypedFloat::total_cmp)
+    }
+
+    #[inline]
+    fn typed_max(self) -> Option<Self::Item> {
+        self.max_by(TypedFloat::total_cmp)
+    }
+}
+
+impl<I: Iterator> PrimitiveFloatIterator for I {
+    #[inline]
+    fn filter_valid<T>(self) -> FilterValid<Self, T>
+    where
+        T: TypedFloat<Content = Self::Item>,
+    {
+        self.filter_map(T::try_new)
+    }
+
+    #[inline]
+    fn try_typed<T>(self) -> TryTyped<Self, T>
+    where
+        T: TypedFloat<Content = Self::Item>,
+    {
+        self.map(T::new)
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{
+    Negative, NegativeFinite, NonNaN, NonNaNFinite, NonZeroNonNaN, NonZeroNonNaNFinite, Positive,
+    PositiveFinite, StrictlyNegative, StrictlyNegativeFinite, StrictlyPositive,
+    StrictlyPositiveFinite, UlpsBetween,
+};
+
+// The bits of a positive float are ordered like its value, so the bits of a negative float
+// are mapped to the opposite of the bits of its absolute value: the result is an integer
+// that increases by one for each representable float, with both zeros mapped to `0`.
+// This is sound because `NaN` is not a possible value.
+
+#[inline]
+fn ulps_f32(value: f32) -> i64 {
+    let bits = value.to_bits();
+    let magnitude = i64::from(bits & !(1 << 31));
+
+    if bits >> 31 == 0 {
+        magnitude
+    } else {
+        -magnitude
+    }
+}
+
+#[inline]
+fn ulps_f64(value: f64) -> i64 {
+    let bits = value.to_bits();
+    // The magnitude of a float that is not `NaN` is at most the bits of infinity, so it fits
+    #[allow(clippy::cast_possible_wrap)]
+    let magnitude = (bits & !(1 << 63)) as i64;
+
+    if bits >> 63 == 0 {
+        magnitude
+    } else {
+        -magnitude
+    }
+}
+
+macro_rules! impl_ulps_between {
+    ($type:ident, [$($rhs:ident),*]) => {
+        $(
+            impl UlpsBetween<$rhs<f32>> for $type<f32> {
+                #[inline]
+                fn ulps_between(self, rhs: $rhs<f32>) -> i64 {
+                    // Both are at most `2^31` in absolute value, so it can't overflow
+                    ulps_f32(rhs.0) - ulps_f32(self.0)
+                }
+            }
+
+            impl UlpsBetween<$rhs<f64>> for $type<f64> {
+                #[inline]
+                fn ulps_between(self, rhs: $rhs<f64>) -> i64 {
+                    ulps_f64(rhs.0).saturating_sub(ulps_f64(self.0))
+                }
+            }
+        )*
+    };
+    ($($type:ident),*) => {
+        $(
+            impl_ulps_between!(
+                $type,
+                [
+                    NonNaN,
+                    NonNaNFinite,
+                    NonZeroNonNaN,
+                    NonZeroNonNaNFinite,
+                    StrictlyPositive,
+                    StrictlyNegative,
+                    Positive,
+                    Negative,
+                    StrictlyPositiveFinite,
+                    StrictlyNegativeFinite,
+                    PositiveFinite,
+                    NegativeFinite
+                ]
+            );
+        )*
+    };
+}
+
+impl_ulps_between!(
+    NonNaN,
+    NonNaNFinite,
+    NonZeroNonNaN,
+    NonZeroNonNaNFinite,
+    StrictlyPositive,
+    StrictlyNegative,
+    Positive,
+    Negative,
+    StrictlyPositiveFinite,
+    StrictlyNegativeFinite,
+    PositiveFinite,
+    NegativeFinite
+);
+
+// The gap between two consecutive floats only depends on the magnitude, so the bits of `|x|`
+// are incremented to get the next float away from zero. The difference is exact, as it is a power of two.
+// At `MAX` the next float would be infinite, but the gap to the previous float is the same.
+
+macro_rules! impl_ulp {
+    ($type:ident, $float:ident, $sign_mask:expr, $example:expr) => {
+        impl $type<$float> {
+            /// Returns the unit in the last place of the value:
+            /// the gap between its absolute value and the next representable float.
+            ///
+            /// At `MAX`, it is the gap to the previous float, which is the same.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            #[doc = concat!("let x = ", stringify!($type), "::<", stringify!($float), ">::new(", stringify!($example), ").unwrap();")]
+            ///
+            #[doc = concat!("assert_eq!(x.ulp(), ", stringify!($float), "::EPSILON);")]
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn ulp(self) -> StrictlyPositiveFinite<$float> {
+                let abs_bits = self.0.to_bits() & !$sign_mask;
+                let abs = $float::from_bits(abs_bits);
+
+                let ulp = if abs_bits == $float::MAX.to_bits() {
+                    abs - $float::from_bits(abs_bits - 1)
+                } else {
+                    $float::from_bits(abs_bits + 1) - abs
+                };
+
+                // # Safety
+                // `abs` is finite and is not `MAX`, or the previous float is used,
+                // so the gap between two distinct finite floats is strictly positive and finite.
+                unsafe { StrictlyPositiveFinite::<$float>::new_unchecked(ulp) }
+            }
+        }
+    };
+    ($type:ident, $example:expr) => {
+        impl_ulp!($type, f32, 1 << 31, $example);
+        impl_ulp!($type, f64, 1 << 63, $example);
+    };
+}
+
+impl_ulp!(NonZeroNonNaNFinite, -1.0);
+impl_ulp!(StrictlyPositiveFinite, 1.0);
+impl_ulp!(StrictlyNegativeFinite, -1.0);
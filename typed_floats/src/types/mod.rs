@@ -19,9 +19,6 @@ impl core::fmt::Display for FromStrError {
 #[cfg(feature = "std")]
 impl std::error::Error for FromStrError {}
 
-#[cfg(feature = "serde")]
-use serde::Serialize;
-
 /// An error that can occur when converting into a typed float
 #[derive(Debug, Eq, PartialEq)]
 pub enum InvalidNumber {
@@ -52,11 +49,101 @@ impl core::fmt::Display for InvalidNumber {
 #[cfg(feature = "std")]
 impl std::error::Error for InvalidNumber {}
 
+/// An error that can occur when converting into a type with bounds other than zero,
+/// like [`UnitInterval`] or [`Bounded`]
+#[derive(Debug, Eq, PartialEq)]
+pub enum RangeError {
+    /// The number is not valid for the bounds that are zero, or is `NaN`
+    InvalidNumber(InvalidNumber),
+    /// The number is outside of the other bounds of the type
+    OutOfRange,
+}
+
+impl core::fmt::Display for RangeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidNumber(e) => write!(f, "{e}"),
+            Self::OutOfRange => write!(f, "Number is out of range"),
+        }
+    }
+}
+
+impl From<InvalidNumber> for RangeError {
+    #[inline]
+    fn from(value: InvalidNumber) -> Self {
+        Self::InvalidNumber(value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RangeError {}
+
+/// An error that can occur when converting into a typed float with `try_from_preserving`
+///
+/// `T` is the type of the bits of the float: `u32` for `f32` and `u64` for `f64`.
+#[derive(Debug, Eq, PartialEq)]
+pub enum PreservingError<T> {
+    /// A `NaN` with its bits, to keep its payload and whether it is signaling
+    NaN(T),
+    /// Any other invalid number, with the same error as `new`
+    InvalidNumber(InvalidNumber),
+}
+
+impl<T: core::fmt::LowerHex> core::fmt::Display for PreservingError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NaN(bits) => write!(f, "Number is NaN (bits: {bits:#x})"),
+            Self::InvalidNumber(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl<T> From<InvalidNumber> for PreservingError<T> {
+    #[inline]
+    fn from(value: InvalidNumber) -> Self {
+        Self::InvalidNumber(value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: core::fmt::Debug + core::fmt::LowerHex> std::error::Error for PreservingError<T> {}
+
+/// An error that can occur when converting a typed float into an integer
+#[derive(Debug, Eq, PartialEq)]
+pub enum IntegerConversionError {
+    /// The number has a fractional part
+    NotAnInteger,
+    /// The number is outside the range of the integer type, including infinities
+    OutOfRange,
+}
+
+impl core::fmt::Display for IntegerConversionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NotAnInteger => write!(f, "Number is not an integer"),
+            Self::OutOfRange => write!(f, "Number is out of the range of the integer type"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IntegerConversionError {}
+
+/// The sign of a number, as returned by `split_sign`
+///
+/// `+0.0` has a positive sign and `-0.0` a negative sign.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Sign {
+    /// `+0.0` and the numbers greater than it
+    Positive,
+    /// `-0.0` and the numbers lower than it
+    Negative,
+}
+
 /// A non-NaN floating point number
 ///
 /// It satisfies the following constraints:
 /// - It is not NaN.
-#[cfg_attr(feature = "serde", derive(Serialize))]
 #[derive(Debug, Copy, Clone)]
 #[repr(transparent)]
 pub struct NonNaN<T = f64>(T);
@@ -66,7 +153,6 @@ pub struct NonNaN<T = f64>(T);
 /// It satisfies the following constraints:
 /// - It is not NaN.
 /// - It is not zero.
-#[cfg_attr(feature = "serde", derive(Serialize))]
 #[derive(Debug, Copy, Clone)]
 #[repr(transparent)]
 pub struct NonZeroNonNaN<T = f64>(T);
@@ -76,7 +162,6 @@ pub struct NonZeroNonNaN<T = f64>(T);
 /// It satisfies the following constraints:
 /// - It is not NaN.
 /// - It is not infinite.
-#[cfg_attr(feature = "serde", derive(Serialize))]
 #[derive(Debug, Copy, Clone)]
 #[repr(transparent)]
 pub struct NonNaNFinite<T = f64>(T);
@@ -87,7 +172,6 @@ pub struct NonNaNFinite<T = f64>(T);
 /// - It is not NaN.
 /// - It is not infinite.
 /// - It is not zero.
-#[cfg_attr(feature = "serde", derive(Serialize))]
 #[derive(Debug, Copy, Clone)]
 #[repr(transparent)]
 pub struct NonZeroNonNaNFinite<T = f64>(T);
@@ -97,7 +181,6 @@ pub struct NonZeroNonNaNFinite<T = f64>(T);
 /// It satisfies the following constraints:
 /// - It is not NaN.
 /// - It is not negative.
-#[cfg_attr(feature = "serde", derive(Serialize))]
 #[derive(Debug, Copy, Clone)]
 #[repr(transparent)]
 pub struct Positive<T = f64>(T);
@@ -107,7 +190,6 @@ pub struct Positive<T = f64>(T);
 /// It satisfies the following constraints:
 /// - It is not NaN.
 /// - It is not positive.
-#[cfg_attr(feature = "serde", derive(Serialize))]
 #[derive(Debug, Copy, Clone)]
 #[repr(transparent)]
 pub struct Negative<T = f64>(T);
@@ -118,7 +200,6 @@ pub struct Negative<T = f64>(T);
 /// - It is not NaN.
 /// - It is not infinite.
 /// - It is not negative.
-#[cfg_attr(feature = "serde", derive(Serialize))]
 #[derive(Debug, Copy, Clone)]
 #[repr(transparent)]
 pub struct PositiveFinite<T = f64>(T);
@@ -129,7 +210,6 @@ pub struct PositiveFinite<T = f64>(T);
 /// - It is not NaN.
 /// - It is not infinite.
 /// - It is not positive.
-#[cfg_attr(feature = "serde", derive(Serialize))]
 #[derive(Debug, Copy, Clone)]
 #[repr(transparent)]
 pub struct NegativeFinite<T = f64>(T);
@@ -140,7 +220,6 @@ pub struct NegativeFinite<T = f64>(T);
 /// - It is not NaN.
 /// - It is not zero.
 /// - It is not negative.
-#[cfg_attr(feature = "serde", derive(Serialize))]
 #[derive(Debug, Copy, Clone)]
 #[repr(transparent)]
 pub struct StrictlyPositive<T = f64>(T);
@@ -151,7 +230,6 @@ pub struct StrictlyPositive<T = f64>(T);
 /// - It is not NaN.
 /// - It is not zero.
 /// - It is not positive.
-#[cfg_attr(feature = "serde", derive(Serialize))]
 #[derive(Debug, Copy, Clone)]
 #[repr(transparent)]
 pub struct StrictlyNegative<T = f64>(T);
@@ -161,7 +239,6 @@ pub struct StrictlyNegative<T = f64>(T);
 /// It satisfies the following constraints:
 /// - It is not NaN.
 /// - It is not negative.
-#[cfg_attr(feature = "serde", derive(Serialize))]
 #[derive(Debug, Copy, Clone)]
 #[repr(transparent)]
 pub struct StrictlyPositiveFinite<T = f64>(T);
@@ -171,22 +248,68 @@ pub struct StrictlyPositiveFinite<T = f64>(T);
 /// It satisfies the following constraints:
 /// - It is not NaN.
 /// - It is not positive.
-#[cfg_attr(feature = "serde", derive(Serialize))]
 #[derive(Debug, Copy, Clone)]
 #[repr(transparent)]
 pub struct StrictlyNegativeFinite<T = f64>(T);
 
-use crate::traits::{Max, Min};
+/// A floating point number in the unit interval `[0, 1]`
+///
+/// It satisfies the following constraints:
+/// - It is not NaN.
+/// - It is not negative (`-0.0` is not valid).
+/// - It is not greater than one.
+#[derive(Debug, Copy, Clone)]
+#[repr(transparent)]
+pub struct UnitInterval<T = f64>(T);
+
+/// A floating point number in the range `[LO, HI]`
+///
+/// The bounds are the bits of two `f64`, as returned by [`bound`],
+/// because floats can't be used as const generic parameters.
+///
+/// It satisfies the following constraints:
+/// - It is not NaN.
+/// - It is greater than or equal to `LO`.
+/// - It is less than or equal to `HI`.
+///
+/// If the bounds are `NaN` or `LO > HI`, no value is valid.
+///
+/// # Examples
+///
+/// ```
+/// # use typed_floats::*;
+/// type Celsius = Bounded<f64, { bound(-273.15) }, { bound(1e6) }>;
+///
+/// let a = Celsius::new(20.0).unwrap();
+/// let b = Celsius::new(100.0).unwrap();
+///
+/// assert_eq!(a.midpoint(b), 60.0);
+/// assert_eq!(Celsius::new(-300.0), Err(RangeError::OutOfRange));
+/// ```
+#[derive(Debug, Copy, Clone)]
+#[repr(transparent)]
+pub struct Bounded<T, const LO_BITS: u64, const HI_BITS: u64>(T);
+
+use crate::traits::{
+    AbsDiff, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Clamp, Lerp, Max, Midpoint, Min,
+    PositiveDiff, SaturatingAdd, SaturatingMul, SaturatingSub, TotalMax, TotalMin,
+};
 
 #[cfg(any(feature = "std", feature = "libm"))]
-use crate::traits::{Atan2, Copysign, DivEuclid, Hypot, Powf};
+use crate::traits::{Atan2, Copysign, DivEuclid, Hypot, Log, MulAdd, Powf, PowiConst, RemEuclid};
 
 #[cfg(all(feature = "libm", not(feature = "std")))]
 use num_traits::Float;
 
 mod accept;
+mod bounded;
 mod f32;
 mod f64;
+#[cfg(feature = "half")]
+mod half;
 mod impls;
+mod unit_interval;
+
+pub use bounded::bound;
 
 typed_floats_macros::generate_floats!();
@@ -0,0 +1,282 @@
+use crate::types::accept::{f32_to_bits, f64_to_bits};
+use crate::types::{InvalidNumber, NonNaN, PositiveFinite, RangeError, UnitInterval};
+
+// Unlike the other types, `UnitInterval` has an upper bound, so it is not generated
+// with the others: only the operations that are closed on `[0, 1]` are implemented.
+// To do some other math, convert it into a `PositiveFinite`.
+
+macro_rules! impl_unit_interval {
+    ($float:ident, $to_bits:ident) => {
+        impl UnitInterval<$float> {
+            /// Equivalent to `0.0`
+            pub const ZERO: Self = Self(0.0);
+
+            /// Equivalent to `1.0`
+            pub const ONE: Self = Self(1.0);
+
+            /// Creates a new value from a primitive type
+            /// It adds a little overhead compared to `new_unchecked`
+            /// because it checks that the value is valid
+            ///
+            /// # Errors
+            /// Returns an error if the value is not valid
+            #[inline]
+            pub fn new(value: $float) -> Result<Self, RangeError> {
+                if value.is_nan() {
+                    Err(InvalidNumber::NaN.into())
+                } else if value.is_sign_negative() {
+                    Err(InvalidNumber::Negative.into())
+                } else if value > 1.0 {
+                    Err(RangeError::OutOfRange)
+                } else {
+                    Ok(Self(value))
+                }
+            }
+
+            /// Creates a new value from a primitive type
+            /// Unlike `new`, it can be used in `const` contexts
+            #[inline]
+            #[must_use]
+            pub const fn try_new(value: $float) -> Option<Self> {
+                if Self::is_valid(value) {
+                    Some(Self(value))
+                } else {
+                    None
+                }
+            }
+
+            /// Creates a new value from a primitive type with zero overhead (in release mode).
+            /// It is up to the caller to ensure that the value is valid
+            ///
+            /// # Safety
+            /// The caller must ensure that the value is valid.
+            /// It will panic in debug mode if the value is not valid,
+            /// but in release mode the behavior is undefined
+            #[inline]
+            #[must_use]
+            pub const unsafe fn new_unchecked(value: $float) -> Self {
+                if !Self::is_valid(value) {
+                    debug_assert!(
+                        false,
+                        concat!(
+                            "The value is not a valid UnitInterval<",
+                            stringify!($float),
+                            ">"
+                        )
+                    );
+
+                    #[cfg(feature = "ensure_no_undefined_behavior")]
+                    panic!(concat!(
+                        "The value is not a valid UnitInterval<",
+                        stringify!($float),
+                        ">"
+                    ));
+
+                    #[cfg(all(
+                        feature = "compiler_hints",
+                        not(feature = "ensure_no_undefined_behavior")
+                    ))]
+                    unsafe {
+                        core::hint::unreachable_unchecked()
+                    }
+                }
+
+                Self(value)
+            }
+
+            /// Returns `true` if `value` is valid for this type.
+            /// Unlike `new`, it can be used in `const` contexts.
+            #[inline]
+            #[must_use]
+            const fn is_valid(value: $float) -> bool {
+                // Without the sign bit, the order of the bits is the order of the values,
+                // and `NaN` and the infinities are greater than `1.0`
+                $to_bits(value) <= $to_bits(1.0)
+            }
+
+            /// Returns the value as a primitive type
+            #[inline]
+            #[must_use]
+            pub const fn get(&self) -> $float {
+                self.0
+            }
+
+            /// Consumes the value and returns it as a primitive type, like `get`
+            #[inline]
+            #[must_use]
+            pub const fn into_inner(self) -> $float {
+                self.0
+            }
+
+            /// Returns `true` if the number is zero.
+            #[inline]
+            #[must_use]
+            pub const fn is_zero(&self) -> bool {
+                $to_bits(self.0) == 0
+            }
+
+            /// Returns `1.0 - self`, which is also in the unit interval.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            /// let a = UnitInterval::<f64>::new(0.25).unwrap();
+            ///
+            /// assert_eq!(a.complement(), 0.75);
+            /// assert_eq!(UnitInterval::<f64>::ONE.complement(), UnitInterval::<f64>::ZERO);
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn complement(self) -> Self {
+                // `1.0 - 1.0` is `+0.0` and `1.0 - 0.0` is `1.0`,
+                // and the subtraction never rounds above `1.0`
+                Self(1.0 - self.0)
+            }
+        }
+
+        impl NonNaN<$float> {
+            /// Clamps the value into the unit interval.
+            ///
+            /// The values greater than `1.0` (including `+inf`) are mapped to `1.0`,
+            /// and the negative values (including `-0.0` and `-inf`) to `+0.0`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            #[doc = concat!("let a = NonNaN::<", stringify!($float), ">::new(1.5).unwrap();")]
+            #[doc = concat!("let b = NonNaN::<", stringify!($float), ">::new(-0.2).unwrap();")]
+            #[doc = concat!("let c = NonNaN::<", stringify!($float), ">::new(0.25).unwrap();")]
+            ///
+            /// assert_eq!(a.to_unit_interval_clamped(), 1.0);
+            /// assert_eq!(b.to_unit_interval_clamped(), 0.0);
+            /// assert_eq!(c.to_unit_interval_clamped(), 0.25);
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn to_unit_interval_clamped(self) -> UnitInterval<$float> {
+                let value = self.get();
+
+                let value = if value >= 1.0 {
+                    1.0
+                } else if value > 0.0 {
+                    value
+                } else {
+                    0.0
+                };
+
+                UnitInterval::<$float>(value)
+            }
+        }
+
+        impl core::ops::Mul for UnitInterval<$float> {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: Self) -> Self::Output {
+                // The product of two numbers in `[0, 1]` is in `[0, 1]`,
+                // and it is `+0.0` if it underflows
+                Self(self.0 * rhs.0)
+            }
+        }
+
+        impl core::ops::MulAssign for UnitInterval<$float> {
+            #[inline]
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl TryFrom<$float> for UnitInterval<$float> {
+            type Error = RangeError;
+
+            #[inline]
+            fn try_from(value: $float) -> Result<Self, Self::Error> {
+                Self::new(value)
+            }
+        }
+
+        impl TryFrom<PositiveFinite<$float>> for UnitInterval<$float> {
+            type Error = RangeError;
+
+            #[inline]
+            fn try_from(value: PositiveFinite<$float>) -> Result<Self, Self::Error> {
+                Self::new(value.get())
+            }
+        }
+
+        impl From<UnitInterval<$float>> for $float {
+            #[inline]
+            fn from(value: UnitInterval<$float>) -> Self {
+                value.0
+            }
+        }
+
+        impl From<UnitInterval<$float>> for PositiveFinite<$float> {
+            #[inline]
+            fn from(value: UnitInterval<$float>) -> Self {
+                // # Safety
+                // A number in `[0, 1]` is always positive and finite
+                unsafe { Self::new_unchecked(value.0) }
+            }
+        }
+
+        impl Eq for UnitInterval<$float> {}
+
+        impl PartialEq for UnitInterval<$float> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl PartialEq<$float> for UnitInterval<$float> {
+            #[inline]
+            fn eq(&self, other: &$float) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl Ord for UnitInterval<$float> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                // There is no `NaN` nor `-0.0`, so the order of the bits is the order of the values
+                self.0.to_bits().cmp(&other.0.to_bits())
+            }
+        }
+
+        impl PartialOrd for UnitInterval<$float> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl core::hash::Hash for UnitInterval<$float> {
+            #[inline]
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                // There is no `-0.0`, so equal values have the same bits
+                self.0.to_bits().hash(state);
+            }
+        }
+
+        impl core::fmt::Display for UnitInterval<$float> {
+            #[inline]
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+    };
+}
+
+impl_unit_interval!(f32, f32_to_bits);
+impl_unit_interval!(f64, f64_to_bits);
+
+impl From<UnitInterval<f32>> for UnitInterval<f64> {
+    #[inline]
+    fn from(value: UnitInterval<f32>) -> Self {
+        // The conversion to `f64` is exact, so the value is still valid
+        Self(f64::from(value.0))
+    }
+}
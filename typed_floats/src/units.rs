@@ -0,0 +1,190 @@
+//! Thin wrappers around [`StrictlyPositiveFinite`] for the quantities expressed in percent or basis points.
+//!
+//! A percent is a hundredth, and a basis point is a hundredth of a percent.
+//! The conversions between them may overflow or be rounded to zero, so they are done with `TryFrom`,
+//! but the conversion into a ratio is always valid.
+//!
+//! # Examples
+//!
+//! ```
+//! use typed_floats::units::{BasisPoints, Percent};
+//! use typed_floats::*;
+//!
+//! let rate = Percent::try_from(1.5).unwrap();
+//!
+//! let ratio: PositiveFinite = rate.as_ratio();
+//! assert_eq!(ratio, 0.015);
+//!
+//! let spread = BasisPoints::try_from(rate).unwrap();
+//! assert_eq!(spread.get(), 150.0);
+//! ```
+
+use crate::{CheckedAdd, InvalidNumber, PositiveFinite, SaturatingAdd, StrictlyPositiveFinite};
+
+/// A quantity in percent, e.g. `Percent(50.0)` is half.
+///
+/// Adding two percents may overflow, so they are added with [`CheckedAdd`] or [`SaturatingAdd`],
+/// which both return a `Percent`.
+///
+/// # Examples
+///
+/// ```
+/// use typed_floats::units::Percent;
+/// use typed_floats::*;
+///
+/// let a = Percent::try_from(50.0).unwrap();
+/// let b = Percent::try_from(25.0).unwrap();
+///
+/// let c: Percent = a.checked_add(b).unwrap();
+/// assert_eq!(c.as_ratio(), 0.75);
+///
+/// assert!(Percent::try_from(0.0).is_err());
+/// ```
+#[derive(Debug, Copy, Clone)]
+#[repr(transparent)]
+pub struct Percent(StrictlyPositiveFinite<f64>);
+
+/// A quantity in basis points, a hundredth of a percent, e.g. `BasisPoints(25.0)` is `0.25%`.
+///
+/// Adding two basis points may overflow, so they are added with [`CheckedAdd`] or [`SaturatingAdd`],
+/// which both return `BasisPoints`.
+///
+/// # Examples
+///
+/// ```
+/// use typed_floats::units::BasisPoints;
+/// use typed_floats::*;
+///
+/// let spread = BasisPoints::try_from(25.0).unwrap();
+///
+/// assert_eq!(spread.as_ratio(), 0.0025);
+/// ```
+#[derive(Debug, Copy, Clone)]
+#[repr(transparent)]
+pub struct BasisPoints(StrictlyPositiveFinite<f64>);
+
+macro_rules! impl_unit {
+    ($unit:ident, $per_one:expr) => {
+        impl $unit {
+            /// Creates a new value from a number of units.
+            #[inline]
+            #[must_use]
+            pub const fn new(value: StrictlyPositiveFinite<f64>) -> Self {
+                Self(value)
+            }
+
+            /// Returns the number of units.
+            #[inline]
+            #[must_use]
+            pub const fn get(self) -> StrictlyPositiveFinite<f64> {
+                self.0
+            }
+
+            /// Returns the quantity as a ratio, e.g. `0.5` for `50%`.
+            ///
+            /// It may be rounded to zero, so it is a [`PositiveFinite`].
+            #[inline]
+            #[must_use]
+            pub fn as_ratio(self) -> PositiveFinite<f64> {
+                // # Safety
+                // A strictly positive finite number divided by a number greater than one
+                // is positive and finite, but it may be rounded to zero.
+                unsafe { PositiveFinite::<f64>::new_unchecked(self.0.get() / $per_one) }
+            }
+        }
+
+        impl PartialEq for $unit {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl Eq for $unit {}
+
+        impl PartialOrd for $unit {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $unit {
+            #[inline]
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        impl core::hash::Hash for $unit {
+            #[inline]
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.0.hash(state);
+            }
+        }
+
+        impl From<StrictlyPositiveFinite<f64>> for $unit {
+            #[inline]
+            fn from(value: StrictlyPositiveFinite<f64>) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<$unit> for StrictlyPositiveFinite<f64> {
+            #[inline]
+            fn from(value: $unit) -> Self {
+                value.0
+            }
+        }
+
+        impl TryFrom<f64> for $unit {
+            type Error = InvalidNumber;
+
+            #[inline]
+            fn try_from(value: f64) -> Result<Self, Self::Error> {
+                StrictlyPositiveFinite::<f64>::new(value).map(Self)
+            }
+        }
+
+        impl CheckedAdd<Self> for $unit {
+            type Output = Self;
+
+            #[inline]
+            fn checked_add(self, rhs: Self) -> Result<Self::Output, InvalidNumber> {
+                self.0.checked_add(rhs.0).map(Self)
+            }
+        }
+
+        impl SaturatingAdd<Self> for $unit {
+            type Output = Self;
+
+            #[inline]
+            fn saturating_add(self, rhs: Self) -> Self::Output {
+                Self(self.0.saturating_add(rhs.0))
+            }
+        }
+    };
+}
+
+impl_unit!(Percent, 100.0);
+impl_unit!(BasisPoints, 10_000.0);
+
+impl TryFrom<Percent> for BasisPoints {
+    type Error = InvalidNumber;
+
+    /// Returns an error if the result overflows.
+    #[inline]
+    fn try_from(value: Percent) -> Result<Self, Self::Error> {
+        Self::try_from(value.0.get() * 100.0)
+    }
+}
+
+impl TryFrom<BasisPoints> for Percent {
+    type Error = InvalidNumber;
+
+    /// Returns an error if the result is rounded to zero.
+    #[inline]
+    fn try_from(value: BasisPoints) -> Result<Self, Self::Error> {
+        Self::try_from(value.0.get() / 100.0)
+    }
+}
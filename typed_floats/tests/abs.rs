@@ -2,3 +2,16 @@
 use typed_floats::*;
 
 typed_floats_macros::generate_tests_self!(abs);
+
+#[test]
+fn test_abs_non_zero() -> Result<(), InvalidNumber> {
+    let a: StrictlyPositiveFinite = tf64::NonZeroNonNaNFinite::try_from(-3.0)?.abs();
+    let b: StrictlyPositive = tf64::NonZeroNonNaN::try_from(f64::NEG_INFINITY)?.abs();
+    let c: StrictlyPositiveFinite = tf64::StrictlyNegativeFinite::try_from(-2.0)?.abs();
+
+    assert_eq!(a, 3.0);
+    assert_eq!(b, f64::INFINITY);
+    assert_eq!(c, 2.0);
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use typed_floats::*;
+
+typed_floats_macros::generate_tests_self_rhs!(abs_diff);
+
+#[test]
+fn test_abs_diff() {
+    let a: tf64::PositiveFinite = 3.0.try_into().unwrap();
+    let b: tf64::PositiveFinite = 7.0.try_into().unwrap();
+
+    let c: tf64::PositiveFinite = a.abs_diff(b);
+    assert_eq!(c, 4.0);
+    assert_eq!(b.abs_diff(a), 4.0);
+
+    // The difference of two finite numbers of different signs may overflow
+    let a: tf64::NonNaNFinite = 3.0.try_into().unwrap();
+    let b: tf64::NonNaNFinite = (-7.0).try_into().unwrap();
+
+    let c: tf64::Positive = a.abs_diff(b);
+    assert_eq!(c, 10.0);
+    assert_eq!(tf64::MAX.abs_diff(tf64::MIN), f64::INFINITY);
+
+    // The zeros are always `+0.0`
+    let a: tf32::NegativeFinite = (-0.0).try_into().unwrap();
+    let b: tf32::PositiveFinite = 0.0.try_into().unwrap();
+    assert_is_positive_zero!(a.abs_diff(b));
+    assert_is_positive_zero!(a.abs_diff(a));
+
+    // `inf - inf` is `NaN`
+    let a: tf64::NonNaN = f64::INFINITY.try_into().unwrap();
+    let c: f64 = a.abs_diff(a);
+    assert!(c.is_nan());
+}
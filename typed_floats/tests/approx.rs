@@ -0,0 +1,63 @@
+#![cfg(feature = "approx")]
+
+use approx::{
+    abs_diff_eq, abs_diff_ne, assert_abs_diff_eq, assert_relative_eq, assert_ulps_eq, relative_ne,
+    ulps_ne,
+};
+use typed_floats::*;
+
+#[test]
+fn test_approx_values() {
+    let values: [f64; 23] = typed_floats_macros::test_values!(f64);
+
+    for &a in &values {
+        for &b in &values {
+            if let (Ok(x), Ok(y)) = (NonNaN::<f64>::new(a), NonNaN::<f64>::new(b)) {
+                assert_eq!(abs_diff_eq!(x, y), abs_diff_eq!(a, b));
+                assert_eq!(relative_ne!(x, y), relative_ne!(a, b));
+                assert_eq!(ulps_ne!(x, y), ulps_ne!(a, b));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_approx_tolerance() {
+    let tenth: StrictlyPositive = 0.1.try_into().unwrap();
+    let fifth: StrictlyPositive = 0.2.try_into().unwrap();
+    let expected: StrictlyPositive = 0.3.try_into().unwrap();
+
+    let sum: StrictlyPositive = tenth + fifth;
+
+    assert_ne!(sum, expected);
+    assert_relative_eq!(sum, expected);
+    assert_ulps_eq!(sum, expected);
+    assert_abs_diff_eq!(sum, expected);
+    assert!(abs_diff_ne!(sum, expected, epsilon = 0.0));
+
+    let x: tf32::NegativeFinite = (-1.0).try_into().unwrap();
+    let y: tf32::NegativeFinite = (-1.05).try_into().unwrap();
+
+    assert_abs_diff_eq!(x, y, epsilon = 0.1);
+    assert_relative_eq!(x, y, max_relative = 0.1);
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn test_approx_sqrt() {
+    let two: StrictlyPositiveFinite = 2.0.try_into().unwrap();
+    let expected: StrictlyPositiveFinite = core::f64::consts::SQRT_2.try_into().unwrap();
+
+    assert_ulps_eq!(two.sqrt(), expected, max_ulps = 1);
+
+    let x: StrictlyPositiveFinite = 1e-10.try_into().unwrap();
+    let expected: StrictlyPositiveFinite = 1e-5.try_into().unwrap();
+
+    assert_relative_eq!(x.sqrt(), expected);
+    assert_ulps_eq!(x.sqrt(), expected, max_ulps = 1);
+
+    let x: tf32::StrictlyPositiveFinite = 3.0.try_into().unwrap();
+    let expected: tf32::StrictlyPositiveFinite = 1.732_050_8.try_into().unwrap();
+
+    assert_ulps_eq!(x.sqrt(), expected, max_ulps = 1);
+}
@@ -0,0 +1,129 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use typed_floats::*;
+
+/// Deterministic pseudo-random bytes, to not depend on `rand`
+fn bytes(len: usize, seed: u64) -> Vec<u8> {
+    let mut state = seed;
+
+    (0..len)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            state.to_be_bytes()[0]
+        })
+        .collect()
+}
+
+macro_rules! test_arbitrary {
+    ($test:ident, $type:ident, $float:ident) => {
+        #[test]
+        fn $test() {
+            for seed in 0..1_000 {
+                let data = bytes(64, seed);
+                let mut u = Unstructured::new(&data);
+
+                while !u.is_empty() {
+                    let value = $type::<$float>::arbitrary(&mut u).unwrap().get();
+
+                    assert!($type::<$float>::new(value).is_ok(), "{value} is not valid");
+                }
+            }
+
+            // Inputs too short are completed with zeros
+            for len in 0..16 {
+                let data = bytes(len, 42);
+                let mut u = Unstructured::new(&data);
+                let value = $type::<$float>::arbitrary(&mut u).unwrap().get();
+
+                assert!($type::<$float>::new(value).is_ok(), "{value} is not valid");
+            }
+
+            // Every bit pattern of the special values is decoded into a valid value
+            for special in [
+                $float::NAN,
+                -$float::NAN,
+                $float::INFINITY,
+                $float::NEG_INFINITY,
+                0.0,
+                -0.0,
+            ] {
+                let data = special.to_bits().to_le_bytes();
+                let value = $type::<$float>::arbitrary(&mut Unstructured::new(&data))
+                    .unwrap()
+                    .get();
+
+                assert!($type::<$float>::new(value).is_ok(), "{value} is not valid");
+            }
+        }
+    };
+}
+
+macro_rules! test_arbitraries {
+    ($test_f32:ident, $test_f64:ident, $type:ident) => {
+        test_arbitrary!($test_f32, $type, f32);
+        test_arbitrary!($test_f64, $type, f64);
+    };
+}
+
+test_arbitraries!(non_nan_f32, non_nan_f64, NonNaN);
+test_arbitraries!(non_nan_finite_f32, non_nan_finite_f64, NonNaNFinite);
+test_arbitraries!(non_zero_non_nan_f32, non_zero_non_nan_f64, NonZeroNonNaN);
+test_arbitraries!(
+    non_zero_non_nan_finite_f32,
+    non_zero_non_nan_finite_f64,
+    NonZeroNonNaNFinite
+);
+test_arbitraries!(positive_f32, positive_f64, Positive);
+test_arbitraries!(positive_finite_f32, positive_finite_f64, PositiveFinite);
+test_arbitraries!(
+    strictly_positive_f32,
+    strictly_positive_f64,
+    StrictlyPositive
+);
+test_arbitraries!(
+    strictly_positive_finite_f32,
+    strictly_positive_finite_f64,
+    StrictlyPositiveFinite
+);
+test_arbitraries!(negative_f32, negative_f64, Negative);
+test_arbitraries!(negative_finite_f32, negative_finite_f64, NegativeFinite);
+test_arbitraries!(
+    strictly_negative_f32,
+    strictly_negative_f64,
+    StrictlyNegative
+);
+test_arbitraries!(
+    strictly_negative_finite_f32,
+    strictly_negative_finite_f64,
+    StrictlyNegativeFinite
+);
+
+#[test]
+fn edge_cases() {
+    let decode = |bits: u64| {
+        tf64::NonNaN::arbitrary(&mut Unstructured::new(&bits.to_le_bytes()))
+            .unwrap()
+            .get()
+    };
+
+    assert_eq!(decode(f64::INFINITY.to_bits()), f64::INFINITY);
+    assert_eq!(decode(f64::NEG_INFINITY.to_bits()), f64::NEG_INFINITY);
+    assert!(decode((-0.0f64).to_bits()).is_sign_negative());
+    assert_eq!(decode(f64::NAN.to_bits()), f64::INFINITY);
+
+    let empty = tf64::StrictlyPositiveFinite::arbitrary(&mut Unstructured::new(&[])).unwrap();
+
+    assert_eq!(empty.get(), f64::from_bits(1));
+
+    let empty = tf32::Negative::arbitrary(&mut Unstructured::new(&[])).unwrap();
+
+    assert!(empty.is_negative_zero());
+
+    let nan = f32::NAN.to_bits().to_le_bytes();
+    let max = tf32::PositiveFinite::arbitrary(&mut Unstructured::new(&nan)).unwrap();
+
+    assert_eq!(max.get(), f32::MAX);
+}
@@ -0,0 +1,83 @@
+use core::ops::{DivAssign, RemAssign};
+use typed_floats::*;
+
+// The assign operators are only implemented when the result always fits into the left operand's type.
+// The pairs that must not compile are checked by the `compile_fail` examples of the crate documentation.
+
+fn rem_assign<A: RemAssign<B> + Copy, B>(a: A, b: B) -> A {
+    let mut a = a;
+    a %= b;
+    a
+}
+
+fn div_assign<A: DivAssign<B> + Copy, B>(a: A, b: B) -> A {
+    let mut a = a;
+    a /= b;
+    a
+}
+
+#[test]
+fn test_rem_assign() {
+    let a: tf64::PositiveFinite = 5.0.try_into().unwrap();
+    let b: tf64::StrictlyPositiveFinite = 3.0.try_into().unwrap();
+    let c: tf64::StrictlyNegativeFinite = (-3.0).try_into().unwrap();
+
+    assert_eq!(rem_assign(a, b), a % b);
+    assert_eq!(rem_assign(a, c), a % c);
+
+    // The result has the sign of the dividend, and `x % inf` is `x`
+    let a: tf64::NegativeFinite = (-5.0).try_into().unwrap();
+    let d: tf64::NonZeroNonNaN = f64::INFINITY.try_into().unwrap();
+
+    assert_eq!(rem_assign(a, b), -2.0);
+    assert_eq!(rem_assign(a, d), -5.0);
+
+    let a: tf32::NonNaNFinite = 7.5.try_into().unwrap();
+    let b: tf32::NonZeroNonNaNFinite = (-2.0).try_into().unwrap();
+
+    assert_eq!(rem_assign(a, b), 1.5);
+}
+
+#[test]
+fn test_div_assign() {
+    let a: tf64::Positive = 6.0.try_into().unwrap();
+    let b: tf64::StrictlyPositiveFinite = 4.0.try_into().unwrap();
+
+    assert_eq!(div_assign(a, b), 1.5);
+    assert_eq!(div_assign(tf64::Positive::from(tf64::ZERO), b), 0.0);
+
+    let a: tf64::NonNaN = f64::INFINITY.try_into().unwrap();
+    let b: tf64::NonZeroNonNaNFinite = (-2.0).try_into().unwrap();
+
+    assert_eq!(div_assign(a, b), f64::NEG_INFINITY);
+
+    let a: tf32::Negative = (-1.0).try_into().unwrap();
+    let b: tf32::StrictlyPositiveFinite = f32::MAX.try_into().unwrap();
+
+    assert_eq!(div_assign(a, b), -1.0 / f32::MAX);
+}
+
+#[test]
+fn test_assign_same_as_op() {
+    let values: [f64; 23] = typed_floats_macros::test_values!(f64);
+
+    for &a in &values {
+        let Ok(a) = tf64::PositiveFinite::new(a) else {
+            continue;
+        };
+
+        for &b in &values {
+            if let Ok(b) = tf64::NonZeroNonNaNFinite::new(b) {
+                assert_eq!(rem_assign(a, b), a % b);
+            }
+
+            let Ok(a) = tf64::NonNaN::try_from(a) else {
+                continue;
+            };
+
+            if let Ok(b) = tf64::StrictlyNegativeFinite::new(b) {
+                assert_eq!(div_assign(a, b), a / b);
+            }
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use core::borrow::Borrow;
+use typed_floats::*;
+
+fn sum<B: Borrow<f64>>(values: &[B]) -> f64 {
+    values.iter().map(|value| *value.borrow()).sum()
+}
+
+fn first<R: AsRef<f32>>(values: &[R]) -> Option<f32> {
+    values.first().map(|value| *value.as_ref())
+}
+
+#[test]
+fn test_borrow() {
+    let values: [PositiveFinite; 3] = [
+        1.0.try_into().unwrap(),
+        2.0.try_into().unwrap(),
+        3.5.try_into().unwrap(),
+    ];
+
+    assert_eq!(sum(&values).to_bits(), 6.5_f64.to_bits());
+    let zero: &f64 = tf64::NEG_ZERO.borrow();
+    assert_eq!(zero.to_bits(), (-0.0_f64).to_bits());
+}
+
+#[test]
+fn test_as_ref() {
+    let values: [tf32::StrictlyNegative; 2] = [(-1.5).try_into().unwrap(), tf32::NEG_INFINITY];
+
+    assert_eq!(first(&values).map(f32::to_bits), Some((-1.5_f32).to_bits()));
+    assert_eq!(first::<tf32::NonNaN>(&[]), None);
+}
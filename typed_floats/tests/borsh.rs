@@ -0,0 +1,48 @@
+#![cfg(feature = "borsh")]
+
+use typed_floats::*;
+
+#[test]
+fn test_borsh_round_trip() {
+    let x: tf64::StrictlyPositiveFinite = 1.5.try_into().unwrap();
+
+    let bytes = borsh::to_vec(&x).unwrap();
+
+    assert_eq!(bytes, 1.5f64.to_le_bytes());
+    assert_eq!(
+        borsh::from_slice::<tf64::StrictlyPositiveFinite>(&bytes).unwrap(),
+        x
+    );
+
+    let values: Vec<tf32::Negative> = [-0.0f32, -1.0, f32::NEG_INFINITY]
+        .iter()
+        .map(|&x| tf32::Negative::new(x).unwrap())
+        .collect();
+
+    let bytes = borsh::to_vec(&values).unwrap();
+    let deserialized = borsh::from_slice::<Vec<tf32::Negative>>(&bytes).unwrap();
+
+    assert_eq!(deserialized, values);
+    assert_is_negative_zero!(deserialized[0]);
+}
+
+#[test]
+fn test_borsh_invalid() {
+    let bytes = f64::NAN.to_le_bytes();
+    let error = borsh::from_slice::<tf64::NonNaNFinite>(&bytes).unwrap_err();
+
+    assert_eq!(error.kind(), borsh::io::ErrorKind::InvalidData);
+    assert_eq!(error.to_string(), "Number is NaN");
+
+    let bytes = f32::INFINITY.to_le_bytes();
+    let error = borsh::from_slice::<tf32::NonNaNFinite>(&bytes).unwrap_err();
+
+    assert_eq!(error.to_string(), "Number is infinite");
+
+    let bytes = (-1.0f64).to_le_bytes();
+    let error = borsh::from_slice::<tf64::Positive>(&bytes).unwrap_err();
+
+    assert_eq!(error.to_string(), "Number is negative");
+
+    assert!(borsh::from_slice::<tf64::Positive>(&[0; 4]).is_err());
+}
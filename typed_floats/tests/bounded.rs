@@ -0,0 +1,111 @@
+use typed_floats::*;
+
+type Celsius = Bounded<f64, { bound(-273.15) }, { bound(1e6) }>;
+type Celsius32 = Bounded<f32, { bound(-273.15) }, { bound(1e6) }>;
+type Large = Bounded<f64, { bound(0.0) }, { bound(f64::MAX) }>;
+type Whole = Bounded<f64, { bound(f64::NEG_INFINITY) }, { bound(f64::INFINITY) }>;
+type Whole32 = Bounded<f32, { bound(f64::NEG_INFINITY) }, { bound(f64::INFINITY) }>;
+
+typed_floats_macros::generate_tests_values!(bounded, {
+    type Range = Bounded<Float, { bound(-273.15) }, { bound(1e6) }>;
+
+    for &value in &values {
+        let res = Range::new(value);
+        let valid = f64::from(value) >= -273.15 && f64::from(value) <= 1e6;
+
+        assert_eq!(res.is_ok(), valid);
+        assert_eq!(Range::try_from(value), res);
+
+        if let Ok(typed) = res {
+            assert_eq!(typed, value);
+            assert_eq!(Float::from(typed).to_bits(), value.to_bits());
+            assert_eq!(NonNaN::<Float>::from(typed), value);
+            assert_eq!(typed.midpoint(typed), typed);
+        } else if value.is_nan() {
+            assert_eq!(res, Err(RangeError::InvalidNumber(InvalidNumber::NaN)));
+        } else {
+            assert_eq!(res, Err(RangeError::OutOfRange));
+        }
+    }
+});
+
+#[test]
+fn test_bounds() {
+    assert_eq!(Celsius::LO.to_bits(), (-273.15_f64).to_bits());
+    assert_eq!(Celsius::HI.to_bits(), 1e6_f64.to_bits());
+
+    assert!(Celsius::new(-273.15).is_ok());
+    assert!(Celsius::new(1e6).is_ok());
+    assert_eq!(
+        Celsius::new(-273.150_000_000_000_1),
+        Err(RangeError::OutOfRange)
+    );
+    assert_eq!(Celsius::new(1e6 + 1e-6), Err(RangeError::OutOfRange));
+    assert_eq!(Celsius::new(f64::INFINITY), Err(RangeError::OutOfRange));
+    assert_eq!(
+        Celsius::new(f64::NAN),
+        Err(RangeError::InvalidNumber(InvalidNumber::NaN))
+    );
+
+    // The bounds are compared exactly: the `f32` nearest to `-273.15` is above the bound
+    // and the next one below is out of the range
+    let above = -273.15_f32;
+    let below = f32::from_bits(above.to_bits() + 1);
+    assert!(f64::from(above) > -273.15 && f64::from(below) < -273.15);
+    assert!(Celsius32::new(above).is_ok());
+    assert_eq!(Celsius32::new(below), Err(RangeError::OutOfRange));
+}
+
+#[test]
+fn test_invalid_bounds() {
+    type Empty = Bounded<f64, { bound(1.0) }, { bound(0.0) }>;
+    type NaN = Bounded<f64, { bound(f64::NAN) }, { bound(1.0) }>;
+
+    assert_eq!(Empty::new(0.5), Err(RangeError::OutOfRange));
+    assert_eq!(NaN::new(0.5), Err(RangeError::OutOfRange));
+}
+
+#[test]
+fn test_clamp() {
+    let min = Celsius::new(0.0).unwrap();
+    let max = Celsius::new(100.0).unwrap();
+
+    let cold: Celsius = Celsius::new(-200.0).unwrap().clamp(min, max);
+    let hot: Celsius = Celsius::new(500.0).unwrap().clamp(min, max);
+    let warm: Celsius = Celsius::new(20.0).unwrap().clamp(min, max);
+
+    assert_eq!(cold, min);
+    assert_eq!(hot, max);
+    assert_eq!(warm, 20.0);
+}
+
+#[test]
+fn test_midpoint() {
+    let low = Celsius::new(-273.15).unwrap();
+    let high = Celsius::new(1e6).unwrap();
+
+    let mid: Celsius = low.midpoint(high);
+    assert_eq!(mid, (1e6 - 273.15) / 2.0);
+    assert_eq!(high.midpoint(low), mid);
+
+    let max = Large::new(f64::MAX).unwrap();
+    assert_eq!(max.midpoint(max), f64::MAX);
+}
+
+#[test]
+fn test_midpoint_infinite_bounds() {
+    let low = Whole::new(f64::NEG_INFINITY).unwrap();
+    let high = Whole::new(f64::INFINITY).unwrap();
+    let one = Whole::new(1.0).unwrap();
+
+    assert_eq!(low.midpoint(high), 0.0);
+    assert_eq!(high.midpoint(low), 0.0);
+    assert_eq!(low.midpoint(low), f64::NEG_INFINITY);
+    assert_eq!(high.midpoint(one), f64::INFINITY);
+
+    let low = Whole32::new(f32::NEG_INFINITY).unwrap();
+    let high = Whole32::new(f32::INFINITY).unwrap();
+
+    assert_eq!(low.midpoint(high), 0.0);
+    assert_eq!(high.midpoint(low), 0.0);
+}
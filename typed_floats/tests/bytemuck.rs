@@ -0,0 +1,60 @@
+#![cfg(feature = "bytemuck")]
+
+use typed_floats::*;
+
+#[test]
+fn test_bytemuck_cast_slice() {
+    let values: Vec<PositiveFinite<f32>> = [0.0f32, 1.5, 3.0]
+        .iter()
+        .filter_map(|&x| PositiveFinite::<f32>::new(x).ok())
+        .collect();
+
+    let floats: &[f32] = bytemuck::cast_slice(&values);
+
+    assert_eq!(floats, &[0.0, 1.5, 3.0]);
+
+    let floats: Vec<f64> =
+        bytemuck::allocation::pod_collect_to_vec(&[tf64::consts::PI, tf64::consts::E]);
+
+    assert_eq!(floats, [core::f64::consts::PI, core::f64::consts::E]);
+}
+
+#[test]
+fn test_bytemuck_checked_cast_slice() {
+    let floats = [1.0f64, 2.0, 3.0];
+    let values: &[StrictlyPositive<f64>] = bytemuck::checked::cast_slice(&floats);
+
+    assert_eq!(values, &[1.0, 2.0, 3.0]);
+
+    let floats = [1.0f32, 0.0];
+    let res = bytemuck::checked::try_cast_slice::<f32, StrictlyPositive<f32>>(&floats);
+
+    assert_eq!(
+        res,
+        Err(bytemuck::checked::CheckedCastError::InvalidBitPattern)
+    );
+
+    let floats = [-1.0f32, f32::NAN];
+    let res = bytemuck::checked::try_cast_slice::<f32, NonNaN<f32>>(&floats);
+
+    assert_eq!(
+        res,
+        Err(bytemuck::checked::CheckedCastError::InvalidBitPattern)
+    );
+
+    let res = bytemuck::checked::try_cast::<f64, Negative<f64>>(-0.0);
+
+    assert!(res.is_ok_and(|x| x.is_negative_zero()));
+}
+
+#[test]
+fn test_bytemuck_zeroed() {
+    let zeros: Vec<Positive<f64>> = bytemuck::zeroed_vec(3);
+
+    assert_eq!(zeros, [0.0, 0.0, 0.0]);
+    assert!(zeros.iter().all(|x| x.is_positive_zero()));
+
+    let zero: NonNaNFinite<f32> = bytemuck::Zeroable::zeroed();
+
+    assert!(zero.is_positive_zero());
+}
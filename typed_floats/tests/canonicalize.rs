@@ -0,0 +1,43 @@
+use typed_floats::*;
+
+typed_floats_macros::generate_tests_values!(canonicalize, Type in [NonNaN, NonNaNFinite], {
+    for &value in &values {
+        let Ok(x) = Type::new(value) else {
+            continue;
+        };
+
+        let canonical = x.canonicalize();
+
+        assert_eq!(canonical, x);
+
+        if value == 0.0 {
+            assert_is_positive_zero!(canonical);
+        } else {
+            assert_eq!(canonical.get().to_bits(), value.to_bits());
+        }
+    }
+});
+
+#[test]
+fn test_canonicalize_values() {
+    let neg_zero: tf64::NonNaNFinite = tf64::NEG_ZERO.into();
+    assert_is_positive_zero!(neg_zero.canonicalize());
+    assert_is_positive_zero!(tf64::NonNaNFinite::from(tf64::ZERO).canonicalize());
+
+    // The negative values are kept
+    let x: tf64::NonNaNFinite = (-3.0).try_into().unwrap();
+    assert_eq!(x.canonicalize().get().to_bits(), (-3.0f64).to_bits());
+
+    let x: tf32::NonNaN = f32::NEG_INFINITY.try_into().unwrap();
+    assert_eq!(x.canonicalize(), f32::NEG_INFINITY);
+
+    // The bits of equal values are equal once canonical
+    let a: tf64::NonNaN = (-0.0).try_into().unwrap();
+    let b: tf64::NonNaN = 0.0.try_into().unwrap();
+    assert_eq!(a, b);
+    assert_ne!(a.get().to_bits(), b.get().to_bits());
+    assert_eq!(
+        a.canonicalize().get().to_bits(),
+        b.canonicalize().get().to_bits()
+    );
+}
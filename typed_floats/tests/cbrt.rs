@@ -3,3 +3,53 @@
 use typed_floats::*;
 
 typed_floats_macros::generate_tests_self!(cbrt);
+
+#[test]
+fn test_cbrt_types() {
+    let x: StrictlyNegative = (-8.0).try_into().unwrap();
+    let y: StrictlyNegative = x.cbrt();
+    assert_eq!(y, -2.0);
+
+    let x: StrictlyNegativeFinite<f32> = (-27.0).try_into().unwrap();
+    let y: StrictlyNegativeFinite<f32> = x.cbrt();
+    assert_eq!(y, -3.0);
+
+    let x: Negative = tf64::NEG_ZERO.into();
+    let y: Negative = x.cbrt();
+    assert_is_negative_zero!(y);
+
+    let y: NegativeFinite = tf64::NEG_ZERO.cbrt();
+    assert_is_negative_zero!(y);
+
+    let x: StrictlyPositive = tf64::INFINITY.into();
+    let y: StrictlyPositive = x.cbrt();
+    assert_eq!(y, f64::INFINITY);
+
+    let y: PositiveFinite = tf64::ZERO.cbrt();
+    assert_is_positive_zero!(y);
+
+    let x: NonZeroNonNaNFinite = (-1.0).try_into().unwrap();
+    let y: NonZeroNonNaNFinite = x.cbrt();
+    assert_eq!(y, -1.0);
+
+    let x: NonNaN = f64::NEG_INFINITY.try_into().unwrap();
+    let y: NonNaN = x.cbrt();
+    assert_eq!(y, f64::NEG_INFINITY);
+}
+
+#[test]
+fn test_cbrt_extremes() {
+    // The cube root of a finite number is never infinite
+    let y: StrictlyPositiveFinite = tf64::MAX.cbrt();
+    assert!(y.get() < 6e102);
+
+    let y: StrictlyNegativeFinite = tf64::MIN.cbrt();
+    assert!(y.get() > -6e102);
+
+    // And the cube root of a non-zero number is never zero
+    let x: StrictlyPositiveFinite = f64::from_bits(1).try_into().unwrap();
+    assert!(x.cbrt().get() > 1e-108);
+
+    let x: StrictlyNegativeFinite<f32> = (-f32::from_bits(1)).try_into().unwrap();
+    assert!(x.cbrt().get() < -1e-15);
+}
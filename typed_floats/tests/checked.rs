@@ -0,0 +1,99 @@
+use typed_floats::*;
+
+typed_floats_macros::generate_tests_values!(
+    checked,
+    Lhs in [
+        NonNaNFinite,
+        NonZeroNonNaNFinite,
+        PositiveFinite,
+        NegativeFinite,
+        StrictlyPositiveFinite,
+        StrictlyNegativeFinite
+    ],
+    Rhs in [
+        NonNaNFinite,
+        NonZeroNonNaNFinite,
+        PositiveFinite,
+        NegativeFinite,
+        StrictlyPositiveFinite,
+        StrictlyNegativeFinite
+    ],
+    {
+        for &a in &values {
+            let Ok(a) = Lhs::new(a) else {
+                continue;
+            };
+
+            for &b in &values {
+                let Ok(b) = Rhs::new(b) else {
+                    continue;
+                };
+
+                for (checked, raw) in [
+                    (a.checked_add(b).map(|x| x.get()), a.get() + b.get()),
+                    (a.checked_sub(b).map(|x| x.get()), a.get() - b.get()),
+                    (a.checked_mul(b).map(|x| x.get()), a.get() * b.get()),
+                    (a.checked_div(b).map(|x| x.get()), a.get() / b.get()),
+                ] {
+                    if raw.is_nan() {
+                        assert_eq!(checked, Err(InvalidNumber::NaN));
+                    } else if raw.is_infinite() {
+                        assert_eq!(checked, Err(InvalidNumber::Infinite));
+                    } else {
+                        assert_eq!(checked.map(Float::to_bits), Ok(raw.to_bits()));
+                    }
+                }
+            }
+        }
+    }
+);
+
+#[test]
+fn test_checked_overflow() {
+    assert_eq!(
+        tf64::MAX.checked_add(tf64::MAX),
+        Err(InvalidNumber::Infinite)
+    );
+    assert_eq!(
+        tf64::MIN.checked_sub(tf64::MAX),
+        Err(InvalidNumber::Infinite)
+    );
+    assert_eq!(
+        tf32::MAX.checked_mul(tf32::MIN),
+        Err(InvalidNumber::Infinite)
+    );
+
+    let min: tf64::StrictlyPositiveFinite = f64::from_bits(1).try_into().unwrap();
+
+    assert_eq!(tf64::MAX.checked_div(min), Err(InvalidNumber::Infinite));
+}
+
+#[test]
+fn test_checked_div_by_zero() {
+    let one: tf64::PositiveFinite = 1.0.try_into().unwrap();
+    let zero: tf64::PositiveFinite = 0.0.try_into().unwrap();
+    let neg_zero: tf64::NegativeFinite = (-0.0).try_into().unwrap();
+
+    assert_eq!(one.checked_div(zero), Err(InvalidNumber::Infinite));
+    assert_eq!(one.checked_div(neg_zero), Err(InvalidNumber::Infinite));
+    assert_eq!(zero.checked_div(zero), Err(InvalidNumber::NaN));
+    assert_eq!(neg_zero.checked_div(zero), Err(InvalidNumber::NaN));
+}
+
+#[test]
+fn test_checked_result_types() {
+    let a: tf64::StrictlyPositiveFinite = 1.0.try_into().unwrap();
+    let b: tf64::PositiveFinite = 2.0.try_into().unwrap();
+
+    let res: Result<tf64::StrictlyPositiveFinite, InvalidNumber> = a.checked_add(b);
+    assert_eq!(res, Ok(3.0.try_into().unwrap()));
+
+    let res: Result<tf64::NonNaNFinite, InvalidNumber> = a.checked_sub(b);
+    assert_eq!(res, Ok((-1.0).try_into().unwrap()));
+
+    let res: Result<tf64::PositiveFinite, InvalidNumber> = a.checked_mul(b);
+    assert_eq!(res, Ok(2.0.try_into().unwrap()));
+
+    let res: Result<tf64::PositiveFinite, InvalidNumber> = a.checked_div(b);
+    assert_eq!(res, Ok(0.5.try_into().unwrap()));
+}
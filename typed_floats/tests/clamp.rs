@@ -0,0 +1,26 @@
+use typed_floats::*;
+
+typed_floats_macros::generate_tests_ternary!(clamp);
+
+#[test]
+fn test_clamp_finite_bounds() {
+    let min: Positive = 0.0.try_into().unwrap();
+    let max: StrictlyPositiveFinite = 1.0.try_into().unwrap();
+
+    let x: Positive = tf64::INFINITY.into();
+    let res: PositiveFinite = Clamp::clamp(x, min, max);
+    assert_eq!(res, 1.0);
+
+    let x: NonNaN = tf64::NEG_INFINITY.into();
+    let res: NonNaNFinite = Clamp::clamp(x, min, max);
+    assert_eq!(res, 0.0);
+}
+
+#[test]
+#[should_panic(expected = "min > max")]
+fn test_clamp_min_greater_than_max() {
+    let min: StrictlyPositiveFinite = 2.0.try_into().unwrap();
+    let max: StrictlyPositiveFinite = 1.0.try_into().unwrap();
+
+    let _ = Clamp::clamp(tf64::ZERO, min, max);
+}
@@ -0,0 +1,42 @@
+use typed_floats::*;
+
+typed_floats_macros::generate_tests_values!(
+    clamp_to_finite,
+    Type in [
+        NonNaN,
+        NonZeroNonNaN,
+        Positive,
+        Negative,
+        StrictlyPositive,
+        StrictlyNegative
+    ],
+    {
+        for &value in &values {
+            let Ok(x) = Type::new(value) else {
+                continue;
+            };
+
+            // This will panic if the result isn't compatible with the return type
+            let clamped = x.clamp_to_finite();
+
+            if value == Float::INFINITY {
+                assert_eq!(clamped, Float::MAX);
+            } else if value == Float::NEG_INFINITY {
+                assert_eq!(clamped, Float::MIN);
+            } else {
+                assert_eq!(clamped.get().to_bits(), value.to_bits());
+            }
+        }
+    }
+);
+
+#[test]
+fn test_clamp_to_finite_constants() {
+    let max: tf64::StrictlyPositiveFinite = tf64::INFINITY.clamp_to_finite();
+    assert_eq!(max, f64::MAX);
+
+    let min: tf64::StrictlyNegativeFinite = tf64::NEG_INFINITY.clamp_to_finite();
+    assert_eq!(min, f64::MIN);
+
+    assert_eq!(tf32::INFINITY.clamp_to_finite(), f32::MAX);
+}
@@ -0,0 +1,95 @@
+use typed_floats::*;
+
+typed_floats_macros::generate_tests_values!(
+    clamping_from,
+    Type in [
+        Positive,
+        PositiveFinite,
+        StrictlyPositive,
+        StrictlyPositiveFinite,
+        Negative,
+        NegativeFinite,
+        StrictlyNegative,
+        StrictlyNegativeFinite
+    ],
+    {
+        for &value in &values {
+            let clamped = Type::clamping_from(value);
+
+            // The valid values are kept
+            if let Ok(x) = Type::new(value) {
+                assert_eq!(clamped.get().to_bits(), x.get().to_bits());
+            }
+        }
+    }
+);
+
+#[test]
+fn test_clamping_from_positive() {
+    assert_is_positive_zero!(tf64::Positive::clamping_from(f64::NAN));
+    assert_is_positive_zero!(tf64::Positive::clamping_from(f64::NEG_INFINITY));
+    assert_is_positive_zero!(tf64::Positive::clamping_from(-0.0));
+    assert_is_positive_zero!(tf64::Positive::clamping_from(0.0));
+    assert_eq!(tf64::Positive::clamping_from(f64::INFINITY), f64::INFINITY);
+
+    assert_is_positive_zero!(tf32::PositiveFinite::clamping_from(f32::NAN));
+    assert_eq!(tf32::PositiveFinite::clamping_from(f32::INFINITY), f32::MAX);
+
+    assert_eq!(
+        tf64::StrictlyPositive::clamping_from(f64::NAN),
+        f64::MIN_POSITIVE
+    );
+    assert_eq!(
+        tf64::StrictlyPositive::clamping_from(f64::NEG_INFINITY),
+        f64::MIN_POSITIVE
+    );
+    assert_eq!(
+        tf64::StrictlyPositive::clamping_from(0.0),
+        f64::MIN_POSITIVE
+    );
+    assert_eq!(
+        tf64::StrictlyPositive::clamping_from(-0.0),
+        f64::MIN_POSITIVE
+    );
+
+    assert_eq!(
+        tf32::StrictlyPositiveFinite::clamping_from(f32::INFINITY),
+        f32::MAX
+    );
+}
+
+#[test]
+fn test_clamping_from_negative() {
+    assert_is_negative_zero!(tf64::Negative::clamping_from(f64::NAN));
+    assert_is_negative_zero!(tf64::Negative::clamping_from(f64::INFINITY));
+    assert_is_negative_zero!(tf64::Negative::clamping_from(0.0));
+    assert_is_negative_zero!(tf64::Negative::clamping_from(-0.0));
+    assert_eq!(
+        tf64::Negative::clamping_from(f64::NEG_INFINITY),
+        f64::NEG_INFINITY
+    );
+
+    assert_is_negative_zero!(tf32::NegativeFinite::clamping_from(f32::NAN));
+    assert_eq!(
+        tf32::NegativeFinite::clamping_from(f32::NEG_INFINITY),
+        f32::MIN
+    );
+
+    assert_eq!(
+        tf64::StrictlyNegative::clamping_from(f64::NAN),
+        -f64::MIN_POSITIVE
+    );
+    assert_eq!(
+        tf64::StrictlyNegative::clamping_from(0.0),
+        -f64::MIN_POSITIVE
+    );
+    assert_eq!(
+        tf64::StrictlyNegative::clamping_from(f64::NEG_INFINITY),
+        f64::NEG_INFINITY
+    );
+
+    assert_eq!(
+        tf32::StrictlyNegativeFinite::clamping_from(f32::NEG_INFINITY),
+        f32::MIN
+    );
+}
@@ -0,0 +1,33 @@
+use core::num::FpCategory;
+use typed_floats::*;
+
+macro_rules! test_subnormal {
+    ($test:ident, $tf:ident) => {
+        #[test]
+        fn $test() {
+            let subnormal: $tf::PositiveFinite = $tf::MIN_POSITIVE.next_down();
+            let neg_subnormal: $tf::NegativeFinite = (-$tf::MIN_POSITIVE).next_up();
+
+            for (value, expected) in [
+                (subnormal.get(), FpCategory::Subnormal),
+                (neg_subnormal.get(), FpCategory::Subnormal),
+                ($tf::MIN_POSITIVE.get(), FpCategory::Normal),
+                ($tf::ZERO.get(), FpCategory::Zero),
+                ($tf::INFINITY.get(), FpCategory::Infinite),
+            ] {
+                let x: $tf::NonNaN = value.try_into().unwrap();
+
+                assert_eq!(x.classify(), expected);
+                assert_eq!(x.is_subnormal(), expected == FpCategory::Subnormal);
+                assert_eq!(x.is_normal(), expected == FpCategory::Normal);
+            }
+
+            assert!(subnormal.is_subnormal());
+            assert!(!subnormal.is_normal());
+            assert_eq!(subnormal.classify(), FpCategory::Subnormal);
+        }
+    };
+}
+
+test_subnormal!(test_f32_subnormal, tf32);
+test_subnormal!(test_f64_subnormal, tf64);
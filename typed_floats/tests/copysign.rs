@@ -3,3 +3,20 @@
 use typed_floats::*;
 
 typed_floats_macros::generate_tests_self_rhs!(copysign);
+
+#[test]
+fn test_copysign_keeps_magnitude() {
+    let magnitude: StrictlyPositiveFinite = 2.0.try_into().unwrap();
+    let sign: StrictlyNegative = (-0.5).try_into().unwrap();
+
+    let res: StrictlyNegativeFinite = magnitude.copysign(sign);
+    assert_eq!(res, -2.0);
+
+    let zero: PositiveFinite = 0.0.try_into().unwrap();
+    let res: NegativeFinite = zero.copysign(sign);
+    assert!(res.is_negative_zero());
+
+    let neg_zero: NegativeFinite = (-0.0).try_into().unwrap();
+    let res: PositiveFinite = neg_zero.copysign(magnitude);
+    assert!(res.is_positive_zero());
+}
@@ -0,0 +1,55 @@
+#![cfg(typed_floats_debug_checks)]
+#![cfg(debug_assertions)]
+
+use typed_floats::*;
+
+#[test]
+#[should_panic(expected = "`NonNaN<f64>::add(NonNaN<f64>)` returned `NaN`")]
+fn test_debug_checks_add() {
+    let a: NonNaN = f64::INFINITY.try_into().unwrap();
+    let b: NonNaN = f64::NEG_INFINITY.try_into().unwrap();
+
+    let _ = a + b;
+}
+
+#[test]
+#[should_panic(expected = "`NonNaNFinite<f32>::div(NonNaNFinite<f32>)` returned `NaN`")]
+fn test_debug_checks_div() {
+    let zero: NonNaNFinite<f32> = 0.0.try_into().unwrap();
+
+    let _ = zero / zero;
+}
+
+#[test]
+#[should_panic(expected = "`Negative<f64>::sqrt()` returned `NaN`")]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn test_debug_checks_sqrt() {
+    let x: Negative = (-1.0).try_into().unwrap();
+
+    let _ = x.sqrt();
+}
+
+#[test]
+#[should_panic(expected = "`StrictlyNegative<f64>::lerp(NonNaN<f64>)` returned `NaN`")]
+fn test_debug_checks_lerp() {
+    let half: UnitInterval = 0.5.try_into().unwrap();
+
+    let _ = tf64::NEG_INFINITY.lerp(NonNaN::from(tf64::INFINITY), half);
+}
+
+#[test]
+fn test_debug_checks_not_nan() {
+    let a: NonNaN = 1.0.try_into().unwrap();
+    let b: NonNaN = f64::NEG_INFINITY.try_into().unwrap();
+
+    // The result type is `f64`, but the result itself is valid
+    let c: f64 = a + b;
+    assert_eq!(c, f64::NEG_INFINITY);
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn test_debug_checks_sqrt_not_nan() {
+    let x: Negative = (-0.0).try_into().unwrap();
+    assert_is_negative_zero!(x.sqrt());
+}
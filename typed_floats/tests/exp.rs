@@ -3,3 +3,15 @@
 use typed_floats::*;
 
 typed_floats_macros::generate_tests_self!(exp);
+
+#[test]
+fn test_exp_underflow() {
+    let a: tf64::StrictlyNegativeFinite = (-1100.0).try_into().unwrap();
+    let b: tf32::StrictlyNegativeFinite = (-200.0).try_into().unwrap();
+
+    let a_exp: tf64::PositiveFinite = a.exp();
+    let b_exp: tf32::PositiveFinite = b.exp();
+
+    assert!(a_exp.is_positive_zero());
+    assert!(b_exp.is_positive_zero());
+}
@@ -3,3 +3,15 @@
 use typed_floats::*;
 
 typed_floats_macros::generate_tests_self!(exp2);
+
+#[test]
+fn test_exp2_underflow() {
+    let a: tf64::StrictlyNegativeFinite = (-1100.0).try_into().unwrap();
+    let b: tf32::StrictlyNegativeFinite = (-200.0).try_into().unwrap();
+
+    let a_exp2: tf64::PositiveFinite = a.exp2();
+    let b_exp2: tf32::PositiveFinite = b.exp2();
+
+    assert!(a_exp2.is_positive_zero());
+    assert!(b_exp2.is_positive_zero());
+}
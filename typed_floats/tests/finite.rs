@@ -0,0 +1,42 @@
+use typed_floats::*;
+
+#[test]
+fn test_finite_strictly_positive() {
+    let x: tf64::StrictlyPositive = 3.0.try_into().unwrap();
+    let finite: tf64::StrictlyPositiveFinite = x.finite().unwrap();
+    assert_eq!(finite, 3.0);
+
+    let max: tf64::StrictlyPositive = f64::MAX.try_into().unwrap();
+    assert_eq!(max.finite(), Ok(tf64::MAX));
+
+    assert_eq!(tf64::INFINITY.finite(), Err(InvalidNumber::Infinite));
+    assert_eq!(tf32::INFINITY.finite(), Err(InvalidNumber::Infinite));
+}
+
+typed_floats_macros::generate_tests_values!(
+    finite,
+    Type in [
+        NonNaN,
+        NonZeroNonNaN,
+        Positive,
+        Negative,
+        StrictlyPositive,
+        StrictlyNegative
+    ],
+    {
+        for &value in &values {
+            let Ok(x) = Type::new(value) else {
+                continue;
+            };
+
+            // This will panic if the result isn't compatible with the return type
+            match x.finite() {
+                Ok(finite) => assert_eq!(finite.get().to_bits(), value.to_bits()),
+                Err(err) => {
+                    assert!(value.is_infinite());
+                    assert_eq!(err, InvalidNumber::Infinite);
+                }
+            }
+        }
+    }
+);
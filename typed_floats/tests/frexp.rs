@@ -0,0 +1,32 @@
+#![cfg(feature = "libm")]
+
+use typed_floats::*;
+
+typed_floats_macros::generate_tests_values!(frexp, Type, {
+    for &value in &values {
+        let Ok(x) = Type::new(value) else {
+            continue;
+        };
+
+        let (mantissa, exp): (Type, i32) = x.frexp();
+
+        if value.is_finite() && value != 0.0 {
+            assert!((0.5..1.0).contains(&mantissa.get().abs()), "{value}");
+        } else {
+            assert_eq!(mantissa.get().to_bits(), value.to_bits());
+            assert_eq!(exp, 0);
+        }
+
+        assert_eq!(mantissa.ldexp(exp).get().to_bits(), value.to_bits());
+    }
+});
+
+#[test]
+fn test_frexp_mantissa_type() {
+    let x: tf64::StrictlyNegativeFinite = (-12.0).try_into().unwrap();
+
+    let (mantissa, exp): (tf64::StrictlyNegativeFinite, i32) = x.frexp();
+
+    assert_eq!(mantissa, -0.75);
+    assert_eq!(exp, 4);
+}
@@ -0,0 +1,248 @@
+use core::any::TypeId;
+use core::convert::Infallible;
+
+use typed_floats::*;
+
+/// `true` if the conversion can't fail, i.e. if `From` is implemented
+fn is_infallible<From, To: TryFrom<From>>() -> bool
+where
+    To::Error: 'static,
+{
+    TypeId::of::<To::Error>() == TypeId::of::<Infallible>()
+}
+
+macro_rules! test_widening {
+    ($test:ident, $from:ident => $($to:ident),*) => {
+        #[test]
+        fn $test() {
+            let values: [f64; 23] = typed_floats_macros::test_values!(f64);
+
+            for value in values.iter().filter_map(|&x| $from::<f64>::new(x).ok()) {
+                $(
+                    let to: $to<f64> = value.into();
+                    assert_eq!(to, value);
+                )*
+            }
+
+            $(
+                assert!(is_infallible::<$from<f64>, $to<f64>>());
+                assert!(is_infallible::<$from<f32>, $to<f32>>());
+            )*
+        }
+    };
+}
+
+macro_rules! test_narrowing {
+    ($test:ident, $from:ident => $($to:ident),*) => {
+        #[test]
+        fn $test() {
+            $(
+                assert!(!is_infallible::<$from<f64>, $to<f64>>());
+                assert!(!is_infallible::<$from<f32>, $to<f32>>());
+            )*
+        }
+    };
+}
+
+test_widening!(test_widening_non_nan_finite, NonNaNFinite => NonNaN);
+test_widening!(test_widening_non_zero_non_nan, NonZeroNonNaN => NonNaN);
+test_widening!(
+    test_widening_non_zero_non_nan_finite,
+    NonZeroNonNaNFinite => NonNaN, NonNaNFinite, NonZeroNonNaN
+);
+test_widening!(test_widening_positive, Positive => NonNaN);
+test_widening!(test_widening_positive_finite, PositiveFinite => NonNaN, NonNaNFinite, Positive);
+test_widening!(
+    test_widening_strictly_positive,
+    StrictlyPositive => NonNaN, NonZeroNonNaN, Positive
+);
+test_widening!(
+    test_widening_strictly_positive_finite,
+    StrictlyPositiveFinite => NonNaN,
+    NonNaNFinite,
+    NonZeroNonNaN,
+    NonZeroNonNaNFinite,
+    Positive,
+    PositiveFinite,
+    StrictlyPositive
+);
+test_widening!(test_widening_negative, Negative => NonNaN);
+test_widening!(test_widening_negative_finite, NegativeFinite => NonNaN, NonNaNFinite, Negative);
+test_widening!(
+    test_widening_strictly_negative,
+    StrictlyNegative => NonNaN, NonZeroNonNaN, Negative
+);
+test_widening!(
+    test_widening_strictly_negative_finite,
+    StrictlyNegativeFinite => NonNaN,
+    NonNaNFinite,
+    NonZeroNonNaN,
+    NonZeroNonNaNFinite,
+    Negative,
+    NegativeFinite,
+    StrictlyNegative
+);
+
+test_narrowing!(
+    test_narrowing_non_nan,
+    NonNaN => NonNaNFinite,
+    NonZeroNonNaN,
+    NonZeroNonNaNFinite,
+    Positive,
+    PositiveFinite,
+    StrictlyPositive,
+    StrictlyPositiveFinite,
+    Negative,
+    NegativeFinite,
+    StrictlyNegative,
+    StrictlyNegativeFinite
+);
+test_narrowing!(
+    test_narrowing_positive,
+    Positive => NonNaNFinite, NonZeroNonNaN, PositiveFinite, StrictlyPositive, Negative
+);
+test_narrowing!(
+    test_narrowing_strictly_positive,
+    StrictlyPositive => NonNaNFinite, PositiveFinite, StrictlyPositiveFinite
+);
+test_narrowing!(
+    test_narrowing_positive_finite,
+    PositiveFinite => NonZeroNonNaN, StrictlyPositiveFinite, NegativeFinite
+);
+test_narrowing!(
+    test_narrowing_negative,
+    Negative => NonNaNFinite, NonZeroNonNaN, NegativeFinite, StrictlyNegative, Positive
+);
+test_narrowing!(
+    test_narrowing_strictly_negative,
+    StrictlyNegative => NonNaNFinite, NegativeFinite, StrictlyNegativeFinite
+);
+test_narrowing!(
+    test_narrowing_negative_finite,
+    NegativeFinite => NonZeroNonNaN, StrictlyNegativeFinite, PositiveFinite
+);
+
+macro_rules! test_f32_to_f64 {
+    ($test:ident, $type:ident) => {
+        #[test]
+        #[allow(clippy::cast_possible_truncation)] // The truncation is what is tested
+        fn $test() {
+            let values: [f32; 23] = typed_floats_macros::test_values!(f32);
+
+            for value in values.iter().filter_map(|&x| $type::<f32>::new(x).ok()) {
+                let wide: $type<f64> = value.into();
+                assert_eq!(wide.get().to_bits(), f64::from(value.get()).to_bits());
+
+                let narrow: Result<$type<f32>, InvalidNumber> = wide.try_into();
+                assert_eq!(narrow.map(|x| x.get().to_bits()), Ok(value.get().to_bits()));
+            }
+
+            let values: [f64; 23] = typed_floats_macros::test_values!(f64);
+
+            for value in values.iter().filter_map(|&x| $type::<f64>::new(x).ok()) {
+                let narrow: Result<$type<f32>, InvalidNumber> = value.try_into();
+                assert_eq!(narrow, $type::<f32>::new(value.get() as f32));
+            }
+        }
+    };
+}
+
+test_f32_to_f64!(test_f32_to_f64_non_nan, NonNaN);
+test_f32_to_f64!(test_f32_to_f64_non_nan_finite, NonNaNFinite);
+test_f32_to_f64!(test_f32_to_f64_non_zero_non_nan, NonZeroNonNaN);
+test_f32_to_f64!(test_f32_to_f64_non_zero_non_nan_finite, NonZeroNonNaNFinite);
+test_f32_to_f64!(test_f32_to_f64_positive, Positive);
+test_f32_to_f64!(test_f32_to_f64_positive_finite, PositiveFinite);
+test_f32_to_f64!(test_f32_to_f64_strictly_positive, StrictlyPositive);
+test_f32_to_f64!(
+    test_f32_to_f64_strictly_positive_finite,
+    StrictlyPositiveFinite
+);
+test_f32_to_f64!(test_f32_to_f64_negative, Negative);
+test_f32_to_f64!(test_f32_to_f64_negative_finite, NegativeFinite);
+test_f32_to_f64!(test_f32_to_f64_strictly_negative, StrictlyNegative);
+test_f32_to_f64!(
+    test_f32_to_f64_strictly_negative_finite,
+    StrictlyNegativeFinite
+);
+
+#[test]
+fn test_f64_to_f32_rejected() {
+    let max: Result<tf32::StrictlyPositiveFinite, InvalidNumber> = tf64::MAX.try_into();
+    let min: Result<tf32::NonNaNFinite, InvalidNumber> =
+        tf64::NonNaNFinite::from(tf64::MIN).try_into();
+
+    assert_eq!(max, Err(InvalidNumber::Infinite));
+    assert_eq!(min, Err(InvalidNumber::Infinite));
+
+    let tiny: tf64::StrictlyPositiveFinite = 1.0e-300.try_into().unwrap();
+    let tiny: Result<tf32::StrictlyPositiveFinite, InvalidNumber> = tiny.try_into();
+
+    assert_eq!(tiny, Err(InvalidNumber::Zero));
+
+    // The types accepting infinity or zero keep the value, rounded
+    let max: Result<tf32::StrictlyPositive, InvalidNumber> =
+        tf64::StrictlyPositive::from(tf64::MAX).try_into();
+    let zero: Result<tf32::NegativeFinite, InvalidNumber> =
+        tf64::NegativeFinite::try_from(-1.0e-300)
+            .unwrap()
+            .try_into();
+
+    assert_eq!(max, Ok(tf32::INFINITY));
+    assert!(zero.is_ok_and(|x| x.is_negative_zero()));
+}
+
+#[test]
+fn test_try_from_f64() {
+    let max = tf32::StrictlyPositiveFinite::try_from_f64(f64::from(f32::MAX)).unwrap();
+    assert_eq!(max, f32::MAX);
+
+    // Rounded down to `f32::MAX`
+    let below_half_ulp = f64::from(f32::MAX) + 2f64.powi(102);
+    let max = tf32::StrictlyPositiveFinite::try_from_f64(below_half_ulp).unwrap();
+    assert_eq!(max, f32::MAX);
+
+    // Rounded up to infinity
+    let above_half_ulp = f64::from(f32::MAX) + 2f64.powi(104);
+    assert_eq!(
+        tf32::StrictlyPositiveFinite::try_from_f64(above_half_ulp),
+        Err(InvalidNumber::Infinite)
+    );
+    assert_eq!(
+        tf32::StrictlyPositive::try_from_f64(above_half_ulp).unwrap(),
+        f32::INFINITY
+    );
+    assert_eq!(
+        tf32::NegativeFinite::try_from_f64(-above_half_ulp),
+        Err(InvalidNumber::Infinite)
+    );
+}
+
+#[test]
+fn test_try_from_f64_underflow() {
+    let min_subnormal = f64::from(f32::from_bits(1));
+
+    let x = tf32::StrictlyPositive::try_from_f64(min_subnormal).unwrap();
+    assert_eq!(x.get().to_bits(), 1);
+
+    // Rounded to zero
+    assert_eq!(
+        tf32::StrictlyPositive::try_from_f64(min_subnormal / 4.0),
+        Err(InvalidNumber::Zero)
+    );
+    assert_eq!(
+        tf32::StrictlyNegativeFinite::try_from_f64(-1e-300),
+        Err(InvalidNumber::Zero)
+    );
+
+    let x = tf32::Positive::try_from_f64(1e-300).unwrap();
+    assert_is_positive_zero!(x);
+
+    let x = tf32::Negative::try_from_f64(-1e-300).unwrap();
+    assert_is_negative_zero!(x);
+
+    assert_eq!(
+        tf32::NonNaN::try_from_f64(f64::NAN),
+        Err(InvalidNumber::NaN)
+    );
+}
@@ -0,0 +1,23 @@
+#![cfg(feature = "libm")]
+
+use typed_floats::*;
+
+typed_floats_macros::generate_tests_self!(gamma);
+
+#[test]
+fn test_gamma() {
+    let x: tf64::StrictlyPositiveFinite = 5.0.try_into().unwrap();
+
+    let y: tf64::StrictlyPositive = x.gamma();
+    assert!((y.get() - 24.0).abs() < 1e-10);
+
+    let x: tf32::PositiveFinite = 5.0.try_into().unwrap();
+    assert!((x.gamma().get() - 24.0).abs() < 1e-4);
+
+    // The result type accepts infinity
+    let overflow: tf64::StrictlyPositive = tf64::MAX.gamma();
+    assert_eq!(overflow, f64::INFINITY);
+
+    let pole: tf64::StrictlyPositive = tf64::ZERO.gamma();
+    assert_eq!(pole, f64::INFINITY);
+}
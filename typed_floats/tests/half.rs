@@ -0,0 +1,142 @@
+#![cfg(feature = "half")]
+
+use half::{bf16, f16};
+use typed_floats::*;
+
+const TEST_VALUES_F16: [f16; 23] = typed_floats_macros::test_values!(f16);
+const TEST_VALUES_BF16: [bf16; 23] = typed_floats_macros::test_values!(bf16);
+
+macro_rules! test_half {
+    ($test:ident, $float:ident, $values:ident, $type:ident) => {
+        #[test]
+        fn $test() {
+            for &value in &$values {
+                let res = $type::<$float>::new(value);
+                let expected = $type::<f32>::new(value.to_f32());
+
+                assert_eq!(res.as_ref().err(), expected.as_ref().err());
+                assert_eq!($type::<$float>::try_new(value), res.as_ref().ok().copied());
+
+                if let (Ok(typed), Ok(expected)) = (res, expected) {
+                    assert_eq!(typed, value);
+                    assert_eq!(typed.get().to_bits(), value.to_bits());
+                    assert_eq!($type::<f32>::from(typed), expected);
+                    assert_eq!($type::<f64>::from(typed), f64::from(expected.get()));
+                    assert_eq!(typed.is_positive_zero(), expected.is_positive_zero());
+                    assert_eq!(typed.is_negative_zero(), expected.is_negative_zero());
+                }
+            }
+        }
+    };
+}
+
+macro_rules! test_halfs {
+    ($f16_test:ident, $bf16_test:ident, $type:ident) => {
+        test_half!($f16_test, f16, TEST_VALUES_F16, $type);
+        test_half!($bf16_test, bf16, TEST_VALUES_BF16, $type);
+    };
+}
+
+test_halfs!(test_f16_non_nan, test_bf16_non_nan, NonNaN);
+test_halfs!(
+    test_f16_non_nan_finite,
+    test_bf16_non_nan_finite,
+    NonNaNFinite
+);
+test_halfs!(
+    test_f16_non_zero_non_nan,
+    test_bf16_non_zero_non_nan,
+    NonZeroNonNaN
+);
+test_halfs!(
+    test_f16_non_zero_non_nan_finite,
+    test_bf16_non_zero_non_nan_finite,
+    NonZeroNonNaNFinite
+);
+test_halfs!(test_f16_positive, test_bf16_positive, Positive);
+test_halfs!(
+    test_f16_positive_finite,
+    test_bf16_positive_finite,
+    PositiveFinite
+);
+test_halfs!(
+    test_f16_strictly_positive,
+    test_bf16_strictly_positive,
+    StrictlyPositive
+);
+test_halfs!(
+    test_f16_strictly_positive_finite,
+    test_bf16_strictly_positive_finite,
+    StrictlyPositiveFinite
+);
+test_halfs!(test_f16_negative, test_bf16_negative, Negative);
+test_halfs!(
+    test_f16_negative_finite,
+    test_bf16_negative_finite,
+    NegativeFinite
+);
+test_halfs!(
+    test_f16_strictly_negative,
+    test_bf16_strictly_negative,
+    StrictlyNegative
+);
+test_halfs!(
+    test_f16_strictly_negative_finite,
+    test_bf16_strictly_negative_finite,
+    StrictlyNegativeFinite
+);
+
+#[test]
+fn test_half_values_are_sorted() {
+    for values in [
+        TEST_VALUES_F16.map(f16::to_f32),
+        TEST_VALUES_BF16.map(bf16::to_f32),
+    ] {
+        let non_nan = values.iter().filter(|x| !x.is_nan());
+
+        assert!(non_nan
+            .clone()
+            .zip(non_nan.skip(1))
+            .all(|(a, b)| a < b || (*a == 0.0 && *b == 0.0)));
+    }
+}
+
+#[test]
+fn test_half_consts() {
+    const X: StrictlyPositiveFinite<f16> = match StrictlyPositiveFinite::<f16>::try_new(f16::ONE) {
+        Some(x) => x,
+        None => panic!(),
+    };
+
+    assert_eq!(X, tf16::ONE);
+    assert_eq!(tf16::MAX, f16::MAX);
+    assert_eq!(tbf16::MIN, bf16::MIN);
+    assert!(tf16::NEG_ZERO.is_negative_zero());
+    assert!(tbf16::ZERO.is_positive_zero());
+    assert_eq!(tf16::ZERO.get(), tf16::NEG_ZERO.get());
+
+    let a: tf32::StrictlyPositive = tf16::INFINITY.into();
+
+    assert_eq!(a, f32::INFINITY);
+
+    let b: tf64::StrictlyNegativeFinite = tbf16::NEG_ONE.into();
+
+    assert_eq!(b, -1.0);
+}
+
+#[test]
+fn test_half_ord() {
+    let mut values: Vec<tf16::NonNaN> = TEST_VALUES_F16
+        .iter()
+        .rev()
+        .filter_map(|&x| tf16::NonNaN::new(x).ok())
+        .collect();
+
+    values.sort();
+
+    assert_eq!(
+        values.first().map(tf16::NonNaN::get),
+        Some(f16::NEG_INFINITY)
+    );
+    assert_eq!(values.last().map(tf16::NonNaN::get), Some(f16::INFINITY));
+}
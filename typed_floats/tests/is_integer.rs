@@ -0,0 +1,68 @@
+use typed_floats::*;
+
+#[test]
+fn test_is_integer() {
+    let a: tf64::PositiveFinite = 2.0.try_into().unwrap();
+    let b: tf64::PositiveFinite = 2.5.try_into().unwrap();
+    let c: tf64::Positive = f64::INFINITY.try_into().unwrap();
+
+    assert!(a.is_integer());
+    assert!(!b.is_integer());
+    assert!(!c.is_integer());
+
+    assert_eq!(a.as_exact_int::<u8>(), Some(2));
+    assert_eq!(b.as_exact_int::<u8>(), None);
+    assert_eq!(c.as_exact_int::<u128>(), None);
+
+    let d: tf32::StrictlyNegative = (-2.0).try_into().unwrap();
+    let e: tf32::StrictlyNegative = f32::NEG_INFINITY.try_into().unwrap();
+
+    assert!(d.is_integer());
+    assert!(!e.is_integer());
+    assert_eq!(d.as_exact_int::<i64>(), Some(-2));
+    assert_eq!(d.as_exact_int::<u64>(), None);
+}
+
+#[test]
+fn test_is_integer_all_values() {
+    let values: [f64; 23] = typed_floats_macros::test_values!(f64);
+
+    for &value in &values {
+        let x = tf64::NonNaN::new(value).unwrap_or(tf64::ZERO.into());
+
+        assert_eq!(
+            x.is_integer(),
+            x.is_finite() && x.get().fract() == 0.0,
+            "{value}"
+        );
+    }
+
+    let values: [f32; 23] = typed_floats_macros::test_values!(f32);
+
+    for &value in &values {
+        let x = tf32::NonNaN::new(value).unwrap_or(tf32::ZERO.into());
+
+        assert_eq!(
+            x.is_integer(),
+            x.is_finite() && x.get().fract() == 0.0,
+            "{value}"
+        );
+    }
+}
+
+#[test]
+fn test_is_integer_large() {
+    let x: tf64::StrictlyPositiveFinite = 4_503_599_627_370_496.5.try_into().unwrap();
+    assert!(x.is_integer());
+
+    let x: tf64::StrictlyPositiveFinite = 2_251_799_813_685_247.5.try_into().unwrap();
+    assert!(!x.is_integer());
+
+    let x: tf32::NonNaNFinite = (-8_388_607.5).try_into().unwrap();
+    assert!(!x.is_integer());
+
+    let x: tf32::NonNaNFinite = f32::MAX.try_into().unwrap();
+    assert!(x.is_integer());
+    assert_eq!(x.as_exact_int::<i128>(), None);
+    assert_eq!(x.as_exact_int::<u128>(), Some(f32::MAX as u128));
+}
@@ -0,0 +1,76 @@
+#![cfg(any(feature = "std", feature = "libm"))]
+
+use typed_floats::*;
+
+#[test]
+fn test_l2_norm_empty() {
+    let norm: tf64::Positive = tf64::NonNaNFinite::l2_norm(&[]);
+    assert_is_positive_zero!(norm);
+
+    let norm: tf32::Positive = tf32::NonNaNFinite::l2_norm(&[]);
+    assert_is_positive_zero!(norm);
+}
+
+#[test]
+fn test_l2_norm_3_4_5() {
+    let values = tf64::NonNaNFinite::try_from_slice(&[3.0, -4.0]).unwrap();
+    assert_eq!(tf64::NonNaNFinite::l2_norm(values), 5.0);
+
+    let values = tf32::NonNaNFinite::try_from_slice(&[-3.0, 4.0]).unwrap();
+    assert_eq!(tf32::NonNaNFinite::l2_norm(values), 5.0);
+
+    let values = tf64::StrictlyNegativeFinite::try_from_slice(&[-3.0, -4.0, -12.0]).unwrap();
+    assert_eq!(tf64::StrictlyNegativeFinite::l2_norm(values), 13.0);
+}
+
+#[test]
+fn test_l2_norm_zeros() {
+    let values = tf64::NonNaNFinite::try_from_slice(&[0.0, -0.0]).unwrap();
+    assert_is_positive_zero!(tf64::NonNaNFinite::l2_norm(values));
+}
+
+#[test]
+fn test_l2_norm_no_intermediate_overflow() {
+    // The sum of the squares would overflow, but not the norm
+    let values = tf64::PositiveFinite::try_from_slice(&[3e200, 4e200]).unwrap();
+    let norm = tf64::PositiveFinite::l2_norm(values).get();
+    assert!((norm - 5e200).abs() <= 5e200 * f64::EPSILON);
+
+    // The squares would be rounded to zero, but not the norm
+    let values = tf64::PositiveFinite::try_from_slice(&[3e-200, 4e-200]).unwrap();
+    let norm = tf64::PositiveFinite::l2_norm(values).get();
+    assert!((norm - 5e-200).abs() <= 5e-200 * f64::EPSILON);
+}
+
+#[test]
+fn test_l2_norm_overflow() {
+    let values = tf64::NonNaNFinite::try_from_slice(&[f64::MAX, f64::MIN]).unwrap();
+    assert_eq!(tf64::NonNaNFinite::l2_norm(values), f64::INFINITY);
+
+    let values = tf32::NonNaNFinite::try_from_slice(&[f32::MAX]).unwrap();
+    assert_eq!(tf32::NonNaNFinite::l2_norm(values), f32::MAX);
+}
+
+#[test]
+fn test_l2_norm_all_values() {
+    let values: [f64; 23] = typed_floats_macros::test_values!(f64);
+
+    let finite = values
+        .iter()
+        .copied()
+        .filter(|x| x.is_finite())
+        .collect::<Vec<_>>();
+
+    for &a in &finite {
+        for &b in &finite {
+            let slice = tf64::NonNaNFinite::try_from_slice(&[a, b])
+                .unwrap()
+                .to_vec();
+
+            // This will panic if the result isn't compatible with the return type
+            let norm = tf64::NonNaNFinite::l2_norm(&slice);
+
+            assert!(norm >= a.abs() && norm >= b.abs());
+        }
+    }
+}
@@ -0,0 +1,21 @@
+#![cfg(feature = "libm")]
+
+use typed_floats::*;
+
+typed_floats_macros::generate_tests_self!(ldexp);
+
+#[test]
+fn test_ldexp_overflow_underflow() {
+    let x: tf64::StrictlyNegativeFinite = (-3.0).try_into().unwrap();
+
+    let big: tf64::Negative = x.ldexp(2000);
+    let small: tf64::Negative = x.ldexp(-2000);
+
+    assert_eq!(big, f64::NEG_INFINITY);
+    assert_is_negative_zero!(small);
+
+    let y: tf32::StrictlyPositiveFinite = 3.0.try_into().unwrap();
+
+    assert_eq!(y.ldexp(200), f32::INFINITY);
+    assert_is_positive_zero!(y.ldexp(-200));
+}
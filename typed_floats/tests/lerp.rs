@@ -0,0 +1,120 @@
+use typed_floats::*;
+
+#[test]
+fn test_lerp_positive() {
+    let a: tf64::StrictlyPositiveFinite = 1.0.try_into().unwrap();
+    let b: tf64::StrictlyPositiveFinite = 3.0.try_into().unwrap();
+    let half: UnitInterval = 0.5.try_into().unwrap();
+
+    let c: tf64::StrictlyPositiveFinite = a.lerp(b, half);
+    assert_eq!(c, 2.0);
+
+    let a: tf64::PositiveFinite = 0.0.try_into().unwrap();
+    let b: tf64::PositiveFinite = tf64::MAX.into();
+
+    let c: tf64::PositiveFinite = a.lerp(b, half);
+    assert_eq!(c, f64::MAX / 2.0);
+}
+
+#[test]
+fn test_lerp_opposite_signs() {
+    let half: UnitInterval = 0.5.try_into().unwrap();
+
+    let c: tf64::NonNaNFinite = tf64::MIN.lerp(tf64::MAX, half);
+    assert_eq!(c, 0.0);
+
+    let a: tf64::StrictlyNegativeFinite = (-1.0).try_into().unwrap();
+    let b: tf64::StrictlyPositiveFinite = 3.0.try_into().unwrap();
+
+    let c: tf64::NonNaNFinite = a.lerp(b, half);
+    assert_eq!(c, 1.0);
+
+    let c: f64 = tf64::NEG_INFINITY.lerp(tf64::INFINITY, half);
+    assert_is_nan!(c);
+}
+
+#[test]
+fn test_lerp_zeros() {
+    let half: UnitInterval = 0.5.try_into().unwrap();
+
+    let c: tf64::NegativeFinite = tf64::NEG_ZERO.lerp(tf64::NEG_ZERO, half);
+    assert_is_negative_zero!(c);
+
+    let c: tf64::PositiveFinite = tf64::ZERO.lerp(tf64::ZERO, half);
+    assert_is_positive_zero!(c);
+}
+
+macro_rules! test_lerp_endpoints {
+    ($name:ident, $a:ident, $b:ident) => {
+        #[test]
+        fn $name() {
+            let values: [f64; 23] = typed_floats_macros::test_values!(f64);
+            let weights = [
+                0.0,
+                1e-300,
+                0.1,
+                0.25,
+                0.5,
+                0.75,
+                0.9,
+                1.0 - f64::EPSILON,
+                1.0,
+            ];
+
+            for &a in &values {
+                let Ok(a) = typed_floats::$a::<f64>::new(a) else {
+                    continue;
+                };
+
+                for &b in &values {
+                    let Ok(b) = typed_floats::$b::<f64>::new(b) else {
+                        continue;
+                    };
+
+                    assert_eq!(
+                        a.lerp(b, UnitInterval::<f64>::ZERO).get().to_bits(),
+                        a.get().to_bits()
+                    );
+                    assert_eq!(
+                        a.lerp(b, UnitInterval::<f64>::ONE).get().to_bits(),
+                        b.get().to_bits()
+                    );
+
+                    let (min, max) = if a < b {
+                        (a.get(), b.get())
+                    } else {
+                        (b.get(), a.get())
+                    };
+
+                    for &t in &weights {
+                        let t = UnitInterval::<f64>::new(t).unwrap();
+
+                        // This will panic if the result isn't compatible with the return type
+                        let c = a.lerp(b, t).get();
+
+                        assert!(min <= c && c <= max, "{a:?}.lerp({b:?}, {t:?}) = {c:?}");
+                    }
+                }
+            }
+        }
+    };
+}
+
+test_lerp_endpoints!(
+    test_lerp_positive_finite,
+    PositiveFinite,
+    StrictlyPositiveFinite
+);
+test_lerp_endpoints!(
+    test_lerp_negative_finite,
+    NegativeFinite,
+    StrictlyNegativeFinite
+);
+test_lerp_endpoints!(test_lerp_strictly_positive, StrictlyPositive, Positive);
+test_lerp_endpoints!(test_lerp_strictly_negative, StrictlyNegative, Negative);
+test_lerp_endpoints!(test_lerp_non_nan_finite, NonNaNFinite, NonZeroNonNaNFinite);
+test_lerp_endpoints!(
+    test_lerp_mixed_signs,
+    StrictlyNegativeFinite,
+    StrictlyPositive
+);
@@ -3,3 +3,25 @@
 use typed_floats::*;
 
 typed_floats_macros::generate_tests_self!(ln_1p);
+
+#[test]
+fn test_ln_1p_negative() {
+    // `ln_1p(x)` is negative for `x` in `(-1, 0)` and `NaN` below `-1`,
+    // so the result of a type accepting negative values is not typed
+    let a: NonNaNFinite = (-0.5).try_into().unwrap();
+    let res: f64 = a.ln_1p();
+
+    assert!(res < 0.0);
+    assert_eq!(res, (-0.5f64).ln_1p());
+
+    let b: NegativeFinite = (-2.0).try_into().unwrap();
+    let res: f64 = b.ln_1p();
+
+    assert!(res.is_nan());
+
+    // Only positive inputs give a positive result
+    let c: PositiveFinite = 0.5.try_into().unwrap();
+    let res: PositiveFinite = c.ln_1p();
+
+    assert!(res > 0.0);
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "libm")]
+
+use typed_floats::*;
+
+/// The reference implementation, for each float type.
+trait LgammaR: Sized {
+    fn lgamma_r(self) -> (Self, i32);
+}
+
+impl LgammaR for f32 {
+    fn lgamma_r(self) -> (Self, i32) {
+        libm::lgammaf_r(self)
+    }
+}
+
+impl LgammaR for f64 {
+    fn lgamma_r(self) -> (Self, i32) {
+        libm::lgamma_r(self)
+    }
+}
+
+typed_floats_macros::generate_tests_values!(ln_gamma, Type, {
+    for &value in &values {
+        let Ok(x) = Type::new(value) else {
+            continue;
+        };
+
+        let (ln_gamma, sign): (typed_floats::NonNaN<Float>, Sign) = x.ln_gamma();
+        let (expected, expected_sign) = value.lgamma_r();
+
+        assert_eq!(ln_gamma, expected, "{value}");
+        assert_eq!(sign == Sign::Negative, expected_sign < 0, "{value}");
+    }
+});
+
+#[test]
+fn test_ln_gamma_poles() {
+    let (ln_gamma, _) = tf64::ZERO.ln_gamma();
+    assert_eq!(ln_gamma, f64::INFINITY);
+
+    let x: tf64::StrictlyNegativeFinite = (-2.0).try_into().unwrap();
+    let (ln_gamma, _) = x.ln_gamma();
+    assert_eq!(ln_gamma, f64::INFINITY);
+
+    let x: tf64::StrictlyPositiveFinite = 1.5.try_into().unwrap();
+    let (ln_gamma, sign) = x.ln_gamma();
+    assert!(ln_gamma < 0.0);
+    assert_eq!(sign, Sign::Positive);
+}
@@ -0,0 +1,45 @@
+#![cfg(any(feature = "std", feature = "libm"))]
+
+use typed_floats::*;
+
+typed_floats_macros::generate_tests_self_rhs!(log);
+
+#[test]
+fn test_log_base() {
+    let eight: tf64::StrictlyPositiveFinite = 8.0.try_into().unwrap();
+    let two: tf64::StrictlyPositiveFinite = 2.0.try_into().unwrap();
+    let hundred: tf64::NonNaN = 100.0.try_into().unwrap();
+    let ten: tf64::Positive = 10.0.try_into().unwrap();
+
+    let result: f64 = eight.log(two);
+    assert_eq!(result, 3.0);
+    assert_eq!(hundred.log(ten), 2.0);
+
+    // A base smaller than one inverts the sign
+    let half: tf64::PositiveFinite = 0.5.try_into().unwrap();
+    assert_eq!(eight.log(half), -3.0);
+
+    let eight: tf32::StrictlyPositive = 8.0.try_into().unwrap();
+    let two: tf32::NonNaNFinite = 2.0.try_into().unwrap();
+
+    let result: f32 = eight.log(two);
+    assert_eq!(result, 3.0);
+}
+
+#[test]
+fn test_log_base_edge_cases() {
+    let one: tf64::StrictlyPositiveFinite = 1.0.try_into().unwrap();
+    let two: tf64::StrictlyPositiveFinite = 2.0.try_into().unwrap();
+
+    assert_is_positive_zero!(one.log(two));
+    assert_is_negative_zero!(two.log(tf64::ZERO));
+    assert_eq!(tf64::ZERO.log(two), f64::NEG_INFINITY);
+    assert_eq!(tf64::INFINITY.log(two), f64::INFINITY);
+
+    assert_is_nan!(one.log(one));
+    assert_is_nan!(tf64::ZERO.log(tf64::ZERO));
+
+    let minus_eight: tf64::NonNaN = (-8.0).try_into().unwrap();
+    assert_is_nan!(minus_eight.log(two));
+    assert_is_nan!(two.log(minus_eight));
+}
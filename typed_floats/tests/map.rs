@@ -0,0 +1,38 @@
+use typed_floats::*;
+
+#[test]
+#[cfg(feature = "std")]
+fn test_map_floor() {
+    let x: NonNaN = 3.7.try_into().unwrap();
+
+    let floored: PositiveFinite = x.map(f64::floor).unwrap();
+    assert_eq!(floored, 3.0);
+
+    let x: NonNaN = (-0.5).try_into().unwrap();
+    assert_eq!(
+        x.map::<PositiveFinite>(f64::floor),
+        Err(InvalidNumber::Negative)
+    );
+}
+
+#[test]
+fn test_map() {
+    let x: tf32::StrictlyPositiveFinite = 2.0.try_into().unwrap();
+
+    let y: tf32::StrictlyNegative = x.map(|x| -x).unwrap();
+    assert_eq!(y, -2.0);
+
+    assert_eq!(
+        x.map::<tf32::PositiveFinite>(|x| x * f32::INFINITY),
+        Err(InvalidNumber::Infinite)
+    );
+    assert_eq!(
+        x.map::<tf32::NonNaN>(|x| x * f32::NAN),
+        Err(InvalidNumber::NaN)
+    );
+    // `UnitInterval` has its own error type, so it is reached through a `PositiveFinite`
+    assert_eq!(
+        UnitInterval::<f32>::try_from(x.map::<tf32::PositiveFinite>(|x| x / 4.0).unwrap()),
+        UnitInterval::<f32>::new(0.5)
+    );
+}
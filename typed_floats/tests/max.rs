@@ -1,3 +1,29 @@
 use typed_floats::*;
 
 typed_floats_macros::generate_tests_self_rhs!(max);
+
+#[test]
+fn test_max_cross_type() {
+    // `Ord::max` has the same name and only accepts `Self`, so the trait is explicit
+    let a: Positive = 3.0.try_into().unwrap();
+    let b: StrictlyPositiveFinite = 2.0.try_into().unwrap();
+
+    // The result is never zero as `b` is not
+    let c: StrictlyPositive = Max::max(a, b);
+    assert_eq!(c, 3.0);
+
+    let c: StrictlyPositive = Max::max(b, a);
+    assert_eq!(c, 3.0);
+
+    let a: PositiveFinite = 0.0.try_into().unwrap();
+    let b: StrictlyPositiveFinite = 2.0.try_into().unwrap();
+
+    let c: StrictlyPositiveFinite = Max::max(a, b);
+    assert_eq!(c, 2.0);
+
+    let a: NegativeFinite = (-1.0).try_into().unwrap();
+    let b: StrictlyPositive = f64::INFINITY.try_into().unwrap();
+
+    let c: StrictlyPositive = Max::max(a, b);
+    assert_eq!(c, f64::INFINITY);
+}
@@ -0,0 +1,17 @@
+use typed_floats::*;
+
+typed_floats_macros::generate_tests_self_rhs!(midpoint);
+
+#[test]
+fn test_midpoint_no_overflow() {
+    let a: StrictlyPositiveFinite = tf64::MAX;
+    let b: StrictlyPositiveFinite = (f64::MAX / 2.0).try_into().unwrap();
+
+    let res: StrictlyPositiveFinite = a.midpoint(b);
+    assert_eq!(res.get().to_bits(), (f64::MAX * 0.75).to_bits());
+
+    let min: StrictlyPositiveFinite = f64::from_bits(1).try_into().unwrap();
+
+    let res: StrictlyPositiveFinite = min.midpoint(min);
+    assert_eq!(res.get().to_bits(), 1);
+}
@@ -1,3 +1,35 @@
 use typed_floats::*;
 
 typed_floats_macros::generate_tests_self_rhs!(min);
+
+#[test]
+fn test_min_cross_type() {
+    // `Ord::min` has the same name and only accepts `Self`, so the trait is explicit
+    let a: Positive = 3.0.try_into().unwrap();
+    let b: StrictlyPositiveFinite = 2.0.try_into().unwrap();
+
+    // `+inf` is only possible if both are infinite, and `+0.0` only if `a` is zero
+    let c: PositiveFinite = Min::min(a, b);
+    assert_eq!(c, 2.0);
+
+    let c: PositiveFinite = Min::min(b, a);
+    assert_eq!(c, 2.0);
+
+    let a: StrictlyPositive = f64::INFINITY.try_into().unwrap();
+    let b: StrictlyPositiveFinite = 2.0.try_into().unwrap();
+
+    let c: StrictlyPositiveFinite = Min::min(a, b);
+    assert_eq!(c, 2.0);
+
+    let a: Positive = f64::INFINITY.try_into().unwrap();
+    let b: StrictlyPositive = f64::INFINITY.try_into().unwrap();
+
+    let c: Positive = Min::min(a, b);
+    assert_eq!(c, f64::INFINITY);
+
+    let a: NegativeFinite = (-1.0).try_into().unwrap();
+    let b: StrictlyPositive = 2.0.try_into().unwrap();
+
+    let c: NegativeFinite = Min::min(a, b);
+    assert_eq!(c, -1.0);
+}
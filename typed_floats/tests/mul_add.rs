@@ -0,0 +1,21 @@
+#![cfg(any(feature = "std", feature = "libm"))]
+
+use typed_floats::*;
+
+typed_floats_macros::generate_tests_ternary!(mul_add);
+
+#[test]
+fn test_mul_add_strictly_positive() {
+    let a: StrictlyPositiveFinite = 2.0.try_into().unwrap();
+    let b: StrictlyPositiveFinite = 3.0.try_into().unwrap();
+    let c: StrictlyPositiveFinite = 1.0.try_into().unwrap();
+
+    let res: StrictlyPositive = a.mul_add(b, c);
+    assert_eq!(res, 7.0);
+
+    // The product may be rounded to zero, so adding `0.0` may return zero
+    let c: PositiveFinite = 0.0.try_into().unwrap();
+
+    let res: Positive = a.mul_add(b, c);
+    assert_eq!(res, 6.0);
+}
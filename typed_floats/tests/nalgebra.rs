@@ -0,0 +1,62 @@
+#![cfg(feature = "nalgebra")]
+
+use nalgebra::{Matrix2, Vector3};
+use typed_floats::*;
+
+#[test]
+fn test_matrix_add() {
+    let a: Matrix2<StrictlyPositive> = Matrix2::new(
+        1.0.try_into().unwrap(),
+        2.0.try_into().unwrap(),
+        3.0.try_into().unwrap(),
+        4.0.try_into().unwrap(),
+    );
+    let b: Matrix2<StrictlyPositive> = Matrix2::from_element(0.5.try_into().unwrap());
+
+    let c: Matrix2<StrictlyPositive> = a + b;
+
+    assert_eq!(
+        c,
+        Matrix2::new(1.5, 2.5, 3.5, 4.5).map(|x| x.try_into().unwrap())
+    );
+
+    let mut d = c;
+    d += a;
+
+    assert_eq!(d[(1, 1)], 8.5);
+}
+
+#[test]
+fn test_matrix_map() {
+    // `NonNaNFinite + NonNaNFinite` may overflow, so the matrices can only be combined element-wise
+    let a: Vector3<NonNaNFinite> = Vector3::new(
+        1.0.try_into().unwrap(),
+        (-2.0).try_into().unwrap(),
+        tf64::MAX.into(),
+    );
+
+    let b: Vector3<NonNaN> = a.zip_map(&a, |x, y| x + y);
+
+    assert_eq!(
+        b,
+        Vector3::new(2.0, -4.0, f64::INFINITY).map(|x| x.try_into().unwrap())
+    );
+}
+
+#[test]
+fn test_matrix_convert() {
+    let a: Matrix2<PositiveFinite<f32>> = Matrix2::from_element(2.0.try_into().unwrap());
+
+    let b: Matrix2<f32> = a.cast();
+    assert_eq!(b, Matrix2::from_element(2.0));
+
+    let c: Option<Matrix2<StrictlyPositiveFinite<f32>>> = nalgebra::try_convert(b);
+    assert_eq!(c, Some(Matrix2::from_element(2.0.try_into().unwrap())));
+
+    let d: Option<Matrix2<StrictlyPositiveFinite<f32>>> =
+        nalgebra::try_convert(Matrix2::new(1.0, 0.0, 1.0, 1.0));
+    assert_eq!(d, None);
+
+    let e: Option<Vector3<NonNaN>> = nalgebra::try_convert(Vector3::new(1.0, f64::NAN, 1.0));
+    assert_eq!(e, None);
+}
@@ -1,3 +1,63 @@
 use typed_floats::*;
 
 typed_floats_macros::generate_tests_self!(neg);
+
+macro_rules! assert_neg_type {
+    ($float:ident, $value:expr, $($type:ident => $opposite:ident),*) => {
+        $(
+            let x: typed_floats::$type<$float> = ($value).try_into().unwrap_or_else(|_| (-$value).try_into().unwrap());
+            let y: typed_floats::$opposite<$float> = -x;
+
+            assert_eq!(y.get().to_bits(), (-x.get()).to_bits());
+        )*
+    };
+}
+
+#[test]
+fn test_neg_type() {
+    assert_neg_type!(
+        f64,
+        2.0,
+        NonNaN => NonNaN,
+        NonZeroNonNaN => NonZeroNonNaN,
+        NonNaNFinite => NonNaNFinite,
+        NonZeroNonNaNFinite => NonZeroNonNaNFinite,
+        Positive => Negative,
+        Negative => Positive,
+        PositiveFinite => NegativeFinite,
+        NegativeFinite => PositiveFinite,
+        StrictlyPositive => StrictlyNegative,
+        StrictlyNegative => StrictlyPositive,
+        StrictlyPositiveFinite => StrictlyNegativeFinite,
+        StrictlyNegativeFinite => StrictlyPositiveFinite
+    );
+    assert_neg_type!(
+        f32,
+        2.0,
+        NonNaN => NonNaN,
+        NonZeroNonNaN => NonZeroNonNaN,
+        NonNaNFinite => NonNaNFinite,
+        NonZeroNonNaNFinite => NonZeroNonNaNFinite,
+        Positive => Negative,
+        Negative => Positive,
+        PositiveFinite => NegativeFinite,
+        NegativeFinite => PositiveFinite,
+        StrictlyPositive => StrictlyNegative,
+        StrictlyNegative => StrictlyPositive,
+        StrictlyPositiveFinite => StrictlyNegativeFinite,
+        StrictlyNegativeFinite => StrictlyPositiveFinite
+    );
+}
+
+#[test]
+fn test_neg_zeros() {
+    let zero: tf64::PositiveFinite = 0.0.try_into().unwrap();
+    let neg_zero: tf64::NegativeFinite = -zero;
+
+    assert_is_negative_zero!(neg_zero);
+
+    let neg_zero: tf32::NegativeFinite = (-0.0).try_into().unwrap();
+    let zero: tf32::PositiveFinite = -neg_zero;
+
+    assert_is_positive_zero!(zero);
+}
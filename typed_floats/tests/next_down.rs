@@ -0,0 +1,11 @@
+use typed_floats::*;
+
+typed_floats_macros::generate_tests_self!(next_down);
+
+#[test]
+fn test_next_down_min_positive() {
+    let res: PositiveFinite = tf64::MIN_POSITIVE.next_down();
+
+    assert!(res.is_subnormal());
+    assert_eq!(res.to_bits(), f64::MIN_POSITIVE.to_bits() - 1);
+}
@@ -0,0 +1,11 @@
+use typed_floats::*;
+
+typed_floats_macros::generate_tests_self!(next_up);
+
+#[test]
+fn test_next_up_max() {
+    let max: StrictlyPositiveFinite = tf64::MAX;
+    let res: StrictlyPositive = max.next_up();
+
+    assert_eq!(res, f64::INFINITY);
+}
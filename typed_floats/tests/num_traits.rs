@@ -0,0 +1,58 @@
+#![cfg(feature = "num-traits")]
+
+use num_traits::{NumCast, ToPrimitive};
+use typed_floats::*;
+
+#[test]
+fn test_num_traits_to_primitive() {
+    let a: NonNaN<f64> = (-3.5f64).try_into().unwrap();
+
+    assert_eq!(a.to_i64(), Some(-3));
+    assert_eq!(a.to_u64(), None);
+    assert_eq!(a.to_f32(), Some(-3.5));
+
+    let b: Positive<f32> = 3.5f32.try_into().unwrap();
+
+    assert_eq!(b.to_u8(), Some(3));
+    assert_eq!(b.to_f64(), Some(3.5));
+
+    assert_eq!(tf64::INFINITY.to_i64(), None);
+}
+
+#[test]
+fn test_num_traits_num_cast() {
+    let a: Option<StrictlyPositiveFinite<f64>> = NumCast::from(3u8);
+    assert_eq!(a.unwrap(), 3.0);
+
+    let b: Option<StrictlyPositiveFinite<f64>> = NumCast::from(0u8);
+    assert!(b.is_none());
+
+    let c: Option<NonNaN<f32>> = NumCast::from(f64::NAN);
+    assert!(c.is_none());
+
+    let d: Option<Negative<f32>> = NumCast::from(-2i32);
+    assert_eq!(d.unwrap(), -2.0);
+}
+
+#[test]
+fn test_num_traits_zero() {
+    use num_traits::Zero;
+
+    let zero = Positive::<f64>::zero();
+    assert!(zero.is_zero());
+    assert!(zero.is_positive_zero());
+
+    let neg_zero = Negative::<f32>::zero();
+    assert!(neg_zero.is_zero());
+    assert!(neg_zero.is_negative_zero());
+
+    let values: [Positive<f64>; 3] = [
+        1.0.try_into().unwrap(),
+        2.0.try_into().unwrap(),
+        3.5.try_into().unwrap(),
+    ];
+
+    let sum = values.iter().fold(Positive::zero(), |acc, &x| acc + x);
+    assert_eq!(sum, 6.5);
+    assert!(!sum.is_zero());
+}
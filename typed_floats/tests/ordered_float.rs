@@ -0,0 +1,62 @@
+#![cfg(feature = "ordered-float")]
+
+use ordered_float::NotNan;
+use std::collections::BTreeMap;
+use typed_floats::*;
+
+#[test]
+fn test_ordered_float_into_not_nan() {
+    let a: NotNan<f64> = tf64::NonNaN::new(-3.5).unwrap().into();
+
+    assert_eq!(a.into_inner(), -3.5);
+
+    let b: NotNan<f32> = tf32::INFINITY.into();
+
+    assert_eq!(b.into_inner(), f32::INFINITY);
+
+    let c: NotNan<f64> = tf64::NEG_ZERO.into();
+
+    assert!(c.into_inner().is_sign_negative());
+}
+
+#[test]
+fn test_ordered_float_from_not_nan() {
+    let a = NotNan::new(2.0f64).unwrap();
+
+    assert_eq!(
+        StrictlyPositiveFinite::<f64>::try_from(a).map(|x| x.get()),
+        Ok(2.0)
+    );
+
+    let inf = NotNan::new(f64::INFINITY).unwrap();
+
+    assert_eq!(
+        StrictlyPositiveFinite::<f64>::try_from(inf),
+        Err(InvalidNumber::Infinite)
+    );
+    assert!(StrictlyPositive::<f64>::try_from(inf).is_ok());
+
+    let neg = NotNan::new(-1.0f32).unwrap();
+
+    assert_eq!(Positive::<f32>::try_from(neg), Err(InvalidNumber::Negative));
+
+    let zero = NotNan::new(0.0f32).unwrap();
+
+    assert_eq!(
+        StrictlyPositive::<f32>::try_from(zero),
+        Err(InvalidNumber::Zero)
+    );
+}
+
+#[test]
+fn test_ordered_float_map_keys() {
+    let mut map: BTreeMap<NotNan<f64>, &str> = BTreeMap::new();
+
+    map.insert(tf64::consts::PI.into(), "pi");
+    map.insert(tf64::consts::E.into(), "e");
+
+    let keys: Vec<tf64::StrictlyPositiveFinite> =
+        map.keys().filter_map(|&k| k.try_into().ok()).collect();
+
+    assert_eq!(keys, [tf64::consts::E, tf64::consts::PI]);
+}
@@ -0,0 +1,78 @@
+use typed_floats::polynomial::eval_poly;
+use typed_floats::*;
+
+fn finite(x: f64) -> NonNaNFinite {
+    x.try_into().unwrap()
+}
+
+#[test]
+fn test_eval_poly_square_plus_one() {
+    // x² + 1
+    let coeffs = [finite(1.0), finite(0.0), finite(1.0)];
+
+    for (x, expected) in [
+        (0.0, 1.0),
+        (-0.0, 1.0),
+        (1.0, 2.0),
+        (-1.0, 2.0),
+        (2.0, 5.0),
+        (-3.0, 10.0),
+        (0.5, 1.25),
+        (1e3, 1e6 + 1.0),
+    ] {
+        let y: NonNaN = eval_poly(&coeffs, finite(x));
+
+        assert_eq!(y, expected, "x = {x}");
+        assert_eq!(y.get(), x * x + 1.0);
+    }
+}
+
+#[test]
+fn test_eval_poly_values() {
+    let values: [f64; 23] = typed_floats_macros::test_values!(f64);
+
+    let coeffs = [finite(1.0), finite(0.0), finite(1.0)];
+
+    for &x in &values {
+        let Ok(x) = NonNaNFinite::<f64>::new(x) else {
+            continue;
+        };
+
+        let y = eval_poly(&coeffs, x);
+
+        // Only overflows to `+inf`
+        assert!(y >= 1.0);
+        assert_eq!(y.get(), x.get() * x.get() + 1.0);
+    }
+}
+
+#[test]
+fn test_eval_poly_empty() {
+    assert_is_positive_zero!(eval_poly(&[], finite(2.0)));
+    assert_is_positive_zero!(eval_poly(&[], finite(0.0)));
+}
+
+#[test]
+fn test_eval_poly_constant() {
+    assert_eq!(eval_poly(&[finite(-7.0)], finite(f64::MAX)), -7.0);
+    assert_eq!(eval_poly(&[finite(-7.0)], finite(0.0)), -7.0);
+}
+
+#[test]
+fn test_eval_poly_overflow() {
+    let max = finite(f64::MAX);
+
+    // MAX * x, with odd and even degrees
+    assert_eq!(eval_poly(&[finite(0.0), max], finite(2.0)), f64::INFINITY);
+    assert_eq!(
+        eval_poly(&[finite(0.0), max], finite(-2.0)),
+        f64::NEG_INFINITY
+    );
+    assert_eq!(
+        eval_poly(&[max, max, max, max], finite(-2.0)),
+        f64::NEG_INFINITY
+    );
+
+    // An infinite accumulator followed by a zero `x` can't happen: with `x == 0` only the constant term remains
+    assert_eq!(eval_poly(&[finite(3.0), max, max], finite(0.0)), 3.0);
+}
@@ -0,0 +1,23 @@
+use typed_floats::*;
+
+typed_floats_macros::generate_tests_self_rhs!(positive_diff);
+
+#[test]
+fn test_positive_diff() {
+    let a: tf64::NonNaNFinite = 3.0.try_into().unwrap();
+    let b: tf64::NonNaNFinite = 5.0.try_into().unwrap();
+
+    let c: tf64::Positive = a.positive_diff(b);
+
+    assert_is_positive_zero!(c);
+    assert_eq!(b.positive_diff(a), 2.0);
+
+    let a: tf32::NegativeFinite = (-3.0).try_into().unwrap();
+    let b: tf32::NegativeFinite = (-5.0).try_into().unwrap();
+
+    let c: tf32::PositiveFinite = a.positive_diff(b);
+
+    assert_eq!(c, 2.0);
+    assert_is_positive_zero!(b.positive_diff(a));
+    assert_is_positive_zero!(tf32::NEG_ZERO.positive_diff(tf32::ZERO));
+}
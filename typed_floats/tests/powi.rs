@@ -3,3 +3,93 @@
 use typed_floats::*;
 
 typed_floats_macros::generate_tests_self!(powi);
+
+typed_floats_macros::generate_tests_values!(powi_const, Type, {
+    for &value in &values {
+        let Ok(x) = Type::new(value) else {
+            continue;
+        };
+
+        // This will panic if the result isn't compatible with the return type
+        assert_eq!(x.powi_const::<0>(), 1.0);
+        assert_eq!(x.powi_const::<1>().get().to_bits(), value.to_bits());
+        assert_eq!(x.powi_const::<2>().get().to_bits(), value.powi(2).to_bits());
+        assert_eq!(x.powi_const::<3>().get().to_bits(), value.powi(3).to_bits());
+        assert_eq!(
+            x.powi_const::<64>().get().to_bits(),
+            value.powi(64).to_bits()
+        );
+    }
+});
+
+#[test]
+fn test_powi_const_types() {
+    let x: tf64::NonNaN = (-2.0).try_into().unwrap();
+
+    let one: tf64::StrictlyPositiveFinite = x.powi_const::<0>();
+    let square: tf64::Positive = x.powi_const::<2>();
+    let cube: tf64::NonNaN = x.powi_const::<3>();
+
+    assert_eq!(one, 1.0);
+    assert_eq!(square, 4.0);
+    assert_eq!(cube, -8.0);
+
+    let x: tf32::StrictlyPositiveFinite = 2.0.try_into().unwrap();
+
+    let cube: tf32::Positive = x.powi_const::<3>();
+    assert_eq!(cube, 8.0);
+
+    // The power may still overflow or be rounded to zero
+    assert_eq!(tf64::MAX.powi_const::<2>(), f64::INFINITY);
+    assert_is_positive_zero!(tf64::MIN_POSITIVE.powi_const::<2>());
+    assert_is_negative_zero!((-tf64::MIN_POSITIVE).powi_const::<3>());
+}
+
+#[test]
+fn test_powi_negative_exponents() {
+    let x: tf64::StrictlyNegativeFinite = (-2.0).try_into().unwrap();
+
+    // `-0.125` is strictly negative, but the type must also accept `MIN.powi(-3)`,
+    // rounded to `-0.0`, and the infinity reached from the subnormals
+    let y: tf64::Negative = x.powi_const::<-3>();
+    assert_eq!(y, -0.125);
+    assert!(y.is_strictly_negative());
+    assert_is_negative_zero!(tf64::MIN.powi_const::<-3>());
+    assert_eq!((-tf64::MIN_POSITIVE).powi_const::<-3>(), f64::NEG_INFINITY);
+
+    let x: tf64::StrictlyPositiveFinite = 2.0.try_into().unwrap();
+
+    // Likewise, `MAX.powi(-2)` is rounded to `+0.0`
+    let y: tf64::Positive = x.powi_const::<-2>();
+    assert_eq!(y, 0.25);
+    assert!(y.is_strictly_positive());
+    assert_is_positive_zero!(tf64::MAX.powi_const::<-2>());
+    assert_eq!(tf64::MIN_POSITIVE.powi_const::<-64>(), f64::INFINITY);
+}
+
+#[test]
+fn test_powi_exponent_classes() {
+    let values: [f64; 23] = typed_floats_macros::test_values!(f64);
+
+    for &value in &values {
+        let Ok(x) = tf64::NonNaN::new(value) else {
+            continue;
+        };
+
+        for n in -3..=3 {
+            let y = x.powi(n);
+
+            if n == 0 {
+                assert_eq!(y, 1.0);
+            } else if n % 2 == 0 {
+                assert!(y.is_sign_positive(), "{value}.powi({n}) = {y:?}");
+            } else {
+                assert_eq!(
+                    y.is_sign_negative(),
+                    x.is_sign_negative(),
+                    "{value}.powi({n})"
+                );
+            }
+        }
+    }
+}
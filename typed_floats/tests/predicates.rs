@@ -0,0 +1,50 @@
+use typed_floats::*;
+
+typed_floats_macros::generate_tests_values!(predicates, Type, {
+    for &value in &values {
+        let Ok(x) = Type::new(value) else {
+            continue;
+        };
+
+        assert_eq!(x.is_strictly_positive(), value > 0.0, "{value}");
+        assert_eq!(x.is_strictly_negative(), value < 0.0, "{value}");
+        assert_eq!(x.is_zero(), value == 0.0, "{value}");
+    }
+});
+
+const fn sign_index(x: tf64::NonNaN) -> usize {
+    if x.is_strictly_negative() {
+        0
+    } else if x.is_zero() {
+        1
+    } else {
+        2
+    }
+}
+
+#[test]
+fn test_predicates_const() {
+    const NEG_INF: tf64::NonNaN = unsafe { tf64::NonNaN::new_unchecked(f64::NEG_INFINITY) };
+    const NEG_ZERO: tf64::NonNaN = unsafe { tf64::NonNaN::new_unchecked(-0.0) };
+    const ZERO: tf64::NonNaN = unsafe { tf64::NonNaN::new_unchecked(0.0) };
+    const MIN_SUBNORMAL: tf64::NonNaN = unsafe { tf64::NonNaN::new_unchecked(5e-324) };
+
+    const TABLE: [usize; 4] = [
+        sign_index(NEG_INF),
+        sign_index(NEG_ZERO),
+        sign_index(ZERO),
+        sign_index(MIN_SUBNORMAL),
+    ];
+
+    assert_eq!(TABLE, [0, 1, 1, 2]);
+
+    const X: tf32::StrictlyNegativeFinite =
+        unsafe { tf32::StrictlyNegativeFinite::new_unchecked(-f32::MIN_POSITIVE) };
+    const IS_STRICTLY_POSITIVE: bool = X.is_strictly_positive();
+    const IS_STRICTLY_NEGATIVE: bool = X.is_strictly_negative();
+    const IS_ZERO: bool = X.is_zero();
+
+    assert!(!IS_STRICTLY_POSITIVE);
+    assert!(IS_STRICTLY_NEGATIVE);
+    assert!(!IS_ZERO);
+}
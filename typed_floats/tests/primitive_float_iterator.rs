@@ -0,0 +1,68 @@
+use typed_floats::*;
+
+#[test]
+fn test_filter_valid() {
+    let values = [1.5, f64::NAN, -2.0, 0.0, -0.0, f64::INFINITY, 4.0];
+
+    let positive: Vec<PositiveFinite<f64>> = values.iter().copied().filter_valid().collect();
+    assert_eq!(positive, [1.5, 0.0, 4.0]);
+
+    let non_nan: Vec<NonNaN<f64>> = values.iter().copied().filter_valid().collect();
+    assert_eq!(non_nan.len(), values.len() - 1);
+
+    let negative = values
+        .iter()
+        .copied()
+        .filter_valid::<StrictlyNegative<f64>>()
+        .map(|x| x.get());
+    assert!(negative.eq([-2.0]));
+
+    let values: [f32; 3] = [f32::NAN, -1.0, 2.0];
+    let positive: Vec<StrictlyPositive<f32>> = values.into_iter().filter_valid().collect();
+    assert_eq!(positive, [2.0]);
+}
+
+#[test]
+fn test_try_typed() {
+    let values = [1.5, f64::NAN, -2.0, 0.0];
+
+    let results: Vec<Result<StrictlyPositive<f64>, InvalidNumber>> =
+        values.iter().copied().try_typed().collect();
+
+    assert_eq!(
+        results,
+        [
+            Ok(StrictlyPositive::<f64>::new(1.5).unwrap()),
+            Err(InvalidNumber::NaN),
+            Err(InvalidNumber::Negative),
+            Err(InvalidNumber::Zero),
+        ]
+    );
+
+    let all: Result<Vec<NonNaN<f64>>, InvalidNumber> = values.iter().copied().try_typed().collect();
+    assert_eq!(all, Err(InvalidNumber::NaN));
+
+    let all: Result<Vec<NonNaN<f64>>, InvalidNumber> =
+        [1.0, -1.0].into_iter().try_typed().collect();
+    assert_eq!(all.unwrap(), [1.0, -1.0]);
+}
+
+#[test]
+fn test_same_as_new() {
+    let values: [f64; 23] = typed_floats_macros::test_values!(f64);
+
+    let filtered: Vec<NegativeFinite<f64>> = values.iter().copied().filter_valid().collect();
+    let expected: Vec<NegativeFinite<f64>> = values
+        .iter()
+        .filter_map(|&x| NegativeFinite::<f64>::new(x).ok())
+        .collect();
+    assert_eq!(filtered, expected);
+
+    let results: Vec<Result<NegativeFinite<f64>, InvalidNumber>> =
+        values.iter().copied().try_typed().collect();
+    let expected: Vec<Result<NegativeFinite<f64>, InvalidNumber>> = values
+        .iter()
+        .map(|&x| NegativeFinite::<f64>::new(x))
+        .collect();
+    assert_eq!(results, expected);
+}
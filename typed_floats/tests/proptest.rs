@@ -0,0 +1,104 @@
+#![cfg(feature = "proptest")]
+
+use proptest::prelude::*;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+use typed_floats::*;
+
+const CASES: usize = 10_000;
+
+macro_rules! test_proptest {
+    ($test:ident, $type:ident, $float:ident) => {
+        #[test]
+        fn $test() {
+            let mut runner = TestRunner::deterministic();
+            let strategy = any::<$type<$float>>();
+
+            for _ in 0..CASES {
+                let mut tree = strategy.new_tree(&mut runner).unwrap();
+                let value = tree.current().get();
+
+                assert!(
+                    $type::<$float>::try_from(value).is_ok(),
+                    "{value} is not valid"
+                );
+
+                // The shrunk values must be valid too
+                while tree.simplify() {
+                    let value = tree.current().get();
+
+                    assert!(
+                        $type::<$float>::try_from(value).is_ok(),
+                        "{value} is not valid"
+                    );
+                }
+            }
+        }
+    };
+}
+
+macro_rules! test_proptests {
+    ($test_f32:ident, $test_f64:ident, $type:ident) => {
+        test_proptest!($test_f32, $type, f32);
+        test_proptest!($test_f64, $type, f64);
+    };
+}
+
+test_proptests!(non_nan_f32, non_nan_f64, NonNaN);
+test_proptests!(non_nan_finite_f32, non_nan_finite_f64, NonNaNFinite);
+test_proptests!(non_zero_non_nan_f32, non_zero_non_nan_f64, NonZeroNonNaN);
+test_proptests!(
+    non_zero_non_nan_finite_f32,
+    non_zero_non_nan_finite_f64,
+    NonZeroNonNaNFinite
+);
+test_proptests!(positive_f32, positive_f64, Positive);
+test_proptests!(positive_finite_f32, positive_finite_f64, PositiveFinite);
+test_proptests!(
+    strictly_positive_f32,
+    strictly_positive_f64,
+    StrictlyPositive
+);
+test_proptests!(
+    strictly_positive_finite_f32,
+    strictly_positive_finite_f64,
+    StrictlyPositiveFinite
+);
+test_proptests!(negative_f32, negative_f64, Negative);
+test_proptests!(negative_finite_f32, negative_finite_f64, NegativeFinite);
+test_proptests!(
+    strictly_negative_f32,
+    strictly_negative_f64,
+    StrictlyNegative
+);
+test_proptests!(
+    strictly_negative_finite_f32,
+    strictly_negative_finite_f64,
+    StrictlyNegativeFinite
+);
+
+#[test]
+fn edge_cases() {
+    let mut runner = TestRunner::deterministic();
+    let strategy = any::<PositiveFinite<f64>>();
+    let values: Vec<f64> = (0..CASES)
+        .filter_map(|_| strategy.new_tree(&mut runner).ok())
+        .map(|tree| tree.current().get())
+        .collect();
+
+    assert!(values.contains(&0.0));
+    assert!(values.contains(&f64::MIN_POSITIVE));
+    assert!(values.contains(&f64::MAX));
+}
+
+proptest! {
+    #[test]
+    fn any_strictly_positive(x in any::<tf64::StrictlyPositive>()) {
+        prop_assert!(x > 0.0);
+    }
+
+    #[test]
+    fn any_negative_finite(x in any::<tf32::NegativeFinite>()) {
+        prop_assert!(x.is_sign_negative() && x.is_finite());
+    }
+}
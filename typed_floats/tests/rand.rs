@@ -0,0 +1,119 @@
+#![cfg(feature = "rand")]
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use typed_floats::*;
+
+const SAMPLES: usize = 10_000;
+
+macro_rules! test_rand {
+    ($test:ident, $type:ident, $float:ident, $min:expr, $max:expr) => {
+        #[test]
+        fn $test() {
+            let mut rng = StdRng::seed_from_u64(42);
+            let mut sum = 0.0;
+
+            for _ in 0..SAMPLES {
+                let x: $type<$float> = rng.gen();
+                let value = x.get();
+
+                assert!($type::<$float>::new(value).is_ok(), "{value} is not valid");
+                assert!(($min..=$max).contains(&value), "{value} is out of range");
+
+                sum += value;
+            }
+
+            // Smoke test of the uniformity: the mean should be close to the middle of the range
+            let mean = sum / SAMPLES as $float;
+            let middle = ($min + $max) / 2.0;
+
+            assert!((mean - middle).abs() < 0.05, "mean: {mean}");
+        }
+    };
+}
+
+macro_rules! test_rands {
+    ($test_f32:ident, $test_f64:ident, $type:ident, $min:expr, $max:expr) => {
+        test_rand!($test_f32, $type, f32, $min, $max);
+        test_rand!($test_f64, $type, f64, $min, $max);
+    };
+}
+
+test_rands!(non_nan_f32, non_nan_f64, NonNaN, 0.0, 1.0);
+test_rands!(
+    non_nan_finite_f32,
+    non_nan_finite_f64,
+    NonNaNFinite,
+    0.0,
+    1.0
+);
+test_rands!(
+    non_zero_non_nan_f32,
+    non_zero_non_nan_f64,
+    NonZeroNonNaN,
+    0.0,
+    1.0
+);
+test_rands!(
+    non_zero_non_nan_finite_f32,
+    non_zero_non_nan_finite_f64,
+    NonZeroNonNaNFinite,
+    0.0,
+    1.0
+);
+test_rands!(positive_f32, positive_f64, Positive, 0.0, 1.0);
+test_rands!(
+    positive_finite_f32,
+    positive_finite_f64,
+    PositiveFinite,
+    0.0,
+    1.0
+);
+test_rands!(
+    strictly_positive_f32,
+    strictly_positive_f64,
+    StrictlyPositive,
+    0.0,
+    1.0
+);
+test_rands!(
+    strictly_positive_finite_f32,
+    strictly_positive_finite_f64,
+    StrictlyPositiveFinite,
+    0.0,
+    1.0
+);
+test_rands!(negative_f32, negative_f64, Negative, -1.0, 0.0);
+test_rands!(
+    negative_finite_f32,
+    negative_finite_f64,
+    NegativeFinite,
+    -1.0,
+    0.0
+);
+test_rands!(
+    strictly_negative_f32,
+    strictly_negative_f64,
+    StrictlyNegative,
+    -1.0,
+    0.0
+);
+test_rands!(
+    strictly_negative_finite_f32,
+    strictly_negative_finite_f64,
+    StrictlyNegativeFinite,
+    -1.0,
+    0.0
+);
+
+#[test]
+fn sample_iter() {
+    let rng = StdRng::seed_from_u64(42);
+
+    let values: Vec<tf64::StrictlyPositive> = rng
+        .sample_iter(rand::distributions::Standard)
+        .take(100)
+        .collect();
+
+    assert_eq!(values.len(), 100);
+    assert!(values.iter().all(|x| x.get() > 0.0 && x.get() <= 1.0));
+}
@@ -0,0 +1,56 @@
+use typed_floats::*;
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn test_raw() {
+    let x: tf64::StrictlyNegative = (-4.0).try_into().unwrap();
+
+    let sqrt: f64 = x.sqrt_raw();
+    assert_is_nan!(sqrt);
+
+    let y: tf64::StrictlyPositive = 4.0.try_into().unwrap();
+
+    let sqrt: f64 = y.sqrt_raw();
+    assert_eq!(sqrt, y.sqrt());
+
+    let neg: f64 = y.neg_raw();
+    assert_eq!(neg, -4.0);
+}
+
+#[test]
+fn test_raw_bin_op() {
+    let zero: tf64::PositiveFinite = 0.0.try_into().unwrap();
+    let inf: tf64::Positive = f64::INFINITY.try_into().unwrap();
+
+    assert_is_nan!(zero.div_raw(zero));
+    assert_is_nan!(inf.sub_raw(inf));
+    assert_is_nan!(zero.mul_raw(inf));
+    assert_is_nan!(inf.rem_raw(1.0));
+
+    let x: tf32::NonZeroNonNaNFinite = 3.0.try_into().unwrap();
+    let y: tf32::StrictlyNegativeFinite = (-2.0).try_into().unwrap();
+
+    let sum: f32 = x.add_raw(y);
+    assert_eq!(sum, x + y);
+    assert_eq!(x.div_raw(2.0), 1.5);
+}
+
+#[test]
+fn test_raw_all_values() {
+    let values: [f64; 23] = typed_floats_macros::test_values!(f64);
+
+    for &a in &values {
+        let Ok(x) = tf64::NonNaN::new(a) else {
+            continue;
+        };
+
+        assert_eq!(x.neg_raw(), -a);
+
+        for &b in &values {
+            let raw = x.mul_raw(b);
+            let expected = a * b;
+
+            assert!(raw == expected || (raw.is_nan() && expected.is_nan()));
+        }
+    }
+}
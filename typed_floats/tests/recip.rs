@@ -1,3 +1,19 @@
 use typed_floats::*;
 
 typed_floats_macros::generate_tests_self!(recip);
+
+#[test]
+fn test_recip_subnormal() {
+    let a: tf64::StrictlyPositiveFinite = f64::from_bits(1).try_into().unwrap();
+    let b: tf32::StrictlyNegativeFinite = (-f32::from_bits(1)).try_into().unwrap();
+
+    let a_recip: tf64::StrictlyPositive = a.recip();
+    let b_recip: tf32::StrictlyNegative = b.recip();
+
+    assert_eq!(a_recip, f64::INFINITY);
+    assert_eq!(b_recip, f32::NEG_INFINITY);
+
+    let c: tf64::StrictlyPositiveFinite = f64::MIN_POSITIVE.try_into().unwrap();
+
+    assert!(c.recip().is_finite());
+}
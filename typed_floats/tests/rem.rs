@@ -1,3 +1,24 @@
 use typed_floats::*;
 
 typed_floats_macros::generate_tests_self_rhs!(rem);
+
+#[test]
+fn test_rem_sign_of_dividend() {
+    let a: tf64::PositiveFinite = 5.0.try_into().unwrap();
+    let b: tf64::StrictlyNegativeFinite = (-3.0).try_into().unwrap();
+
+    let r: tf64::PositiveFinite = a % b;
+    assert_eq!(r, 2.0);
+
+    let a: tf64::NegativeFinite = (-5.0).try_into().unwrap();
+    let b: tf64::StrictlyPositiveFinite = 3.0.try_into().unwrap();
+
+    let r: tf64::NegativeFinite = a % b;
+    assert_eq!(r, -2.0);
+
+    let a: tf32::StrictlyPositiveFinite = 6.0.try_into().unwrap();
+    let b: tf32::StrictlyNegative = (-3.0).try_into().unwrap();
+
+    let r: tf32::PositiveFinite = a % b;
+    assert_is_positive_zero!(r);
+}
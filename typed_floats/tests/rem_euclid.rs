@@ -0,0 +1,21 @@
+#![cfg(any(feature = "std", feature = "libm"))]
+
+use typed_floats::*;
+
+typed_floats_macros::generate_tests_self_rhs!(rem_euclid);
+
+#[test]
+fn test_rem_euclid_negative() {
+    let a: StrictlyNegativeFinite = (-7.0).try_into().unwrap();
+    let b: StrictlyPositiveFinite = 3.0.try_into().unwrap();
+
+    // May be `-0.0`
+    let res: NonNaNFinite = a.rem_euclid(b);
+    assert_eq!(res, 2.0);
+    assert!(res.is_sign_positive());
+
+    let a: PositiveFinite = 7.0.try_into().unwrap();
+
+    let res: PositiveFinite = a.rem_euclid(b);
+    assert_eq!(res, 1.0);
+}
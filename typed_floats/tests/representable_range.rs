@@ -0,0 +1,106 @@
+use typed_floats::*;
+
+typed_floats_macros::generate_tests_values!(representable_range, Type, {
+    let first = representable_range::<Type>().next().unwrap().get();
+    let last = representable_range::<Type>().next_back().unwrap().get();
+
+    // The first and last values are the bounds of the type
+    for &value in &values {
+        if Type::new(value).is_ok() {
+            assert!(first.total_cmp(&value).is_le());
+            assert!(last.total_cmp(&value).is_ge());
+        }
+    }
+
+    // Both ends are in ascending order, without any invalid value
+    let head: Vec<Float> = representable_range::<Type>()
+        .take(1000)
+        .map(|x| x.get())
+        .collect();
+    let mut tail: Vec<Float> = representable_range::<Type>()
+        .rev()
+        .take(1000)
+        .map(|x| x.get())
+        .collect();
+    tail.reverse();
+
+    for values in [head, tail] {
+        for pair in values.windows(2) {
+            assert!(pair[0].total_cmp(&pair[1]).is_lt());
+        }
+        for &value in &values {
+            assert!(Type::new(value).is_ok());
+        }
+    }
+});
+
+#[test]
+fn test_strictly_positive_finite_f32() {
+    let mut values = representable_range::<StrictlyPositiveFinite<f32>>();
+
+    // The smallest value is the smallest subnormal, not `MIN_POSITIVE` which is the smallest normal
+    assert_eq!(values.next().unwrap(), f32::from_bits(1));
+    assert_eq!(values.next().unwrap(), f32::from_bits(2));
+    assert_eq!(values.next_back(), Some(tf32::MAX));
+
+    // `MIN_POSITIVE` comes after every subnormal
+    let mut values = representable_range::<StrictlyPositiveFinite<f32>>();
+    assert_eq!(
+        values.nth(f32::MIN_POSITIVE.to_bits() as usize - 1),
+        Some(tf32::MIN_POSITIVE)
+    );
+
+    let count = f32::MAX.to_bits() as usize;
+    let values = representable_range::<StrictlyPositiveFinite<f32>>();
+    assert_eq!(values.size_hint().1, Some(count));
+}
+
+#[test]
+fn test_zeros() {
+    let inf_bits = f32::INFINITY.to_bits() as usize;
+
+    // `-0.0` then `+0.0`
+    let mut values = representable_range::<NonNaN<f32>>();
+    let zero = values.nth(inf_bits).unwrap();
+    assert_is_negative_zero!(zero);
+    assert_is_positive_zero!(values.next().unwrap());
+
+    // Both zeros are skipped
+    let mut values = representable_range::<NonZeroNonNaN<f32>>();
+    assert_eq!(values.nth(inf_bits - 1).unwrap(), -f32::from_bits(1));
+    assert_eq!(values.next().unwrap(), f32::from_bits(1));
+
+    let mut values = representable_range::<NonZeroNonNaN<f32>>();
+    assert_eq!(values.nth_back(inf_bits - 1).unwrap(), f32::from_bits(1));
+    assert_eq!(values.next_back().unwrap(), -f32::from_bits(1));
+
+    // `nth` and `next` agree around the zeros
+    let mut values = representable_range::<NonZeroNonNaN<f32>>();
+    values.nth(inf_bits - 10);
+
+    for n in inf_bits - 9..inf_bits + 5 {
+        assert_eq!(
+            values.next(),
+            representable_range::<NonZeroNonNaN<f32>>().nth(n)
+        );
+    }
+}
+
+#[test]
+fn test_exhausted() {
+    let mut values = representable_range::<NonNaNFinite<f32>>();
+    let mut count = 0;
+
+    // Meet in the middle
+    values.nth(f32::MAX.to_bits() as usize - 10);
+    values.nth_back(f32::MAX.to_bits() as usize - 10);
+
+    while values.next().is_some() {
+        count += 1;
+    }
+
+    // From `-10 ulps` to `+10 ulps`, with both zeros, minus the two values returned by `nth`
+    assert_eq!(count, 21 + 1 - 2);
+    assert_eq!(values.next(), None);
+    assert_eq!(values.next_back(), None);
+}
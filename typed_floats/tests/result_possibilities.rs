@@ -0,0 +1,120 @@
+use typed_floats::*;
+
+const NAN: FloatPossibilities = FloatPossibilities {
+    accept_nan: true,
+    accept_inf: true,
+    accept_zero: true,
+    accept_positive: true,
+    accept_negative: true,
+};
+
+#[test]
+fn test_possibilities() {
+    assert_eq!(
+        NonNaN::<f64>::POSSIBILITIES,
+        FloatPossibilities {
+            accept_nan: false,
+            accept_inf: true,
+            accept_zero: true,
+            accept_positive: true,
+            accept_negative: true,
+        }
+    );
+
+    assert_eq!(
+        NegativeFinite::<f32>::POSSIBILITIES,
+        FloatPossibilities {
+            accept_nan: false,
+            accept_inf: false,
+            accept_zero: true,
+            accept_positive: false,
+            accept_negative: true,
+        }
+    );
+}
+
+#[test]
+fn test_neg() {
+    assert_eq!(
+        result_possibilities("neg", StrictlyPositive::<f64>::POSSIBILITIES),
+        Some(StrictlyNegative::<f64>::POSSIBILITIES)
+    );
+    assert_eq!(
+        result_possibilities("neg", NonNaNFinite::<f64>::POSSIBILITIES),
+        Some(NonNaNFinite::<f64>::POSSIBILITIES)
+    );
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn test_sqrt() {
+    assert_eq!(
+        result_possibilities("sqrt", StrictlyPositive::<f64>::POSSIBILITIES),
+        Some(StrictlyPositive::<f64>::POSSIBILITIES)
+    );
+    assert_eq!(
+        result_possibilities("sqrt", PositiveFinite::<f64>::POSSIBILITIES),
+        Some(PositiveFinite::<f64>::POSSIBILITIES)
+    );
+    assert_eq!(
+        result_possibilities("sqrt", NonNaN::<f64>::POSSIBILITIES),
+        Some(NAN)
+    );
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn test_abs() {
+    assert_eq!(
+        result_possibilities("abs", NonNaN::<f64>::POSSIBILITIES),
+        Some(Positive::<f64>::POSSIBILITIES)
+    );
+    assert_eq!(
+        result_possibilities("abs", StrictlyNegativeFinite::<f64>::POSSIBILITIES),
+        Some(StrictlyPositiveFinite::<f64>::POSSIBILITIES)
+    );
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn test_cbrt() {
+    assert_eq!(
+        result_possibilities("cbrt", StrictlyNegative::<f64>::POSSIBILITIES),
+        Some(StrictlyNegative::<f64>::POSSIBILITIES)
+    );
+    assert_eq!(
+        result_possibilities("cbrt", PositiveFinite::<f64>::POSSIBILITIES),
+        Some(PositiveFinite::<f64>::POSSIBILITIES)
+    );
+    assert_eq!(
+        result_possibilities("cbrt", NonZeroNonNaN::<f64>::POSSIBILITIES),
+        Some(NonZeroNonNaN::<f64>::POSSIBILITIES)
+    );
+}
+
+#[test]
+fn test_unsupported() {
+    assert_eq!(
+        result_possibilities("unknown", NonNaN::<f64>::POSSIBILITIES),
+        None
+    );
+
+    // `add` is a binary operation
+    assert_eq!(
+        result_possibilities("add", NonNaN::<f64>::POSSIBILITIES),
+        None
+    );
+
+    assert_eq!(result_possibilities("neg", NAN), None);
+
+    // No sign isn't one of the 12 types
+    let no_sign = FloatPossibilities {
+        accept_nan: false,
+        accept_inf: true,
+        accept_zero: true,
+        accept_positive: false,
+        accept_negative: false,
+    };
+
+    assert_eq!(result_possibilities("neg", no_sign), None);
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "rkyv")]
+
+use typed_floats::*;
+
+#[test]
+fn test_rkyv_round_trip() {
+    let values: Vec<PositiveFinite<f32>> = [0.0f32, 1.5, 3.0, f32::MAX]
+        .iter()
+        .map(|&x| PositiveFinite::<f32>::new(x).unwrap())
+        .collect();
+
+    let bytes = rkyv::to_bytes::<_, 256>(&values).unwrap();
+
+    let archived = rkyv::check_archived_root::<Vec<PositiveFinite<f32>>>(&bytes).unwrap();
+
+    assert_eq!(archived.as_slice(), values.as_slice());
+
+    let deserialized: Vec<PositiveFinite<f32>> =
+        rkyv::Deserialize::deserialize(archived, &mut rkyv::Infallible).unwrap();
+
+    assert_eq!(deserialized, values);
+}
+
+#[test]
+fn test_rkyv_zero_copy() {
+    let values = [-0.0, -1.0, f64::NEG_INFINITY];
+
+    let bytes = rkyv::to_bytes::<_, 256>(&values.to_vec()).unwrap();
+
+    // The typed floats are archived as the bare float, so an archive of floats
+    // can be accessed as an archive of typed floats.
+    let archived = rkyv::check_archived_root::<Vec<tf64::Negative>>(&bytes).unwrap();
+
+    assert_eq!(archived.len(), 3);
+    assert_is_negative_zero!(archived[0]);
+    assert_eq!(archived[1], -1.0);
+    assert_eq!(archived[2], f64::NEG_INFINITY);
+}
+
+#[test]
+fn test_rkyv_check_bytes() {
+    let bytes = rkyv::to_bytes::<_, 256>(&vec![1.0, f64::NAN]).unwrap();
+
+    assert!(rkyv::check_archived_root::<Vec<tf64::NonNaN>>(&bytes).is_err());
+
+    let bytes = rkyv::to_bytes::<_, 256>(&vec![1.0f32, f32::INFINITY]).unwrap();
+
+    assert!(rkyv::check_archived_root::<Vec<tf32::NonNaN>>(&bytes).is_ok());
+    assert!(rkyv::check_archived_root::<Vec<tf32::NonNaNFinite>>(&bytes).is_err());
+
+    let bytes = rkyv::to_bytes::<_, 256>(&vec![1.0f32, 0.0]).unwrap();
+
+    assert!(rkyv::check_archived_root::<Vec<tf32::StrictlyPositive>>(&bytes).is_err());
+}
@@ -0,0 +1,77 @@
+use typed_floats::*;
+
+typed_floats_macros::generate_tests_values!(
+    saturating,
+    Lhs in [
+        NonNaNFinite,
+        NonZeroNonNaNFinite,
+        PositiveFinite,
+        NegativeFinite,
+        StrictlyPositiveFinite,
+        StrictlyNegativeFinite
+    ],
+    Rhs in [
+        NonNaNFinite,
+        NonZeroNonNaNFinite,
+        PositiveFinite,
+        NegativeFinite,
+        StrictlyPositiveFinite,
+        StrictlyNegativeFinite
+    ],
+    {
+        for &a in &values {
+            let Ok(a) = Lhs::new(a) else {
+                continue;
+            };
+
+            for &b in &values {
+                let Ok(b) = Rhs::new(b) else {
+                    continue;
+                };
+
+                for (saturating, raw) in [
+                    (a.saturating_add(b).get(), a.get() + b.get()),
+                    (a.saturating_sub(b).get(), a.get() - b.get()),
+                    (a.saturating_mul(b).get(), a.get() * b.get()),
+                ] {
+                    let expected = if raw == Float::INFINITY {
+                        Float::MAX
+                    } else if raw == Float::NEG_INFINITY {
+                        Float::MIN
+                    } else {
+                        raw
+                    };
+
+                    assert_eq!(saturating.to_bits(), expected.to_bits());
+                }
+            }
+        }
+    }
+);
+
+#[test]
+fn test_saturating_overflow() {
+    let max: tf64::StrictlyPositiveFinite = tf64::MAX.saturating_add(tf64::MAX);
+    let min: tf64::StrictlyNegativeFinite = tf64::MIN.saturating_add(tf64::MIN);
+
+    assert_eq!(max, tf64::MAX);
+    assert_eq!(min, tf64::MIN);
+
+    assert_eq!(tf32::MAX.saturating_sub(tf32::MIN), tf32::MAX);
+    assert_eq!(tf32::MAX.saturating_mul(tf32::MIN), tf32::MIN);
+}
+
+#[test]
+fn test_saturating_same_type() {
+    let a: tf64::PositiveFinite = 1.5.try_into().unwrap();
+    let b: tf64::PositiveFinite = tf64::MAX.into();
+
+    let res: tf64::PositiveFinite = a.saturating_add(b);
+    assert_eq!(res, tf64::MAX);
+
+    let a: tf64::NonNaNFinite = (-1.5).try_into().unwrap();
+    let b: tf64::NonNaNFinite = tf64::MIN.into();
+
+    let res: tf64::NonNaNFinite = b.saturating_sub(a.saturating_mul(b));
+    assert_eq!(res, tf64::MIN);
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "schemars")]
+
+use schemars::schema_for;
+use serde_json::{json, Value};
+use typed_floats::*;
+
+#[test]
+fn test_schemars_bounds() {
+    let schema = serde_json::to_value(schema_for!(StrictlyPositiveFinite<f64>)).unwrap();
+
+    assert_eq!(schema["type"], "number");
+    assert_eq!(schema["format"], "double");
+    assert_eq!(schema["exclusiveMinimum"], 0.0);
+    assert_eq!(schema["maximum"], f64::MAX);
+    assert_eq!(schema.get("minimum"), None);
+    assert_eq!(schema.get("exclusiveMaximum"), None);
+
+    let schema = serde_json::to_value(schema_for!(Positive<f32>)).unwrap();
+
+    assert_eq!(schema["format"], "float");
+    assert_eq!(schema["minimum"], 0.0);
+    assert_eq!(schema.get("maximum"), None);
+
+    let schema = serde_json::to_value(schema_for!(StrictlyNegative<f64>)).unwrap();
+
+    assert_eq!(schema["exclusiveMaximum"], 0.0);
+    assert_eq!(schema.get("minimum"), None);
+
+    let schema = serde_json::to_value(schema_for!(NonNaNFinite<f32>)).unwrap();
+
+    assert_eq!(schema["minimum"], json!(f64::from(f32::MIN)));
+    assert_eq!(schema["maximum"], json!(f64::from(f32::MAX)));
+
+    let schema = serde_json::to_value(schema_for!(NonNaN<f64>)).unwrap();
+
+    for bound in ["minimum", "maximum", "exclusiveMinimum", "exclusiveMaximum"] {
+        assert_eq!(schema.get(bound), None);
+    }
+}
+
+#[test]
+fn test_schemars_description() {
+    let schema = serde_json::to_value(schema_for!(NonZeroNonNaNFinite<f64>)).unwrap();
+
+    assert_eq!(
+        schema["description"],
+        "A floating point number that is not NaN, not infinite, not zero"
+    );
+
+    let schema = serde_json::to_value(schema_for!(NegativeFinite<f64>)).unwrap();
+
+    assert_eq!(
+        schema["description"],
+        "A floating point number that is not NaN, not infinite, with a negative sign"
+    );
+}
+
+#[test]
+fn test_schemars_in_struct() {
+    #[derive(schemars::JsonSchema)]
+    #[allow(dead_code)]
+    struct Measure {
+        value: tf64::StrictlyPositiveFinite,
+        offset: Option<tf32::NonNaN>,
+    }
+
+    let schema = serde_json::to_value(schema_for!(Measure)).unwrap();
+    let properties = &schema["properties"];
+
+    assert_eq!(properties["value"]["exclusiveMinimum"], 0.0);
+    assert_eq!(properties["offset"]["format"], "float");
+    assert_eq!(schema["required"], json!(["value"]));
+    assert!(matches!(properties, Value::Object(_)));
+}
@@ -22,7 +22,10 @@ fn test_serde_deserialize() {
     let a: Result<Positive<f64>, _> = serde_json::from_str(json);
 
     assert!(a.is_err());
-    assert_eq!(a.unwrap_err().to_string(), "Number is negative");
+    assert_eq!(
+        a.unwrap_err().to_string(),
+        "expected a positive number, got -3.0 (Number is negative)"
+    );
 }
 
 #[test]
@@ -46,3 +49,124 @@ fn test_serde_struct() {
 
     assert_eq!(a_json, map);
 }
+
+const TEST_VALUES_F64: [f64; 23] = typed_floats_macros::test_values!(f64);
+const TEST_VALUES_F32: [f32; 23] = typed_floats_macros::test_values!(f32);
+
+#[test]
+fn test_serde_deserialize_errors() {
+    let err = serde_json::from_str::<StrictlyPositiveFinite<f32>>("0.0").unwrap_err();
+    let msg = err.to_string();
+
+    assert!(msg.contains("expected a finite strictly positive number"));
+    assert!(msg.contains("got 0.0"));
+    assert!(msg.contains("Number is zero"));
+
+    let err = serde_json::from_str::<NegativeFinite<f64>>("1e400").unwrap_err();
+
+    // `serde_json` rejects numbers out of range before the type is checked
+    assert!(err.to_string().contains("number out of range"));
+
+    let err = serde_json::from_str::<NonZeroNonNaN<f64>>("-0.0").unwrap_err();
+    let msg = err.to_string();
+
+    assert!(msg.contains("expected a non-zero and non-NaN number"));
+    assert!(msg.contains("got -0.0"));
+}
+
+macro_rules! test_round_trip {
+    ($test:ident, $type:ident) => {
+        #[test]
+        fn $test() {
+            for &value in &TEST_VALUES_F64 {
+                if let Ok(t) = $type::<f64>::new(value) {
+                    if !value.is_finite() {
+                        // JSON can't hold infinities
+                        continue;
+                    }
+
+                    let serialized = serde_json::to_string(&t).unwrap();
+                    assert_eq!(serialized, serde_json::to_string(&value).unwrap());
+
+                    let deserialized: $type<f64> = serde_json::from_str(&serialized).unwrap();
+                    assert_eq!(deserialized.get().to_bits(), value.to_bits());
+                }
+            }
+
+            for &value in &TEST_VALUES_F32 {
+                if let Ok(t) = $type::<f32>::new(value) {
+                    if !value.is_finite() {
+                        continue;
+                    }
+
+                    let serialized = serde_json::to_string(&t).unwrap();
+                    assert_eq!(serialized, serde_json::to_string(&value).unwrap());
+
+                    let deserialized: $type<f32> = serde_json::from_str(&serialized).unwrap();
+                    assert_eq!(deserialized.get().to_bits(), value.to_bits());
+                }
+            }
+
+            // `NaN` is serialized as `null` by `serde_json`
+            assert!(serde_json::from_str::<$type<f64>>("null").is_err());
+            assert!(serde_json::from_str::<$type<f32>>("null").is_err());
+        }
+    };
+}
+
+test_round_trip!(test_serde_non_nan, NonNaN);
+test_round_trip!(test_serde_non_zero_non_nan, NonZeroNonNaN);
+test_round_trip!(test_serde_non_nan_finite, NonNaNFinite);
+test_round_trip!(test_serde_non_zero_non_nan_finite, NonZeroNonNaNFinite);
+test_round_trip!(test_serde_positive, Positive);
+test_round_trip!(test_serde_negative, Negative);
+test_round_trip!(test_serde_positive_finite, PositiveFinite);
+test_round_trip!(test_serde_negative_finite, NegativeFinite);
+test_round_trip!(test_serde_strictly_positive, StrictlyPositive);
+test_round_trip!(test_serde_strictly_negative, StrictlyNegative);
+test_round_trip!(test_serde_strictly_positive_finite, StrictlyPositiveFinite);
+test_round_trip!(test_serde_strictly_negative_finite, StrictlyNegativeFinite);
+
+#[test]
+fn test_serde_as_string() {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct Values {
+        #[serde(with = "typed_floats::serde_helpers::as_string")]
+        non_nan: NonNaN<f64>,
+        #[serde(with = "typed_floats::serde_helpers::as_string")]
+        strictly_positive: StrictlyPositive<f32>,
+        #[serde(with = "typed_floats::serde_helpers::as_string_option")]
+        negative_finite: Option<NegativeFinite<f64>>,
+    }
+
+    for (a, b) in [
+        (f64::MAX, f32::MAX),
+        (f64::MIN_POSITIVE, f32::MIN_POSITIVE),
+        (f64::from_bits(1), f32::from_bits(1)),
+        (f64::NEG_INFINITY, f32::INFINITY),
+        (-0.1, 0.1),
+    ] {
+        let values = Values {
+            non_nan: a.try_into().unwrap(),
+            strictly_positive: b.try_into().unwrap(),
+            negative_finite: NegativeFinite::<f64>::new(-a.abs()).ok(),
+        };
+
+        let json = serde_json::to_string(&values).unwrap();
+        let deserialized: Values = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized.non_nan.get().to_bits(), a.to_bits());
+        assert_eq!(deserialized.strictly_positive.get().to_bits(), b.to_bits());
+        assert_eq!(
+            deserialized.negative_finite.map(|x| x.get().to_bits()),
+            values.negative_finite.map(|x| x.get().to_bits())
+        );
+    }
+
+    let json = r#"{"non_nan":"NaN","strictly_positive":"1.0","negative_finite":null}"#;
+    let err = serde_json::from_str::<Values>(json).err().unwrap();
+
+    assert!(err.to_string().contains("Number is NaN"));
+}
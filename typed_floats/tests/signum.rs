@@ -3,3 +3,18 @@
 use typed_floats::*;
 
 typed_floats_macros::generate_tests_self!(signum);
+
+#[test]
+fn test_signum_types() -> Result<(), InvalidNumber> {
+    let a: StrictlyNegativeFinite = tf64::StrictlyNegative::try_from(-7.0)?.signum();
+    let b: StrictlyPositiveFinite = tf64::Positive::try_from(0.0)?.signum();
+    let c: NonZeroNonNaNFinite = tf64::NonNaN::try_from(-0.0)?.signum();
+    let d: StrictlyNegativeFinite = tf64::NEG_INFINITY.signum();
+
+    assert_eq!(a, -1.0);
+    assert_eq!(b, 1.0);
+    assert_eq!(c, -1.0);
+    assert_eq!(d, -1.0);
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+#![cfg(any(feature = "std", feature = "libm"))]
+
+use typed_floats::*;
+
+macro_rules! values {
+    ($float:ident) => {
+        [
+            $float::NEG_INFINITY,
+            $float::MIN,
+            -core::$float::consts::PI,
+            -1.0,
+            -0.0,
+            0.0,
+            1.0,
+            core::$float::consts::FRAC_PI_2,
+            core::$float::consts::PI,
+            $float::MAX,
+            $float::INFINITY,
+        ]
+    };
+}
+
+const fn assert_same_type<T>(_: &T, _: &T) {}
+
+macro_rules! test_sin_cos {
+    ($test:ident, $type:ident) => {
+        #[test]
+        fn $test() {
+            for value in values!(f64) {
+                if let Ok(x) = $type::<f64>::try_from(value) {
+                    let (sin, cos) = x.sin_cos();
+
+                    // Fails to compile if the types differ from `sin()` and `cos()`
+                    assert_same_type(&sin, &x.sin());
+                    assert_same_type(&cos, &x.cos());
+
+                    assert_eq!(f64::from(sin).to_bits(), f64::from(x.sin()).to_bits());
+                    assert_eq!(f64::from(cos).to_bits(), f64::from(x.cos()).to_bits());
+                }
+            }
+
+            for value in values!(f32) {
+                if let Ok(x) = $type::<f32>::try_from(value) {
+                    let (sin, cos) = x.sin_cos();
+
+                    assert_same_type(&sin, &x.sin());
+                    assert_same_type(&cos, &x.cos());
+
+                    assert_eq!(f32::from(sin).to_bits(), f32::from(x.sin()).to_bits());
+                    assert_eq!(f32::from(cos).to_bits(), f32::from(x.cos()).to_bits());
+                }
+            }
+        }
+    };
+}
+
+test_sin_cos!(non_nan, NonNaN);
+test_sin_cos!(non_zero_non_nan, NonZeroNonNaN);
+test_sin_cos!(non_nan_finite, NonNaNFinite);
+test_sin_cos!(non_zero_non_nan_finite, NonZeroNonNaNFinite);
+test_sin_cos!(positive, Positive);
+test_sin_cos!(negative, Negative);
+test_sin_cos!(positive_finite, PositiveFinite);
+test_sin_cos!(negative_finite, NegativeFinite);
+test_sin_cos!(strictly_positive, StrictlyPositive);
+test_sin_cos!(strictly_negative, StrictlyNegative);
+test_sin_cos!(strictly_positive_finite, StrictlyPositiveFinite);
+test_sin_cos!(strictly_negative_finite, StrictlyNegativeFinite);
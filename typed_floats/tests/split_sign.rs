@@ -0,0 +1,48 @@
+use typed_floats::*;
+
+typed_floats_macros::generate_tests_values!(split_sign, Type, {
+    for &value in &values {
+        let Ok(x) = Type::new(value) else {
+            continue;
+        };
+
+        let (sign, magnitude) = x.split_sign();
+
+        let expected_sign = if value.is_sign_negative() {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        };
+
+        assert_eq!(sign, expected_sign);
+        assert!(magnitude.is_sign_positive());
+        assert_eq!(magnitude, value.abs());
+
+        // The round trip keeps the signed zeros
+        assert_eq!(magnitude.with_sign(sign).get().to_bits(), value.to_bits());
+    }
+});
+
+#[test]
+fn test_split_sign_zeros() {
+    let (sign, magnitude): (Sign, tf64::PositiveFinite) = tf64::NEG_ZERO.split_sign();
+
+    assert_eq!(sign, Sign::Negative);
+    assert_is_positive_zero!(magnitude);
+    assert_is_negative_zero!(magnitude.with_sign(sign));
+
+    let (sign, magnitude) = tf64::ZERO.split_sign();
+
+    assert_eq!(sign, Sign::Positive);
+    assert_is_positive_zero!(magnitude);
+    assert_is_positive_zero!(magnitude.with_sign(sign));
+}
+
+#[test]
+fn test_with_sign_types() {
+    let x: tf32::StrictlyPositiveFinite = 2.0.try_into().unwrap();
+
+    let y: tf32::NonZeroNonNaNFinite = x.with_sign(Sign::Negative);
+
+    assert_eq!(y, -2.0);
+}
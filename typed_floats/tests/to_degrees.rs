@@ -1,3 +1,29 @@
 use typed_floats::*;
 
 typed_floats_macros::generate_tests_self!(to_degrees);
+
+#[test]
+fn test_to_degrees_zero() {
+    // Zero stays exactly zero, so the result accepts zero like the input
+    let zero: Positive = tf64::ZERO.to_degrees();
+
+    assert!(zero.is_positive_zero());
+
+    let neg_zero: NegativeFinite<f32> = tf32::NEG_ZERO;
+    let neg_zero: Negative<f32> = neg_zero.to_degrees();
+
+    assert!(neg_zero.is_negative_zero());
+}
+
+#[test]
+fn test_to_degrees_overflow() {
+    // The types don't bound the magnitude, so a finite input may overflow
+    let max: StrictlyPositive = tf64::MAX.to_degrees();
+
+    assert_eq!(max, f64::INFINITY);
+
+    let one: StrictlyPositiveFinite = 1.0.try_into().unwrap();
+    let res: StrictlyPositive = one.to_degrees();
+
+    assert!(res.is_finite());
+}
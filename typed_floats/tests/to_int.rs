@@ -0,0 +1,96 @@
+use typed_floats::*;
+
+#[test]
+fn test_to_int() {
+    let a: PositiveFinite = 2.0.try_into().unwrap();
+    let b: PositiveFinite = 2.5.try_into().unwrap();
+    let c: NonNaN = (-1.0).try_into().unwrap();
+
+    assert_eq!(u32::try_from(a), Ok(2));
+    assert_eq!(u32::try_from(b), Err(IntegerConversionError::NotAnInteger));
+    assert_eq!(u32::try_from(c), Err(IntegerConversionError::OutOfRange));
+    assert_eq!(i32::try_from(c), Ok(-1));
+
+    let one: tf32::StrictlyPositiveFinite = 1.0.try_into().unwrap();
+    let index: usize = one.try_into().unwrap();
+    assert_eq!(index, 1);
+}
+
+#[test]
+fn test_to_int_zeros() {
+    assert_eq!(u8::try_from(tf64::ZERO), Ok(0));
+    assert_eq!(u8::try_from(tf64::NEG_ZERO), Ok(0));
+    assert_eq!(i8::try_from(tf32::NEG_ZERO), Ok(0));
+}
+
+#[test]
+fn test_to_int_range() {
+    let max: PositiveFinite = 255.0.try_into().unwrap();
+    let over: PositiveFinite = 256.0.try_into().unwrap();
+    let min: NegativeFinite = (-128.0).try_into().unwrap();
+    let under: NegativeFinite = (-129.0).try_into().unwrap();
+
+    assert_eq!(u8::try_from(max), Ok(u8::MAX));
+    assert_eq!(u8::try_from(over), Err(IntegerConversionError::OutOfRange));
+    assert_eq!(i8::try_from(min), Ok(i8::MIN));
+    assert_eq!(i8::try_from(under), Err(IntegerConversionError::OutOfRange));
+
+    // `2^63` is exactly `i64::MAX + 1`
+    let over: PositiveFinite = 9_223_372_036_854_775_808.0.try_into().unwrap();
+    let max: PositiveFinite = 9_223_372_036_854_774_784.0.try_into().unwrap();
+
+    assert_eq!(i64::try_from(over), Err(IntegerConversionError::OutOfRange));
+    assert_eq!(i64::try_from(max), Ok(9_223_372_036_854_774_784));
+    assert_eq!(u64::try_from(over), Ok(1 << 63));
+    assert_eq!(i64::try_from(-over), Ok(i64::MIN));
+
+    assert_eq!(
+        u128::try_from(tf32::MAX),
+        Ok(340_282_346_638_528_859_811_704_183_484_516_925_440)
+    );
+    assert_eq!(
+        i128::try_from(tf32::MAX),
+        Err(IntegerConversionError::OutOfRange)
+    );
+    assert_eq!(
+        u128::try_from(tf64::MAX),
+        Err(IntegerConversionError::OutOfRange)
+    );
+}
+
+#[test]
+fn test_to_int_infinite() {
+    assert_eq!(
+        u64::try_from(tf64::INFINITY),
+        Err(IntegerConversionError::OutOfRange)
+    );
+    assert_eq!(
+        i64::try_from(tf64::NEG_INFINITY),
+        Err(IntegerConversionError::OutOfRange)
+    );
+    assert_eq!(
+        u128::try_from(tf32::INFINITY),
+        Err(IntegerConversionError::OutOfRange)
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_to_int_fractional() {
+    let values: [f64; 23] = typed_floats_macros::test_values!(f64);
+
+    for value in values
+        .iter()
+        .filter_map(|&x| NonNaNFinite::<f64>::new(x).ok())
+    {
+        let res = i32::try_from(value);
+
+        if value.get() < f64::from(i32::MIN) || value.get() > f64::from(i32::MAX) {
+            assert_eq!(res, Err(IntegerConversionError::OutOfRange));
+        } else if value.get().fract() == 0.0 {
+            assert_eq!(res.map(f64::from), Ok(value.get()));
+        } else {
+            assert_eq!(res, Err(IntegerConversionError::NotAnInteger));
+        }
+    }
+}
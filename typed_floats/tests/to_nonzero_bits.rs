@@ -0,0 +1,53 @@
+use core::num::{NonZeroU32, NonZeroU64};
+use typed_floats::*;
+
+macro_rules! test_to_nonzero_bits {
+    ($name:ident, $type:ident) => {
+        #[test]
+        fn $name() {
+            let values: [f64; 23] = typed_floats_macros::test_values!(f64);
+
+            for &value in &values {
+                if let Ok(x) = typed_floats::$type::<f64>::new(value) {
+                    let bits: NonZeroU64 = x.to_nonzero_bits();
+                    assert_eq!(bits.get(), value.to_bits());
+                }
+            }
+
+            let values: [f32; 23] = typed_floats_macros::test_values!(f32);
+
+            for &value in &values {
+                if let Ok(x) = typed_floats::$type::<f32>::new(value) {
+                    let bits: NonZeroU32 = x.to_nonzero_bits();
+                    assert_eq!(bits.get(), value.to_bits());
+                }
+            }
+        }
+    };
+}
+
+test_to_nonzero_bits!(test_non_zero_non_nan, NonZeroNonNaN);
+test_to_nonzero_bits!(test_non_zero_non_nan_finite, NonZeroNonNaNFinite);
+test_to_nonzero_bits!(test_negative, Negative);
+test_to_nonzero_bits!(test_negative_finite, NegativeFinite);
+test_to_nonzero_bits!(test_strictly_positive, StrictlyPositive);
+test_to_nonzero_bits!(test_strictly_negative, StrictlyNegative);
+test_to_nonzero_bits!(test_strictly_positive_finite, StrictlyPositiveFinite);
+test_to_nonzero_bits!(test_strictly_negative_finite, StrictlyNegativeFinite);
+
+#[test]
+fn test_to_nonzero_bits_negative_zero() {
+    let bits = tf64::NEG_ZERO.to_nonzero_bits();
+    assert_eq!(bits.get(), 1 << 63);
+
+    let x: tf32::Negative = (-0.0).try_into().unwrap();
+    assert_eq!(x.to_nonzero_bits().get(), 1 << 31);
+}
+
+#[test]
+fn test_to_nonzero_bits_const() {
+    const X: tf64::StrictlyPositiveFinite = tf64::MIN_POSITIVE;
+    const BITS: NonZeroU64 = X.to_nonzero_bits();
+
+    assert_eq!(BITS.get(), f64::MIN_POSITIVE.to_bits());
+}
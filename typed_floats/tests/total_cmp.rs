@@ -0,0 +1,43 @@
+use core::cmp::Ordering;
+use typed_floats::*;
+
+#[test]
+fn test_total_cmp_zeros() {
+    let neg_zero: tf64::NonNaN = (-0.0).try_into().unwrap();
+    let zero: tf64::NonNaN = 0.0.try_into().unwrap();
+
+    assert_eq!(neg_zero.cmp(&zero), Ordering::Equal);
+    assert_eq!(neg_zero.total_cmp(&zero), Ordering::Less);
+    assert_eq!(zero.total_cmp(&neg_zero), Ordering::Greater);
+}
+
+#[test]
+fn test_total_cmp_values() {
+    let values: [f64; 23] = typed_floats_macros::test_values!(f64);
+
+    for &a in &values {
+        for &b in &values {
+            if let (Ok(x), Ok(y)) = (tf64::NonNaN::new(a), tf64::NonNaN::new(b)) {
+                assert_eq!(x.total_cmp(&y), a.total_cmp(&b));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_total_cmp_sort() {
+    let mut values: Vec<tf32::NonNaNFinite> = [1.0, 0.0, -0.0, -1.0, 0.0, -0.0]
+        .iter()
+        .map(|&x| x.try_into().unwrap())
+        .collect();
+
+    values.sort_by(tf32::NonNaNFinite::total_cmp);
+
+    let bits: Vec<u32> = values.iter().map(tf32::NonNaNFinite::to_bits).collect();
+    let expected: Vec<u32> = [-1.0_f32, -0.0, -0.0, 0.0, 0.0, 1.0]
+        .iter()
+        .map(|x| x.to_bits())
+        .collect();
+
+    assert_eq!(bits, expected);
+}
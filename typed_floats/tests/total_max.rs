@@ -0,0 +1,15 @@
+use typed_floats::*;
+
+typed_floats_macros::generate_tests_self_rhs!(total_max);
+
+#[test]
+fn test_total_max_zeros() {
+    let neg_zero: Negative = tf64::NEG_ZERO.into();
+    let zero: Positive = tf64::ZERO.into();
+
+    let a: Positive = TotalMax::total_max(neg_zero, zero);
+    let b: Positive = TotalMax::total_max(zero, neg_zero);
+
+    assert_is_positive_zero!(a);
+    assert_is_positive_zero!(b);
+}
@@ -0,0 +1,15 @@
+use typed_floats::*;
+
+typed_floats_macros::generate_tests_self_rhs!(total_min);
+
+#[test]
+fn test_total_min_zeros() {
+    let neg_zero: Negative = tf64::NEG_ZERO.into();
+    let zero: Positive = tf64::ZERO.into();
+
+    let a: Negative = TotalMin::total_min(neg_zero, zero);
+    let b: Negative = TotalMin::total_min(zero, neg_zero);
+
+    assert_is_negative_zero!(a);
+    assert_is_negative_zero!(b);
+}
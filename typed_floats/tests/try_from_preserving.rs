@@ -0,0 +1,57 @@
+use typed_floats::*;
+
+typed_floats_macros::generate_tests_values!(try_from_preserving, Type, {
+    for &value in &values {
+        let result = Type::try_from_preserving(value);
+
+        if value.is_nan() {
+            assert_eq!(result, Err(PreservingError::NaN(value.to_bits())));
+        } else {
+            assert_eq!(result, Type::new(value).map_err(PreservingError::from));
+        }
+    }
+});
+
+#[test]
+fn test_nan_payload() {
+    // A signaling `NaN` with a payload, and a negative quiet one
+    let signaling = f64::from_bits(0x7ff0_0000_dead_beef);
+    let quiet = f64::from_bits(0xfff8_0000_0000_0042);
+
+    assert_eq!(
+        tf64::NonNaN::try_from_preserving(signaling),
+        Err(PreservingError::NaN(0x7ff0_0000_dead_beef))
+    );
+    assert_eq!(
+        tf64::Positive::try_from_preserving(quiet),
+        Err(PreservingError::NaN(0xfff8_0000_0000_0042))
+    );
+
+    let signaling = f32::from_bits(0x7f80_beef);
+    assert_eq!(
+        tf32::StrictlyPositiveFinite::try_from_preserving(signaling),
+        Err(PreservingError::NaN(0x7f80_beef))
+    );
+
+    // The other constructors don't keep the bits
+    assert_eq!(tf64::NonNaN::new(quiet), Err(InvalidNumber::NaN));
+
+    assert_eq!(
+        PreservingError::NaN(0x7ff0_0000_dead_beef_u64).to_string(),
+        "Number is NaN (bits: 0x7ff00000deadbeef)"
+    );
+    assert_eq!(
+        PreservingError::NaN(0x7f80_beef_u32).to_string(),
+        "Number is NaN (bits: 0x7f80beef)"
+    );
+
+    // The other errors are the same as `new`
+    assert_eq!(
+        tf32::Negative::try_from_preserving(1.0),
+        Err(PreservingError::InvalidNumber(InvalidNumber::Positive))
+    );
+    assert_eq!(
+        PreservingError::<u32>::InvalidNumber(InvalidNumber::Zero).to_string(),
+        "Number is zero"
+    );
+}
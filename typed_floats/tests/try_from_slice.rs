@@ -0,0 +1,79 @@
+use typed_floats::*;
+
+typed_floats_macros::generate_tests_values!(try_from_slice, Type, {
+    assert_eq!(core::mem::size_of::<Type>(), core::mem::size_of::<Float>());
+    assert_eq!(
+        core::mem::align_of::<Type>(),
+        core::mem::align_of::<Float>()
+    );
+
+    let first_invalid = values.iter().position(|&x| Type::new(x).is_err());
+
+    match Type::try_from_slice(&values) {
+        Ok(typed) => {
+            assert_eq!(first_invalid, None);
+            assert_eq!(typed.len(), values.len());
+        }
+        Err((index, error)) => {
+            assert_eq!(Some(index), first_invalid);
+            assert_eq!(Type::new(values[index]), Err(error));
+        }
+    }
+
+    let valid = values
+        .iter()
+        .copied()
+        .filter(|&x| Type::new(x).is_ok())
+        .collect::<Vec<_>>();
+
+    let typed = Type::try_from_slice(&valid).unwrap();
+
+    assert_eq!(typed.len(), valid.len());
+    for (t, v) in typed.iter().zip(valid.iter()) {
+        assert_eq!(t.get().to_bits(), v.to_bits());
+    }
+});
+
+#[test]
+fn test_try_from_slice_mixed() {
+    let values = [1.0, 2.0, f64::INFINITY, -3.0, 4.0];
+
+    assert_eq!(
+        tf64::PositiveFinite::try_from_slice(&values),
+        Err((2, InvalidNumber::Infinite))
+    );
+    assert_eq!(
+        tf64::Positive::try_from_slice(&values),
+        Err((3, InvalidNumber::Negative))
+    );
+    assert_eq!(
+        tf64::PositiveFinite::try_from_slice(&values[..2]).unwrap(),
+        &[1.0, 2.0]
+    );
+
+    assert_eq!(tf32::StrictlyPositive::try_from_slice(&[]), Ok(&[][..]));
+}
+
+#[test]
+fn test_from_slice_unchecked_round_trip() {
+    let values = [1.0, f32::MIN_POSITIVE, f32::MAX, f32::INFINITY];
+
+    let typed = unsafe { tf32::StrictlyPositive::from_slice_unchecked(&values) };
+
+    assert_eq!(
+        typed,
+        tf32::StrictlyPositive::try_from_slice(&values).unwrap()
+    );
+    assert_eq!(typed.as_ptr().cast::<f32>(), values.as_ptr());
+
+    let primitives = tf32::StrictlyPositive::as_primitive_slice(typed);
+
+    assert_eq!(primitives, &values);
+    assert_eq!(primitives.as_ptr(), values.as_ptr());
+
+    let typed = tf64::NegativeFinite::try_from_slice(&[-0.0, -1.0, f64::MIN]).unwrap();
+    let primitives = tf64::NegativeFinite::as_primitive_slice(typed);
+
+    assert_is_negative_zero!(primitives[0]);
+    assert_eq!(primitives[1..], [-1.0, f64::MIN]);
+}
@@ -0,0 +1,41 @@
+use typed_floats::*;
+
+#[test]
+fn test_try_set() {
+    let mut x: tf64::StrictlyPositive = 1.0.try_into().unwrap();
+
+    assert_eq!(x.try_set(2.5), Ok(()));
+    assert_eq!(x, 2.5);
+
+    assert_eq!(x.try_set(0.0), Err(InvalidNumber::Zero));
+    assert_eq!(x, 2.5);
+
+    assert_eq!(x.try_set(-1.0), Err(InvalidNumber::Negative));
+    assert_eq!(x, 2.5);
+
+    assert_eq!(x.try_set(f64::NAN), Err(InvalidNumber::NaN));
+    assert_eq!(x, 2.5);
+
+    assert_eq!(x.try_set(f64::INFINITY), Ok(()));
+    assert_eq!(x, f64::INFINITY);
+}
+
+#[test]
+fn test_try_set_all_values() {
+    let values: [f32; 23] = typed_floats_macros::test_values!(f32);
+
+    let mut x: tf32::NegativeFinite = (-1.0).try_into().unwrap();
+
+    for &value in &values {
+        let before = x;
+        let result = x.try_set(value);
+
+        assert_eq!(result.is_ok(), tf32::NegativeFinite::new(value).is_ok());
+
+        if result.is_ok() {
+            assert_eq!(x.get().to_bits(), value.to_bits());
+        } else {
+            assert_eq!(x.get().to_bits(), before.get().to_bits());
+        }
+    }
+}
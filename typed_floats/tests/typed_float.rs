@@ -0,0 +1,100 @@
+use typed_floats::*;
+
+fn check<T: TypedFloat>(value: T::Content)
+where
+    T::Content: Into<f64>,
+{
+    let Some(x) = T::try_new(value) else {
+        assert!(T::new(value).is_err());
+        return;
+    };
+
+    assert_eq!(T::new(value), Ok(x));
+
+    let as_f64: f64 = x.get().into();
+
+    assert!(!x.is_nan());
+    assert_eq!(x.is_infinite(), as_f64.is_infinite());
+    assert_eq!(x.is_finite(), as_f64.is_finite());
+    assert_eq!(x.is_sign_positive(), as_f64.is_sign_positive());
+    assert_eq!(x.is_sign_negative(), as_f64.is_sign_negative());
+    assert_eq!(
+        x.is_positive_zero(),
+        as_f64 == 0.0 && as_f64.is_sign_positive()
+    );
+    assert_eq!(
+        x.is_negative_zero(),
+        as_f64 == 0.0 && as_f64.is_sign_negative()
+    );
+
+    assert_eq!(x.into_f64().get().to_bits(), as_f64.to_bits());
+    assert_eq!(x.try_into_f32(), <T::F32 as TypedFloat>::new(as_f64 as f32));
+}
+
+macro_rules! test_typed_float {
+    ($type:ident) => {
+        #[test]
+        #[allow(non_snake_case)]
+        fn $type() {
+            let values_f64: [f64; 23] = typed_floats_macros::test_values!(f64);
+
+            for &value in &values_f64 {
+                check::<$type<f64>>(value);
+
+                let x = $type::<f64>::try_new(value);
+                assert_eq!(x.map(|x| x.is_normal()), x.map(|_| value.is_normal()));
+            }
+
+            let values_f32: [f32; 23] = typed_floats_macros::test_values!(f32);
+
+            for &value in &values_f32 {
+                check::<$type<f32>>(value);
+
+                // A subnormal `f32` is a normal `f64`, so it is checked without the conversion
+                let x = $type::<f32>::try_new(value);
+                assert_eq!(x.map(|x| x.is_normal()), x.map(|_| value.is_normal()));
+                assert_eq!(x.map(|x| x.is_subnormal()), x.map(|_| value.is_subnormal()));
+                assert_eq!(x.map(|x| x.classify()), x.map(|_| value.classify()));
+            }
+        }
+    };
+}
+
+mod typed_float {
+    use super::*;
+
+    test_typed_float!(NonNaN);
+    test_typed_float!(NonNaNFinite);
+    test_typed_float!(NonZeroNonNaN);
+    test_typed_float!(NonZeroNonNaNFinite);
+    test_typed_float!(StrictlyPositive);
+    test_typed_float!(StrictlyNegative);
+    test_typed_float!(Positive);
+    test_typed_float!(Negative);
+    test_typed_float!(StrictlyPositiveFinite);
+    test_typed_float!(StrictlyNegativeFinite);
+    test_typed_float!(PositiveFinite);
+    test_typed_float!(NegativeFinite);
+}
+
+#[test]
+fn test_precision_conversions() {
+    let x: NonNaN<f32> = 0.1.try_into().unwrap();
+    let y: NonNaN<f64> = x.into_f64();
+    assert_eq!(y, f64::from(0.1_f32));
+    assert_eq!(y.try_into_f32(), Ok(x));
+    assert_eq!(x.try_into_f32(), Ok(x));
+
+    // The overflow is only an error for the finite types
+    let x: NonNaN<f64> = 1e300.try_into().unwrap();
+    assert_eq!(x.try_into_f32(), Ok(tf32::INFINITY.into()));
+
+    let x: StrictlyPositiveFinite<f64> = 1e300.try_into().unwrap();
+    assert_eq!(x.try_into_f32(), Err(InvalidNumber::Infinite));
+
+    let x: StrictlyNegative<f64> = (-1e-300).try_into().unwrap();
+    assert_eq!(x.try_into_f32(), Err(InvalidNumber::Zero));
+
+    let x: StrictlyPositiveFinite<f64> = f64::from(f32::MAX).try_into().unwrap();
+    assert_eq!(x.try_into_f32(), Ok(tf32::MAX));
+}
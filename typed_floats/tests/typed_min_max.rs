@@ -0,0 +1,52 @@
+use typed_floats::*;
+
+#[test]
+fn test_typed_min_max() {
+    let values: [f64; 23] = typed_floats_macros::test_values!(f64);
+
+    let finite = values
+        .iter()
+        .filter_map(|&x| tf64::NonNaNFinite::new(x).ok())
+        .collect::<Vec<_>>();
+
+    let min = finite.iter().copied().typed_min().unwrap();
+    let max = finite.iter().copied().typed_max().unwrap();
+
+    for x in &finite {
+        assert!(min <= *x);
+        assert!(max >= *x);
+    }
+
+    assert_eq!(min, f64::MIN);
+    assert_eq!(max, f64::MAX);
+
+    let values: [f32; 23] = typed_floats_macros::test_values!(f32);
+
+    let finite = values
+        .iter()
+        .filter_map(|&x| tf32::StrictlyPositiveFinite::new(x).ok())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        finite.iter().copied().typed_min().unwrap(),
+        f32::from_bits(1)
+    );
+    assert_eq!(finite.iter().copied().typed_max().unwrap(), f32::MAX);
+}
+
+#[test]
+fn test_typed_min_max_zeros() {
+    let zeros = [0.0, -0.0, 0.0]
+        .iter()
+        .map(|&x| tf32::NonNaNFinite::new(x).unwrap())
+        .collect::<Vec<_>>();
+
+    assert_is_negative_zero!(zeros.iter().copied().typed_min().unwrap());
+    assert_is_positive_zero!(zeros.iter().copied().typed_max().unwrap());
+}
+
+#[test]
+fn test_typed_min_max_empty() {
+    assert_eq!(Vec::<tf64::NonNaN>::new().into_iter().typed_min(), None);
+    assert_eq!(Vec::<tf32::Negative>::new().into_iter().typed_max(), None);
+}
@@ -0,0 +1,49 @@
+use typed_floats::*;
+
+typed_floats_macros::generate_tests_values!(
+    ulp,
+    Type in [
+        NonZeroNonNaNFinite,
+        StrictlyPositiveFinite,
+        StrictlyNegativeFinite
+    ],
+    {
+        for &value in &values {
+            let Ok(x) = Type::new(value) else {
+                continue;
+            };
+
+            // This will panic if the result isn't compatible with the return type
+            let ulp = x.ulp();
+
+            assert!(ulp.get() > 0.0);
+            assert!(ulp.get().is_finite());
+
+            let abs = value.abs();
+            if abs < Float::MAX {
+                assert_eq!(abs + ulp.get(), Float::from_bits(abs.to_bits() + 1));
+            } else {
+                assert_eq!(abs - ulp.get(), Float::from_bits(abs.to_bits() - 1));
+            }
+        }
+    }
+);
+
+#[test]
+fn test_ulp_values() {
+    let one: tf64::StrictlyPositiveFinite = 1.0.try_into().unwrap();
+    assert_eq!(one.ulp(), f64::EPSILON);
+
+    let minus_one: tf64::StrictlyNegativeFinite = (-1.0).try_into().unwrap();
+    assert_eq!(minus_one.ulp(), f64::EPSILON);
+
+    // The gap below a power of two is smaller, but `ulp` is the gap above the magnitude
+    let two: tf64::StrictlyPositiveFinite = 2.0.try_into().unwrap();
+    assert_eq!(two.ulp(), 2.0 * f64::EPSILON);
+
+    assert_eq!(tf64::MIN_POSITIVE.ulp(), f64::from_bits(1));
+    assert_eq!(
+        tf64::MAX.ulp(),
+        f64::MAX - f64::from_bits(f64::MAX.to_bits() - 1)
+    );
+}
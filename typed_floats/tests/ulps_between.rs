@@ -0,0 +1,68 @@
+use typed_floats::*;
+
+fn next_up(value: f64) -> f64 {
+    if value == 0.0 {
+        f64::from_bits(1)
+    } else if value > 0.0 {
+        f64::from_bits(value.to_bits() + 1)
+    } else {
+        f64::from_bits(value.to_bits() - 1)
+    }
+}
+
+#[test]
+fn test_ulps_between_same_value() {
+    let values: [f64; 23] = typed_floats_macros::test_values!(f64);
+
+    for &value in &values {
+        if let Ok(x) = tf64::NonNaN::new(value) {
+            assert_eq!(x.ulps_between(x), 0);
+        }
+    }
+
+    let values: [f32; 23] = typed_floats_macros::test_values!(f32);
+
+    for &value in &values {
+        if let Ok(x) = tf32::NonNaN::new(value) {
+            assert_eq!(x.ulps_between(x), 0);
+        }
+    }
+}
+
+#[test]
+fn test_ulps_between_adjacent() {
+    let values: [f64; 23] = typed_floats_macros::test_values!(f64);
+
+    for &value in &values {
+        if value.is_nan() || value == f64::INFINITY {
+            continue;
+        }
+
+        let (Ok(x), Ok(y)) = (tf64::NonNaN::new(value), tf64::NonNaN::new(next_up(value))) else {
+            panic!("{value} and its next value should be valid");
+        };
+
+        assert_eq!(x.ulps_between(y), 1, "{value}");
+        assert_eq!(y.ulps_between(x), -1, "{value}");
+    }
+}
+
+#[test]
+fn test_ulps_between_types() {
+    let one: tf32::StrictlyPositiveFinite = 1.0.try_into().unwrap();
+    let two: tf32::PositiveFinite = 2.0.try_into().unwrap();
+    let neg_one: tf32::StrictlyNegative = (-1.0).try_into().unwrap();
+
+    // There are `2^23` floats in `[1, 2)`
+    assert_eq!(one.ulps_between(two), 1 << 23);
+    assert_eq!(neg_one.ulps_between(one), 2 * i64::from(1.0_f32.to_bits()));
+    assert_eq!(tf32::NEG_ZERO.ulps_between(tf32::ZERO), 0);
+    assert_eq!(
+        tf32::NEG_INFINITY.ulps_between(tf32::INFINITY),
+        2 * 0x7f80_0000
+    );
+
+    assert_eq!(tf64::NEG_INFINITY.ulps_between(tf64::INFINITY), i64::MAX);
+    assert_eq!(tf64::INFINITY.ulps_between(tf64::NEG_INFINITY), i64::MIN);
+    assert_eq!(tf64::MIN.ulps_between(tf64::MAX), i64::MAX);
+}
@@ -0,0 +1,144 @@
+use typed_floats::*;
+
+typed_floats_macros::generate_tests_values!(unit_interval, {
+    for &value in &values {
+        let res = UnitInterval::<Float>::new(value);
+        let valid = (0.0..=1.0).contains(&value) && value.is_sign_positive();
+
+        assert_eq!(res.is_ok(), valid);
+        assert_eq!(
+            UnitInterval::<Float>::try_new(value),
+            res.as_ref().ok().copied()
+        );
+        assert_eq!(UnitInterval::<Float>::try_from(value), res);
+
+        if let Ok(typed) = res {
+            assert_eq!(typed, value);
+            assert_eq!(typed.get().to_bits(), value.to_bits());
+            assert_eq!(Float::from(typed).to_bits(), value.to_bits());
+            assert_eq!(PositiveFinite::<Float>::from(typed), value);
+            assert_eq!(typed.complement(), 1.0 - value);
+        }
+
+        if let Ok(positive) = PositiveFinite::<Float>::new(value) {
+            assert_eq!(UnitInterval::<Float>::try_from(positive), res);
+        }
+    }
+});
+
+#[test]
+fn test_bounds() {
+    assert_eq!(UnitInterval::<f64>::new(0.0), Ok(UnitInterval::<f64>::ZERO));
+    assert_eq!(UnitInterval::<f64>::new(1.0), Ok(UnitInterval::<f64>::ONE));
+    assert!(UnitInterval::<f64>::ZERO.is_zero());
+    assert!(!UnitInterval::<f64>::ONE.is_zero());
+
+    assert_eq!(
+        UnitInterval::<f64>::new(-0.0),
+        Err(RangeError::InvalidNumber(InvalidNumber::Negative))
+    );
+    assert_eq!(
+        UnitInterval::<f64>::new(-1.0),
+        Err(RangeError::InvalidNumber(InvalidNumber::Negative))
+    );
+    assert_eq!(
+        UnitInterval::<f64>::new(f64::NAN),
+        Err(RangeError::InvalidNumber(InvalidNumber::NaN))
+    );
+    assert_eq!(
+        UnitInterval::<f64>::new(1.0 + f64::EPSILON),
+        Err(RangeError::OutOfRange)
+    );
+    assert_eq!(
+        UnitInterval::<f64>::new(f64::INFINITY),
+        Err(RangeError::OutOfRange)
+    );
+    assert_eq!(
+        UnitInterval::<f32>::new(1.0 + f32::EPSILON),
+        Err(RangeError::OutOfRange)
+    );
+    assert_eq!(UnitInterval::<f32>::try_new(-0.0), None);
+
+    assert_eq!(RangeError::OutOfRange.to_string(), "Number is out of range");
+    assert_eq!(
+        RangeError::from(InvalidNumber::NaN).to_string(),
+        "Number is NaN"
+    );
+}
+
+#[test]
+fn test_mul_keeps_the_type() {
+    let half = UnitInterval::<f64>::new(0.5).unwrap();
+
+    let quarter: UnitInterval<f64> = half * half;
+    assert_eq!(quarter, 0.25);
+
+    let mut value = UnitInterval::<f64>::ONE;
+    value *= half;
+    assert_eq!(value, half);
+
+    // The product underflows to `+0.0`
+    let tiny = UnitInterval::<f64>::new(f64::MIN_POSITIVE).unwrap();
+    assert!((tiny * tiny).is_zero());
+}
+
+#[test]
+fn test_complement() {
+    assert_eq!(
+        UnitInterval::<f64>::ZERO.complement(),
+        UnitInterval::<f64>::ONE
+    );
+    assert!(UnitInterval::<f64>::ONE.complement().is_zero());
+    assert_eq!(
+        UnitInterval::<f32>::new(0.25).unwrap().complement(),
+        UnitInterval::<f32>::new(0.75).unwrap()
+    );
+}
+
+#[test]
+fn test_ord() {
+    let mut values = [0.5, 1.0, 0.0, 0.25].map(|value| UnitInterval::<f64>::new(value).unwrap());
+    values.sort();
+
+    assert_eq!(
+        values,
+        [0.0, 0.25, 0.5, 1.0].map(|value| UnitInterval::<f64>::new(value).unwrap())
+    );
+    assert_eq!(
+        UnitInterval::<f64>::from(UnitInterval::<f32>::new(0.5).unwrap()),
+        UnitInterval::<f64>::new(0.5).unwrap()
+    );
+}
+
+typed_floats_macros::generate_tests_values!(to_unit_interval_clamped, {
+    for &value in &values {
+        let Ok(x) = NonNaN::<Float>::new(value) else {
+            continue;
+        };
+
+        let clamped = x.to_unit_interval_clamped();
+
+        if let Ok(expected) = UnitInterval::<Float>::new(value) {
+            assert_eq!(clamped, expected);
+        } else if value > 1.0 {
+            assert_eq!(clamped, UnitInterval::<Float>::ONE);
+        } else {
+            assert_eq!(clamped.get().to_bits(), (0.0 as Float).to_bits());
+        }
+    }
+});
+
+#[test]
+fn test_to_unit_interval_clamped_values() {
+    let clamp = |x: f64| NonNaN::<f64>::new(x).unwrap().to_unit_interval_clamped();
+
+    assert_eq!(clamp(1.5), 1.0);
+    assert_eq!(clamp(-0.2), 0.0);
+    assert_eq!(clamp(0.3), 0.3);
+    assert_eq!(clamp(1.0), 1.0);
+    assert_eq!(clamp(f64::INFINITY), UnitInterval::<f64>::ONE);
+    assert_eq!(clamp(f64::NEG_INFINITY), UnitInterval::<f64>::ZERO);
+
+    // The result is never `-0.0`
+    assert!(clamp(-0.0).get().is_sign_positive());
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "units")]
+
+use typed_floats::units::{BasisPoints, Percent};
+use typed_floats::*;
+
+#[test]
+fn test_percent_as_ratio() -> Result<(), InvalidNumber> {
+    let ratio: PositiveFinite = Percent::try_from(50.0)?.as_ratio();
+    assert_eq!(ratio, 0.5);
+
+    let ratio: PositiveFinite = Percent::try_from(250.0)?.as_ratio();
+    assert_eq!(ratio, 2.5);
+
+    // The smallest percent is rounded to zero
+    let ratio: PositiveFinite = Percent::new(f64::from_bits(1).try_into()?).as_ratio();
+    assert_is_positive_zero!(ratio);
+
+    Ok(())
+}
+
+#[test]
+fn test_basis_points_as_ratio() -> Result<(), InvalidNumber> {
+    let ratio: PositiveFinite = BasisPoints::try_from(25.0)?.as_ratio();
+    assert_eq!(ratio, 0.0025);
+
+    Ok(())
+}
+
+#[test]
+fn test_invalid() {
+    assert_eq!(Percent::try_from(0.0), Err(InvalidNumber::Zero));
+    assert_eq!(Percent::try_from(-1.0), Err(InvalidNumber::Negative));
+    assert_eq!(BasisPoints::try_from(f64::NAN), Err(InvalidNumber::NaN));
+    assert_eq!(
+        BasisPoints::try_from(f64::INFINITY),
+        Err(InvalidNumber::Infinite)
+    );
+}
+
+#[test]
+fn test_conversions() -> Result<(), InvalidNumber> {
+    let percent = Percent::try_from(1.25)?;
+
+    let basis_points = BasisPoints::try_from(percent)?;
+    assert_eq!(basis_points.get(), 125.0);
+    assert_eq!(Percent::try_from(basis_points)?, percent);
+
+    let value: StrictlyPositiveFinite = percent.into();
+    assert_eq!(value, 1.25);
+    assert_eq!(Percent::from(value), percent);
+
+    // The conversions may overflow or be rounded to zero
+    assert_eq!(
+        BasisPoints::try_from(Percent::from(tf64::MAX)),
+        Err(InvalidNumber::Infinite)
+    );
+    assert_eq!(
+        Percent::try_from(BasisPoints::new(f64::from_bits(1).try_into()?)),
+        Err(InvalidNumber::Zero)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_add() -> Result<(), InvalidNumber> {
+    let a = Percent::try_from(50.0)?;
+    let b = Percent::try_from(25.0)?;
+
+    let c: Percent = a.checked_add(b)?;
+    assert_eq!(c, Percent::try_from(75.0)?);
+
+    let d: Percent = a.saturating_add(b);
+    assert_eq!(d, c);
+
+    let max = Percent::from(tf64::MAX);
+    assert_eq!(max.checked_add(max), Err(InvalidNumber::Infinite));
+    assert_eq!(max.saturating_add(max), max);
+
+    let a = BasisPoints::try_from(10.0)?;
+    let b: BasisPoints = a.checked_add(a)?;
+    assert_eq!(b.get(), 20.0);
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "simd")]
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use typed_floats::*;
+
+// The chunked path must agree with the scalar one, so the slices mix valid values
+// with a few edge values, at random positions and with random lengths around the chunk size.
+
+typed_floats_macros::generate_tests_values!(validate_slice, Type, {
+    let valid: Vec<Float> = values
+        .iter()
+        .copied()
+        .filter(|&x| Type::new(x).is_ok())
+        .collect();
+
+    let mut rng = StdRng::seed_from_u64(42);
+
+    for _ in 0..1000 {
+        let len = rng.gen_range(0..100);
+        let mut slice: Vec<Float> = (0..len)
+            .map(|_| valid[rng.gen_range(0..valid.len())])
+            .collect();
+
+        // Insert some edge values, valid or not
+        for _ in 0..rng.gen_range(0..3) {
+            if len > 0 {
+                slice[rng.gen_range(0..len)] = values[rng.gen_range(0..values.len())];
+            }
+        }
+
+        let expected = Type::try_from_slice(&slice).map(|_| ());
+        let result = Type::validate_slice(&slice);
+
+        assert_eq!(result, expected, "{:?}", slice);
+    }
+});
+
+#[test]
+fn test_validate_large_slice() {
+    let mut values = vec![1.0; 1_000_003];
+    assert_eq!(tf64::StrictlyPositive::validate_slice(&values), Ok(()));
+
+    // Only the first invalid element is reported
+    values[1_000_002] = -1.0;
+    values[500_001] = f64::NAN;
+    values[500_007] = 0.0;
+    assert_eq!(
+        tf64::StrictlyPositive::validate_slice(&values),
+        Err((500_001, InvalidNumber::NaN))
+    );
+}
@@ -2,7 +2,10 @@ use quote::quote;
 use syn::Ident;
 
 use crate::impl_self::get_impl_self;
-use crate::{get_definitions, get_impl_self_rhs, FloatDefinition, ReturnTypeDefinition};
+use crate::{
+    get_definitions, get_impl_self_rhs, get_impl_ternary, FloatDefinition, ReturnTypeDefinition,
+    F32, F64,
+};
 
 fn test_op_checks(
     float: &FloatDefinition,
@@ -54,7 +57,43 @@ fn test_op_checks(
     res
 }
 
+fn test_op_result_checks(
+    op_name: &str,
+    result_type: &ReturnTypeDefinition,
+    var: &proc_macro2::Ident,
+) -> proc_macro2::TokenStream {
+    let mut res = proc_macro2::TokenStream::new();
+
+    // Even if the result type is not as strict as possible, every value must be valid for it
+    if let ReturnTypeDefinition::FloatDefinition(def) = result_type {
+        let full_type = def.full_type_ident();
+
+        res.extend(quote! {
+            for x in #var.iter() {
+                let valid = <#full_type>::try_from(*x);
+                assert!(valid.is_ok(), "{} generated {:?} but it is not a valid {}: {:?}", #op_name, x, stringify!(#full_type), valid);
+            }
+        });
+    }
+
+    res
+}
+
 pub(crate) fn test_values(float_type: &Ident) -> proc_macro2::TokenStream {
+    if float_type == "f16" || float_type == "bf16" {
+        return test_values_half(float_type);
+    }
+
+    // A subnormal number larger than the smallest one, as `1.0e-308` would be rounded to zero in `f32`
+    let subnormal = if float_type == "f32" {
+        quote! { 1.0e-40 }
+    } else {
+        quote! { 1.0e-308 }
+    };
+
+    // The smallest subnormal number, `from_bits(1)`, computed without the non-`const` `from_bits`
+    let min_subnormal = quote! { (core::#float_type::MIN_POSITIVE * core::#float_type::EPSILON) };
+
     quote! {
         [
             core::#float_type::NAN,
@@ -66,10 +105,12 @@ pub(crate) fn test_values(float_type: &Ident) -> proc_macro2::TokenStream {
             -core::#float_type::consts::FRAC_PI_2,
             -1.0,
             -core::#float_type::MIN_POSITIVE,
-            -1.0e-308,
+            -#subnormal,
+            -#min_subnormal,
             -0.0,
             0.0,
-            1.0e-308,
+            #min_subnormal,
+            #subnormal,
             core::#float_type::MIN_POSITIVE,
             1.0,
             core::#float_type::consts::FRAC_PI_2,
@@ -82,11 +123,56 @@ pub(crate) fn test_values(float_type: &Ident) -> proc_macro2::TokenStream {
     }
 }
 
+/// The same values as `test_values` for the `half` types.
+/// `half::f16` and `half::bf16` have no `const` negation, so the sign bit is set manually.
+fn test_values_half(float_type: &Ident) -> proc_macro2::TokenStream {
+    let neg = |value: proc_macro2::TokenStream| {
+        quote! { half::#float_type::from_bits(#value.to_bits() | 0x8000) }
+    };
+
+    let neg_pi = neg(quote! { half::#float_type::PI });
+    let neg_e = neg(quote! { half::#float_type::E });
+    let neg_frac_pi_2 = neg(quote! { half::#float_type::FRAC_PI_2 });
+    let neg_min_positive = neg(quote! { half::#float_type::MIN_POSITIVE });
+    let neg_min_positive_subnormal = neg(quote! { half::#float_type::MIN_POSITIVE_SUBNORMAL });
+    let max_subnormal =
+        quote! { half::#float_type::from_bits(half::#float_type::MIN_POSITIVE.to_bits() - 1) };
+    let neg_max_subnormal = neg(max_subnormal.clone());
+
+    quote! {
+        [
+            half::#float_type::NAN,
+            half::#float_type::NEG_INFINITY,
+            half::#float_type::MIN,
+            #neg_pi,
+            #neg_e,
+            half::#float_type::from_f32_const(-2.0),
+            #neg_frac_pi_2,
+            half::#float_type::NEG_ONE,
+            #neg_min_positive,
+            #neg_max_subnormal,
+            #neg_min_positive_subnormal,
+            half::#float_type::NEG_ZERO,
+            half::#float_type::ZERO,
+            half::#float_type::MIN_POSITIVE_SUBNORMAL,
+            #max_subnormal,
+            half::#float_type::MIN_POSITIVE,
+            half::#float_type::ONE,
+            half::#float_type::FRAC_PI_2,
+            half::#float_type::from_f32_const(2.0),
+            half::#float_type::E,
+            half::#float_type::PI,
+            half::#float_type::MAX,
+            half::#float_type::INFINITY,
+        ]
+    }
+}
+
 pub(crate) fn get_test_values(float_type: &Ident) -> proc_macro2::TokenStream {
     let values = test_values(float_type);
 
     quote! {
-        let values: [#float_type; 21] = #values;
+        let values: [#float_type; 23] = #values;
 
         for i in 1..values.len() {
             let value = values[i];
@@ -136,7 +222,7 @@ pub fn generate_tests_self(float_type: &'static str, filter: &str) -> proc_macro
             });
 
             let test = &op.get_test("num_a");
-            let test_float = &op.get_test("a");
+            let test_float = &op.get_test_primitive("a", &float_type);
 
             let get = match &op.get_result(float, &floats_f64) {
                 ReturnTypeDefinition::NativeFloat => quote! { res },
@@ -167,11 +253,17 @@ pub fn generate_tests_self(float_type: &'static str, filter: &str) -> proc_macro
                 #vals.push(as_float);
             });
 
+            let result_type = op.get_result(float, &floats_f64);
+            let result_checks = test_op_result_checks(op.display, &result_type, &vals);
+
+            check_ops.extend(quote! {
+                #result_checks
+            });
+
             if op.skip_check_return_type_strictness {
                 continue;
             }
 
-            let result_type = op.get_result(float, &floats_f64);
             let checks = test_op_checks(float, op.display, &result_type, &vals);
 
             check_ops.extend(quote! {
@@ -185,8 +277,8 @@ pub fn generate_tests_self(float_type: &'static str, filter: &str) -> proc_macro
             {
                 #init_test_ops
 
-                for a in values.iter() {
-                    let a_float = <#full_type>::try_from(*a);
+                for &a in values.iter() {
+                    let a_float = <#full_type>::try_from(a);
 
                     if let Ok(num_a) = a_float {
                         println!("compute with a = {:?}", num_a);
@@ -212,6 +304,195 @@ pub fn generate_tests_self(float_type: &'static str, filter: &str) -> proc_macro
     }
 }
 
+/// A type alias bound in turn to each of the given types, e.g. `Type in [NonNaN, Positive]`,
+/// or to each of the 12 types if no list is given.
+struct Binding {
+    alias: Ident,
+    types: Vec<Ident>,
+}
+
+/// The input of `generate_tests_values!`: the name of the tests, the bindings and the body.
+pub struct TestsValues {
+    name: Ident,
+    bindings: Vec<Binding>,
+    body: proc_macro2::TokenStream,
+}
+
+impl syn::parse::Parse for TestsValues {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        let mut bindings = Vec::new();
+
+        loop {
+            input.parse::<syn::Token![,]>()?;
+
+            if input.peek(syn::token::Brace) {
+                let content;
+                syn::braced!(content in input);
+
+                return Ok(Self {
+                    name,
+                    bindings,
+                    body: content.parse()?,
+                });
+            }
+
+            let alias = input.parse()?;
+
+            let types = if input.peek(syn::Token![in]) {
+                input.parse::<syn::Token![in]>()?;
+
+                let content;
+                syn::bracketed!(content in input);
+
+                content
+                    .parse_terminated(Ident::parse, syn::Token![,])?
+                    .into_iter()
+                    .collect()
+            } else {
+                get_definitions(F64)
+                    .iter()
+                    .map(FloatDefinition::name_ident)
+                    .collect()
+            };
+
+            bindings.push(Binding { alias, types });
+        }
+    }
+}
+
+/// Generate a test per float type and per combination of the bound types,
+/// in modules named after them (e.g. `frexp::NonNaN::f32`).
+///
+/// The body is run with `values`, the test values, `Float`, the float type,
+/// and the aliases of the bindings (e.g. `Type` for `NonNaN<f32>`).
+pub fn generate_tests_values(input: &TestsValues) -> proc_macro2::TokenStream {
+    let name = &input.name;
+    let tests = generate_tests_values_bindings(input, &input.bindings, &mut Vec::new());
+
+    quote! {
+        #[allow(non_snake_case)]
+        mod #name {
+            use super::*;
+
+            #tests
+        }
+    }
+}
+
+fn generate_tests_values_bindings(
+    input: &TestsValues,
+    bindings: &[Binding],
+    bound: &mut Vec<(Ident, Ident)>,
+) -> proc_macro2::TokenStream {
+    let mut output = proc_macro2::TokenStream::new();
+
+    let Some((binding, rest)) = bindings.split_first() else {
+        for float_type in [F32, F64] {
+            let float_type = Ident::new(float_type, proc_macro2::Span::call_site());
+            let values = test_values(&float_type);
+            let body = &input.body;
+
+            let aliases = bound.iter().map(|(alias, name)| {
+                quote! { type #alias = typed_floats::#name<#float_type>; }
+            });
+
+            output.extend(quote! {
+                #[test]
+                fn #float_type() {
+                    #[allow(dead_code)]
+                    type Float = #float_type;
+                    #(#aliases)*
+
+                    let values: [Float; 23] = #values;
+
+                    #body
+                }
+            });
+        }
+
+        return output;
+    };
+
+    for name in &binding.types {
+        bound.push((binding.alias.clone(), name.clone()));
+        let tests = generate_tests_values_bindings(input, rest, bound);
+        bound.pop();
+
+        output.extend(quote! {
+            mod #name {
+                use super::*;
+
+                #tests
+            }
+        });
+    }
+
+    output
+}
+
+pub fn generate_tests_from_bits(float_type: &'static str) -> proc_macro2::TokenStream {
+    let floats = get_definitions(float_type);
+
+    let float_type = floats
+        .first()
+        .expect("no float returned")
+        .float_type_ident();
+
+    let test_fn_name = quote::format_ident!("test_{float_type}_from_bits");
+
+    let mut output = proc_macro2::TokenStream::new();
+
+    for float in &floats {
+        let full_type = float.full_type_ident();
+
+        output.extend(quote! {
+            for &a in values.iter() {
+                let expected = <#full_type>::try_from(a);
+                let from_bits = <#full_type>::from_bits(a.to_bits());
+
+                // `from_bits` must accept and reject the same values as `try_from`
+                assert_eq!(from_bits, expected, "from_bits({:#x}) for {}", a.to_bits(), stringify!(#full_type));
+
+                if let Ok(x) = expected {
+                    // The comparison of the zeros ignores their sign, so the bits are compared too
+                    let round_trip = <#full_type>::from_bits(x.to_bits());
+                    assert_eq!(round_trip, Ok(x));
+                    assert_eq!(round_trip.unwrap().to_bits(), a.to_bits());
+                }
+            }
+
+            for &nan in nans.iter() {
+                assert_eq!(<#full_type>::from_bits(nan.to_bits()), Err(InvalidNumber::NaN));
+                assert_eq!(<#full_type>::try_from(nan), Err(InvalidNumber::NaN));
+            }
+        });
+    }
+
+    let values = get_test_values(&float_type);
+
+    quote! {
+        #[test]
+        fn #test_fn_name() {
+            #values
+
+            // Every bit pattern that is not a valid value of any type
+            let nans = [
+                #float_type::NAN,
+                -#float_type::NAN,
+                #float_type::from_bits(#float_type::INFINITY.to_bits() | 1),
+                #float_type::from_bits(!0),
+            ];
+
+            for nan in nans {
+                assert!(nan.is_nan());
+            }
+
+            #output
+        }
+    }
+}
+
 pub fn generate_tests_self_rhs(float_type: &'static str, filter: &str) -> proc_macro2::TokenStream {
     let floats_f64 = get_definitions(float_type);
 
@@ -347,3 +628,140 @@ pub fn generate_tests_self_rhs(float_type: &'static str, filter: &str) -> proc_m
         }
     }
 }
+
+pub fn generate_tests_ternary(float_type: &'static str, filter: &str) -> proc_macro2::TokenStream {
+    let floats = get_definitions(float_type);
+
+    let mut output = proc_macro2::TokenStream::new();
+
+    let float_type = floats
+        .first()
+        .expect("no floats returned")
+        .float_type_ident();
+
+    let ops_ternary = get_impl_ternary()
+        .into_iter()
+        .filter(|x| x.key == filter)
+        .collect::<Vec<_>>();
+
+    assert!(!ops_ternary.is_empty());
+
+    // The operation is executed through a generic function,
+    // otherwise the 1728 combinations take too long to compile.
+    for op in &ops_ternary {
+        let op_name = op.key;
+        let helper = quote::format_ident!("compute_{float_type}_{op_name}");
+        let trait_ident: syn::Path = syn::parse_str(op.trait_name).unwrap();
+
+        let test = &op.get_test("num_x", "num_a", "num_b");
+        let test_float = &op.get_test_primitive("x", "a", "b");
+        let precondition = &op.get_test_precondition("x", "a", "b");
+
+        output.extend(quote! {
+            fn #helper<X, A, B>(values: &[#float_type]) -> Vec<#float_type>
+            where
+                X: TryFrom<#float_type> + #trait_ident<A, B> + Copy,
+                A: TryFrom<#float_type> + Copy,
+                B: TryFrom<#float_type> + Copy,
+                <X as #trait_ident<A, B>>::Output: Into<#float_type>,
+            {
+                let mut all = Vec::new();
+
+                for x in values.iter().copied() {
+                    let Ok(num_x) = X::try_from(x) else {
+                        continue;
+                    };
+
+                    for a in values.iter().copied() {
+                        let Ok(num_a) = A::try_from(a) else {
+                            continue;
+                        };
+
+                        for b in values.iter().copied() {
+                            let Ok(num_b) = B::try_from(b) else {
+                                continue;
+                            };
+
+                            if !(#precondition) {
+                                continue;
+                            }
+
+                            // This will panic if the result isn't compatible with the return type
+                            let f: #float_type = #test.into();
+
+                            // Check that the result is the same as if done with the float directly
+                            let original = #test_float;
+                            if original.is_nan() {
+                                assert_eq!(original.is_nan(), f.is_nan());
+                            } else {
+                                assert_eq!(original, f, "original op result is not the same as the implemented op");
+                            }
+
+                            all.push(f);
+                        }
+                    }
+                }
+
+                all
+            }
+        });
+    }
+
+    let values = get_test_values(&float_type);
+
+    // One test per type of `self`
+    for float in &floats {
+        let full_type = float.full_type_ident();
+
+        let test_fn_name = quote::format_ident!("test_{float_type}_{filter}_{}", float.name);
+
+        let mut check_ops = proc_macro2::TokenStream::new();
+
+        for float_a in &floats {
+            let full_type_a = float_a.full_type_ident();
+
+            for float_b in &floats {
+                let full_type_b = float_b.full_type_ident();
+
+                for op in &ops_ternary {
+                    let helper = quote::format_ident!("compute_{float_type}_{}", op.key);
+                    let vals = quote::format_ident!("all_{}", op.key);
+
+                    let checks = if op.skip_check_return_type_strictness {
+                        proc_macro2::TokenStream::new()
+                    } else {
+                        let result_type = op.get_result(float, float_a, float_b, &floats);
+                        let checks = test_op_checks(float, op.key, &result_type, &vals);
+
+                        // Nothing to check if the operation is never defined for those types
+                        quote! {
+                            if !#vals.is_empty() {
+                                #checks
+                            }
+                        }
+                    };
+
+                    check_ops.extend(quote! {
+                        {
+                            let #vals = #helper::<#full_type, #full_type_a, #full_type_b>(&values);
+
+                            #checks
+                        }
+                    });
+                }
+            }
+        }
+
+        output.extend(quote! {
+            #[test]
+            #[allow(non_snake_case)]
+            fn #test_fn_name() {
+                #values
+
+                #check_ops
+            }
+        });
+    }
+
+    output
+}
@@ -0,0 +1,93 @@
+use proc_macro2::Span;
+use quote::quote;
+use syn::Ident;
+
+use crate::types::{
+    output_name, return_type_definition, FloatDefinition, OpRhs, ReturnTypeDefinition,
+    ReturnTypeSpecification,
+};
+
+/// Generate the `Checked*` traits implementations between two finite types.
+///
+/// The result type is the one of the corresponding operator, without the infinities:
+/// the operation is done on the primitive types and the result is checked.
+pub fn get_impl_checked(
+    float: &FloatDefinition,
+    rhs: &FloatDefinition,
+    floats: &[FloatDefinition],
+    ops: &[OpRhs],
+) -> proc_macro2::TokenStream {
+    let mut output = proc_macro2::TokenStream::new();
+
+    if float.s.accept_inf || rhs.s.accept_inf {
+        return output;
+    }
+
+    let float_full_type = &float.full_type_ident();
+    let rhs_full_type = &rhs.full_type_ident();
+    let float_type = &float.float_type_ident();
+
+    for (key, trait_name, fn_name) in [
+        ("add", "CheckedAdd", "checked_add"),
+        ("sub", "CheckedSub", "checked_sub"),
+        ("mul", "CheckedMul", "checked_mul"),
+        ("div", "CheckedDiv", "checked_div"),
+    ] {
+        let op = ops.iter().find(|op| op.key == key).expect("Op not found");
+
+        let result = finite_result(op, float, rhs, floats);
+
+        let output_type = output_name(&result, float_type);
+        let output_call = match &result {
+            ReturnTypeDefinition::FloatDefinition(d) => d.call_tokens(),
+            ReturnTypeDefinition::NativeFloat => unreachable!(),
+        };
+
+        let op = op.get_op(float, rhs);
+
+        let trait_ident = Ident::new(trait_name, Span::call_site());
+        let fn_ident = Ident::new(fn_name, Span::call_site());
+
+        output.extend(quote! {
+            impl #trait_ident<#rhs_full_type> for #float_full_type {
+                type Output = #output_type;
+
+                #[inline]
+                fn #fn_ident(self, rhs: #rhs_full_type) -> Result<Self::Output, InvalidNumber> {
+                    #output_call::new(#op)
+                }
+            }
+        });
+    }
+
+    output
+}
+
+/// The result type of an operation between two finite types, without the infinities.
+pub(crate) fn finite_result(
+    op: &OpRhs,
+    float: &FloatDefinition,
+    rhs: &FloatDefinition,
+    floats: &[FloatDefinition],
+) -> ReturnTypeDefinition {
+    // Dividing by zero never gives a finite result (`x/0.0` is infinite and `0.0/0.0` is `NaN`)
+    // so the result type is the one of a division by a non-zero number.
+    let mut rhs_checked = rhs.clone();
+    if op.key == "div" {
+        rhs_checked.s.accept_zero = false;
+    }
+
+    match op.get_result(float, &rhs_checked, floats) {
+        ReturnTypeDefinition::FloatDefinition(result) => {
+            let mut result = result.s;
+            result.accept_inf = false;
+            return_type_definition(
+                &ReturnTypeSpecification::FloatSpecifications(result),
+                floats,
+            )
+        }
+        ReturnTypeDefinition::NativeFloat => {
+            panic!("The {} of two finite numbers should never be `NaN`", op.key)
+        }
+    }
+}
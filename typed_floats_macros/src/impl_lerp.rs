@@ -0,0 +1,100 @@
+use quote::quote;
+
+use crate::types::{
+    native_float_return, output_name, return_type_definition, FloatDefinition,
+    ReturnTypeDefinition, ReturnTypeSpecification,
+};
+
+/// Generate the `Lerp` trait implementation between two types.
+///
+/// The result is clamped between the endpoints, so it accepts the values of both of them.
+/// It may also be zero if the endpoints can have opposite signs,
+/// and it is `NaN` only between two infinities of opposite signs.
+pub fn get_impl_lerp(
+    float: &FloatDefinition,
+    rhs: &FloatDefinition,
+    floats: &[FloatDefinition],
+) -> proc_macro2::TokenStream {
+    let (a, b) = (&float.s, &rhs.s);
+
+    let can_be_nan = a.accept_inf
+        && b.accept_inf
+        && ((a.accept_positive && b.accept_negative) || (a.accept_negative && b.accept_positive));
+
+    let result = if can_be_nan {
+        ReturnTypeSpecification::NativeFloat
+    } else {
+        let mut output_spec = a.union(b);
+        output_spec.accept_zero |=
+            (a.accept_positive && b.accept_negative) || (a.accept_negative && b.accept_positive);
+
+        ReturnTypeSpecification::FloatSpecifications(output_spec)
+    };
+
+    let result = return_type_definition(&result, floats);
+
+    let float_full_type = &float.full_type_ident();
+    let rhs_full_type = &rhs.full_type_ident();
+    let float_type = &float.float_type_ident();
+
+    let output_type = output_name(&result, float_type);
+    let return_value = match &result {
+        ReturnTypeDefinition::FloatDefinition(d) => {
+            let output_call = d.call_tokens();
+            quote! { unsafe { #output_call::new_unchecked(value) } }
+        }
+        ReturnTypeDefinition::NativeFloat => native_float_return(
+            &quote! { value },
+            &format!("{}::lerp({})", float.full_type_name(), rhs.full_type_name()),
+        ),
+    };
+
+    quote! {
+        impl Lerp<#rhs_full_type, #float_type> for #float_full_type {
+            type Output = #output_type;
+
+            #[inline]
+            #[allow(clippy::suboptimal_flops)] // `mul_add` is not available without `std`
+            fn lerp(self, rhs: #rhs_full_type, t: UnitInterval<#float_type>) -> Self::Output {
+                let (a, b, t) = (self.get(), rhs.get(), t.get());
+
+                let value = if t <= 0.0 {
+                    a
+                } else if t >= 1.0 {
+                    b
+                } else {
+                    let diff = b - a;
+
+                    // The difference is infinite only if the endpoints have opposite signs
+                    // or are infinite, in which cases the weighted sum can't overflow
+                    let value = if diff.is_finite() {
+                        a + diff * t
+                    } else {
+                        a * (1.0 - t) + b * t
+                    };
+
+                    // The rounding may cross an endpoint
+                    let (min, max) = if a < b { (a, b) } else { (b, a) };
+                    let value = if value < min {
+                        min
+                    } else if value > max {
+                        max
+                    } else {
+                        value
+                    };
+
+                    // `-0.0 + 0.0` is `+0.0`, so a zero takes the sign of the endpoints if they share it
+                    if value == 0.0 && a.is_sign_negative() && b.is_sign_negative() {
+                        -0.0
+                    } else if value == 0.0 && a.is_sign_positive() && b.is_sign_positive() {
+                        0.0
+                    } else {
+                        value
+                    }
+                };
+
+                #return_value
+            }
+        }
+    }
+}
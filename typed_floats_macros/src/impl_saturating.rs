@@ -0,0 +1,73 @@
+use proc_macro2::Span;
+use quote::quote;
+use syn::Ident;
+
+use crate::impl_checked::finite_result;
+use crate::types::{output_name, FloatDefinition, OpRhs, ReturnTypeDefinition};
+
+/// Generate the `Saturating*` traits implementations between two finite types.
+///
+/// The result type is the same as the `Checked*` traits:
+/// an infinite result is replaced by `MAX` or `MIN`, depending on its sign.
+pub fn get_impl_saturating(
+    float: &FloatDefinition,
+    rhs: &FloatDefinition,
+    floats: &[FloatDefinition],
+    ops: &[OpRhs],
+) -> proc_macro2::TokenStream {
+    let mut output = proc_macro2::TokenStream::new();
+
+    if float.s.accept_inf || rhs.s.accept_inf {
+        return output;
+    }
+
+    let float_full_type = &float.full_type_ident();
+    let rhs_full_type = &rhs.full_type_ident();
+    let float_type = &float.float_type_ident();
+
+    for (key, trait_name, fn_name) in [
+        ("add", "SaturatingAdd", "saturating_add"),
+        ("sub", "SaturatingSub", "saturating_sub"),
+        ("mul", "SaturatingMul", "saturating_mul"),
+    ] {
+        let op = ops.iter().find(|op| op.key == key).expect("Op not found");
+
+        let result = finite_result(op, float, rhs, floats);
+
+        let output_type = output_name(&result, float_type);
+        let output_call = match &result {
+            ReturnTypeDefinition::FloatDefinition(d) => d.call_tokens(),
+            ReturnTypeDefinition::NativeFloat => unreachable!(),
+        };
+
+        let op = op.get_op(float, rhs);
+
+        let trait_ident = Ident::new(trait_name, Span::call_site());
+        let fn_ident = Ident::new(fn_name, Span::call_site());
+
+        output.extend(quote! {
+            impl #trait_ident<#rhs_full_type> for #float_full_type {
+                type Output = #output_type;
+
+                #[inline]
+                fn #fn_ident(self, rhs: #rhs_full_type) -> Self::Output {
+                    let value = #op;
+
+                    // The operation between two finite numbers is never `NaN`
+                    // and an overflow keeps the sign of the result
+                    let value = if !value.is_infinite() {
+                        value
+                    } else if value.is_sign_positive() {
+                        core::#float_type::MAX
+                    } else {
+                        core::#float_type::MIN
+                    };
+
+                    unsafe { #output_call::new_unchecked(value) }
+                }
+            }
+        });
+    }
+
+    output
+}
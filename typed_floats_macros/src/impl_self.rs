@@ -1,7 +1,108 @@
 use quote::quote;
 
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::types::{
+    native_float_return, output_name, return_type_definition, FloatDefinition, ReturnTypeDefinition,
+};
 use crate::types::{FloatSpecifications, Op, OpBuilder, ReturnTypeSpecification};
 
+/// The classes of exponents of `powi` leading to different possible results.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[derive(Clone, Copy)]
+pub enum PowiExponent {
+    Zero,
+    PositiveEven,
+    PositiveOdd,
+    NegativeEven,
+    NegativeOdd,
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+const POWI_EXPONENTS: [PowiExponent; 5] = [
+    PowiExponent::Zero,
+    PowiExponent::PositiveEven,
+    PowiExponent::PositiveOdd,
+    PowiExponent::NegativeEven,
+    PowiExponent::NegativeOdd,
+];
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl PowiExponent {
+    /// The class of the exponent `n`.
+    pub const fn of(n: i32) -> Self {
+        match (n.signum(), n % 2 == 0) {
+            (0, _) => Self::Zero,
+            (1, true) => Self::PositiveEven,
+            (1, false) => Self::PositiveOdd,
+            (_, true) => Self::NegativeEven,
+            (_, false) => Self::NegativeOdd,
+        }
+    }
+
+    /// The marker type of the class, in the `powi` module.
+    pub fn ident(self) -> proc_macro2::Ident {
+        let name = match self {
+            Self::Zero => "Zero",
+            Self::PositiveEven => "PositiveEven",
+            Self::PositiveOdd => "PositiveOdd",
+            Self::NegativeEven => "NegativeEven",
+            Self::NegativeOdd => "NegativeOdd",
+        };
+
+        proc_macro2::Ident::new(name, proc_macro2::Span::call_site())
+    }
+}
+
+/// The exponents implementing `ExponentClass`, usable with `powi_const`.
+#[cfg(any(feature = "std", feature = "libm"))]
+pub const POWI_CONST_EXPONENTS: core::ops::RangeInclusive<i32> = -64..=64;
+
+/// The result of `x.powi(n)` for the exponents `n` of the given class.
+#[cfg(any(feature = "std", feature = "libm"))]
+const fn powi_result(float: &FloatSpecifications, n: PowiExponent) -> FloatSpecifications {
+    match n {
+        // `x.powi(0)` is `1.0`, even for zero and infinity
+        PowiExponent::Zero => FloatSpecifications {
+            accept_negative: false,
+            accept_positive: true,
+            accept_zero: false,
+            accept_inf: false,
+        },
+        // An even power is positive, including `(-0.0).powi(2)`.
+        // Every type accepts finite numbers large enough to overflow
+        // and small enough to underflow, and a negative `n` also swaps zero and infinity,
+        // so both are always possible.
+        PowiExponent::PositiveEven | PowiExponent::NegativeEven => FloatSpecifications {
+            accept_negative: false,
+            accept_positive: true,
+            accept_zero: true,
+            accept_inf: true,
+        },
+        // An odd power keeps the sign of the base, including for `-0.0`
+        PowiExponent::PositiveOdd | PowiExponent::NegativeOdd => FloatSpecifications {
+            accept_negative: float.accept_negative,
+            accept_positive: float.accept_positive,
+            accept_zero: true,
+            accept_inf: true,
+        },
+    }
+}
+
+/// The result of `x.cbrt()`, which is exactly as strict as `x`.
+#[cfg(any(feature = "std", feature = "libm"))]
+const fn cbrt_result(float: &FloatSpecifications) -> FloatSpecifications {
+    FloatSpecifications {
+        // The cube root keeps the sign, including for `-0.0` and `-inf`
+        accept_negative: float.accept_negative,
+        accept_positive: float.accept_positive,
+        // Only zero and infinity are their own cube roots: `cbrt(MAX)` is about `5.6e102`
+        // and the cube root of the smallest subnormal is about `1.7e-108`,
+        // so it can't overflow nor be rounded to zero
+        accept_zero: float.accept_zero,
+        accept_inf: float.accept_inf,
+    }
+}
+
 pub fn get_impl_self() -> Vec<Op> {
     vec![
         OpBuilder::new("neg")
@@ -342,6 +443,7 @@ pub fn get_impl_self() -> Vec<Op> {
             .build(),
         #[cfg(any(feature = "std", feature = "libm"))]
         OpBuilder::new("exp")
+            .comment("Rounding errors may generate zero from negative values: `exp(-1000.0) = 0.0`.")
             .description(quote! {
                 /// Returns `e^(self)`, (the exponential function).
                 ///
@@ -375,6 +477,7 @@ pub fn get_impl_self() -> Vec<Op> {
             .build(),
         #[cfg(any(feature = "std", feature = "libm"))]
         OpBuilder::new("exp2")
+            .comment("Rounding errors may generate zero from negative values: `exp2(-1100.0) = 0.0`.")
             .description(quote! {
                 /// Returns `2^(self)`.
                 ///
@@ -596,7 +699,12 @@ pub fn get_impl_self() -> Vec<Op> {
                 /// See [`f64::to_radians()`] for more details.
             })
             .result(Box::new(|float| {
-                ReturnTypeSpecification::FloatSpecifications(float.s.clone())
+                let mut output_spec = float.s.clone();
+
+                // The smallest subnormal numbers are rounded to zero
+                output_spec.accept_zero = true;
+
+                ReturnTypeSpecification::FloatSpecifications(output_spec)
             }))
             .build(),
         #[cfg(any(feature = "std", feature = "libm"))]
@@ -626,7 +734,7 @@ pub fn get_impl_self() -> Vec<Op> {
                 /// See [`f64::cbrt()`] for more details.
             })
             .result(Box::new(|float| {
-                ReturnTypeSpecification::FloatSpecifications(float.s.clone())
+                ReturnTypeSpecification::FloatSpecifications(cbrt_result(&float.s))
             }))
             .build(),
         #[cfg(any(feature = "std", feature = "libm"))]
@@ -1067,6 +1175,7 @@ pub fn get_impl_self() -> Vec<Op> {
             .result(Box::new(|_| ReturnTypeSpecification::NativeFloat))
             .build(),
         OpBuilder::new("recip")
+            .comment("Rounding errors may generate infinity from subnormal values: `recip(1e-320) = inf`.")
             .description(quote! {
                 /// Takes the reciprocal (inverse) of a number, `1/x`.
                 ///
@@ -1085,6 +1194,11 @@ pub fn get_impl_self() -> Vec<Op> {
                 ///
                 /// assert_is_positive_zero!(tf64::INFINITY.recip());
                 /// assert_is_negative_zero!(tf64::NEG_INFINITY.recip());
+                ///
+                /// // The reciprocal of a subnormal number can overflow
+                /// let c: StrictlyPositiveFinite = f64::from_bits(1).try_into().unwrap();
+                ///
+                /// assert_eq!(c.recip(), tf64::INFINITY);
                 /// ```
                 ///
                 /// See [`f64::recip()`] for more details.
@@ -1094,7 +1208,95 @@ pub fn get_impl_self() -> Vec<Op> {
                     accept_negative: float.s.accept_negative,
                     accept_positive: float.s.accept_positive,
                     accept_zero: float.s.accept_inf,
-                    accept_inf: float.s.accept_zero,
+                    // All types accept subnormal numbers, whose reciprocal can overflow
+                    accept_inf: true,
+                })
+            }))
+            .build(),
+        OpBuilder::new("next_up")
+            .description(quote! {
+                /// Returns the least number greater than `self`.
+                ///
+                /// The result is `+0.0` for no input: the number following the smallest
+                /// negative subnormal is `-0.0`, and the one following both zeros is the
+                /// smallest positive subnormal.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// # use typed_floats::*;
+                /// let a: StrictlyPositiveFinite = 1.0.try_into().unwrap();
+                /// let b: StrictlyPositive = a.next_up();
+                ///
+                /// assert_eq!(b, 1.0 + f64::EPSILON);
+                ///
+                /// assert_eq!(tf64::MAX.next_up(), tf64::INFINITY);
+                /// let tiny: StrictlyNegativeFinite = (-f64::from_bits(1)).try_into().unwrap();
+                ///
+                /// assert_eq!(tf64::ZERO.next_up(), f64::from_bits(1));
+                /// assert_is_negative_zero!(tiny.next_up());
+                /// assert_eq!(tf64::NEG_INFINITY.next_up(), f64::MIN);
+                /// ```
+                ///
+                /// See [`f64::next_up()`] for more details.
+            })
+            .op_fn(Box::new(|float| {
+                next_float(&quote! { self.get() }, &float.float_type_ident(), true)
+            }))
+            .op_test_primitive(Box::new(|var, float_type| {
+                next_float(&quote! { #var }, float_type, true)
+            }))
+            .result(Box::new(|float| {
+                ReturnTypeSpecification::FloatSpecifications(FloatSpecifications {
+                    // The smallest negative subnormal goes to `-0.0`
+                    accept_zero: float.s.accept_negative,
+                    accept_positive: float.s.accept_positive || float.s.accept_zero,
+                    accept_negative: float.s.accept_negative,
+                    // `MAX` goes to `+inf`
+                    accept_inf: float.s.accept_positive,
+                })
+            }))
+            .build(),
+        OpBuilder::new("next_down")
+            .description(quote! {
+                /// Returns the greatest number less than `self`.
+                ///
+                /// The result is `-0.0` for no input: the number preceding the smallest
+                /// positive subnormal is `+0.0`, and the one preceding both zeros is the
+                /// smallest negative subnormal.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// # use typed_floats::*;
+                /// let a: PositiveFinite = tf64::MIN_POSITIVE.next_down();
+                ///
+                /// assert!(a.is_subnormal());
+                ///
+                /// assert_eq!(tf64::MIN.next_down(), tf64::NEG_INFINITY);
+                /// let tiny: StrictlyPositiveFinite = f64::from_bits(1).try_into().unwrap();
+                ///
+                /// assert_eq!(tf64::ZERO.next_down(), -f64::from_bits(1));
+                /// assert_is_positive_zero!(tiny.next_down());
+                /// assert_eq!(tf64::INFINITY.next_down(), f64::MAX);
+                /// ```
+                ///
+                /// See [`f64::next_down()`] for more details.
+            })
+            .op_fn(Box::new(|float| {
+                next_float(&quote! { self.get() }, &float.float_type_ident(), false)
+            }))
+            .op_test_primitive(Box::new(|var, float_type| {
+                next_float(&quote! { #var }, float_type, false)
+            }))
+            .result(Box::new(|float| {
+                ReturnTypeSpecification::FloatSpecifications(FloatSpecifications {
+                    // The smallest positive subnormal goes to `+0.0`
+                    accept_zero: float.s.accept_positive,
+                    accept_positive: float.s.accept_positive,
+                    accept_negative: float.s.accept_negative || float.s.accept_zero,
+                    // `MIN` goes to `-inf`
+                    accept_inf: float.s.accept_negative,
                 })
             }))
             .build(),
@@ -1113,6 +1315,12 @@ pub fn get_impl_self() -> Vec<Op> {
                 /// It might have a different sequence of rounding operations than `powf`,
                 /// so the results are not guaranteed to agree.
                 ///
+                /// As `n` is only known at runtime, the result type holds for every exponent:
+                /// the sign of the base is kept for odd exponents, and zero and infinity can
+                /// always be reached (negative exponents, overflow or underflow).
+                /// [`PowiConst::powi_const()`] takes an exponent known at compile time instead,
+                /// with a result type depending on it.
+                ///
                 /// # Examples
                 ///
                 /// ```
@@ -1126,21 +1334,301 @@ pub fn get_impl_self() -> Vec<Op> {
                 /// assert_eq!(x.powi(-1), -0.5);
                 /// assert_eq!(x.powi(-2), 0.25);
                 /// assert_eq!(x.powi(-3), -0.125);
+                ///
+                /// let y: StrictlyPositiveFinite = 2.0.try_into().unwrap();
+                /// let z: Positive = y.powi(-2);
+                ///
+                /// assert_eq!(z, 0.25);
                 /// ```
                 /// See [`f64::powi()`] for more details.
             })
+            .result(Box::new(|float| {
+                // The exponent can't be part of the type, so the result must accept
+                // the results of every class of exponents.
+                ReturnTypeSpecification::FloatSpecifications(
+                    POWI_EXPONENTS
+                        .iter()
+                        .map(|&n| powi_result(&float.s, n))
+                        .reduce(|a, b| a.union(&b))
+                        .expect("No exponent"),
+                )
+            }))
+            .skip_check_return_type_strictness()
+            .op_test(Box::new(|var| {
+                quote! { #var.powi(2) }
+            }))
+            .build(),
+        #[cfg(feature = "libm")]
+        OpBuilder::new("ldexp")
+            .params(quote! {self, exp: i32})
+            .op_fn(Box::new(|float| {
+                let exp = syn::Ident::new("exp", proc_macro2::Span::call_site());
+
+                if float.float_type == "f32" {
+                    quote! { libm::ldexpf(self.get(), #exp) }
+                } else {
+                    quote! { libm::ldexp(self.get(), #exp) }
+                }
+            }))
+            .description(quote! {
+                /// Multiplies `self` by 2 raised to the power of `exp`.
+                ///
+                /// It is the inverse of `frexp`.
+                ///
+                /// As `exp` is only known at runtime, the result type holds for every exponent:
+                /// the sign is kept, but the result can always overflow to infinity
+                /// or underflow to zero.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// # use typed_floats::*;
+                /// let x: StrictlyPositiveFinite = 3.0.try_into().unwrap();
+                ///
+                /// let y: Positive = x.ldexp(2);
+                /// assert_eq!(y, 12.0);
+                ///
+                /// assert_eq!(x.ldexp(-1), 1.5);
+                /// assert_eq!(x.ldexp(2000), f64::INFINITY);
+                /// assert_eq!(x.ldexp(-2000), 0.0);
+                /// ```
+            })
             .result(Box::new(|float| {
                 ReturnTypeSpecification::FloatSpecifications(FloatSpecifications {
                     accept_negative: float.s.accept_negative,
-                    accept_positive: true,
+                    accept_positive: float.s.accept_positive,
                     accept_zero: true,
                     accept_inf: true,
                 })
             }))
+            // The exponent of the tests can't overflow nor underflow
             .skip_check_return_type_strictness()
             .op_test(Box::new(|var| {
-                quote! { #var.powi(2) }
+                quote! { #var.ldexp(2) }
+            }))
+            .op_test_primitive(Box::new(|var, _| {
+                // Multiplying by a power of two is exact
+                quote! { #var * 4.0 }
+            }))
+            .build(),
+        #[cfg(feature = "libm")]
+        OpBuilder::new("gamma")
+            .op_fn(Box::new(|float| {
+                if float.float_type == "f32" {
+                    quote! { libm::tgammaf(self.get()) }
+                } else {
+                    quote! { libm::tgamma(self.get()) }
+                }
+            }))
+            .description(quote! {
+                /// Computes the gamma function of `self`.
+                ///
+                /// The result is `NaN` for the negative integers and `-inf`,
+                /// so only the types without negative numbers return a typed float.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// # use typed_floats::*;
+                /// let x: StrictlyPositiveFinite = 5.0.try_into().unwrap();
+                ///
+                /// let y: StrictlyPositive = x.gamma();
+                /// assert!((y.get() - 24.0).abs() < 1e-10);
+                ///
+                /// assert_eq!(tf64::ZERO.gamma(), f64::INFINITY);
+                /// assert_eq!(tf64::MAX.gamma(), f64::INFINITY);
+                ///
+                /// let z: NonNaN = (-1.0).try_into().unwrap();
+                /// assert_is_nan!(z.gamma());
+                /// ```
+            })
+            .comment("The gamma function of a negative integer is `NaN`.")
+            .result(Box::new(|float| {
+                if float.s.accept_negative {
+                    // All the types accepting negative numbers accept `-1.0`
+                    ReturnTypeSpecification::NativeFloat
+                } else {
+                    // `gamma(+0.0)` is `+inf`, and it overflows for large numbers.
+                    // Its minimum for positive numbers is about `0.8856`.
+                    ReturnTypeSpecification::FloatSpecifications(FloatSpecifications {
+                        accept_negative: false,
+                        accept_positive: true,
+                        accept_zero: false,
+                        accept_inf: true,
+                    })
+                }
+            }))
+            .op_test_primitive(Box::new(|var, float_type| {
+                if float_type == "f32" {
+                    quote! { libm::tgammaf(#var) }
+                } else {
+                    quote! { libm::tgamma(#var) }
+                }
             }))
             .build(),
     ]
 }
+
+/// Generate the body of `next_up` (or `next_down` if `up` is false) applied to `value`.
+/// `f64::next_up` and `f64::next_down` require a more recent Rust version than the MSRV,
+/// so the bits are computed directly.
+fn next_float(
+    value: &proc_macro2::TokenStream,
+    float_type: &proc_macro2::Ident,
+    up: bool,
+) -> proc_macro2::TokenStream {
+    let (limit, tiny, away_from_zero) = if up {
+        (
+            quote! { INFINITY },
+            quote! { <#float_type>::from_bits(1) },
+            quote! { value > 0.0 },
+        )
+    } else {
+        (
+            quote! { NEG_INFINITY },
+            quote! { -<#float_type>::from_bits(1) },
+            quote! { value < 0.0 },
+        )
+    };
+
+    quote! {{
+        let value: #float_type = #value;
+        let bits = value.to_bits();
+
+        if value.is_nan() || value == core::#float_type::#limit {
+            value
+        } else if value == 0.0 {
+            #tiny
+        } else if #away_from_zero {
+            <#float_type>::from_bits(bits + 1)
+        } else {
+            <#float_type>::from_bits(bits - 1)
+        }
+    }}
+}
+
+/// Generate `sin_cos`, returning the same types as `sin` and `cos` would.
+#[cfg(any(feature = "std", feature = "libm"))]
+pub fn get_impl_sin_cos(
+    float: &FloatDefinition,
+    floats: &[FloatDefinition],
+    ops: &[Op],
+) -> proc_macro2::TokenStream {
+    let get_op_result = |key: &str| {
+        ops.iter()
+            .find(|op| op.key == key)
+            .expect("Op not found")
+            .get_result(float, floats)
+    };
+
+    let wrap = |output: &ReturnTypeDefinition, value: proc_macro2::TokenStream| match output {
+        ReturnTypeDefinition::FloatDefinition(d) => {
+            let output_call = &d.call_tokens();
+
+            quote! { unsafe { #output_call::new_unchecked(#value) } }
+        }
+        ReturnTypeDefinition::NativeFloat => {
+            native_float_return(&value, &format!("{}::sin_cos()", float.full_type_name()))
+        }
+    };
+
+    let float_full_type = &float.full_type_ident();
+    let float_type = &float.float_type_ident();
+
+    let sin_output = get_op_result("sin");
+    let cos_output = get_op_result("cos");
+
+    let sin_name = output_name(&sin_output, float_type);
+    let cos_name = output_name(&cos_output, float_type);
+
+    let sin_value = wrap(&sin_output, quote! { sin });
+    let cos_value = wrap(&cos_output, quote! { cos });
+
+    quote! {
+        impl #float_full_type {
+            /// Simultaneously computes the sine and cosine of the number, `x`.
+            /// Returns `(sin(x), cos(x))`.
+            ///
+            /// The returned types are the same as the ones of [`Self::sin()`] and [`Self::cos()`].
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use typed_floats::*;
+            /// let x: NonNaNFinite = core::f64::consts::FRAC_PI_4.try_into().unwrap();
+            ///
+            /// let (sin, cos): (NonNaNFinite, NonNaNFinite) = x.sin_cos();
+            ///
+            /// assert_relative_eq!(sin.get(), core::f64::consts::FRAC_1_SQRT_2);
+            /// assert_relative_eq!(cos.get(), core::f64::consts::FRAC_1_SQRT_2);
+            ///
+            /// let (sin, cos) = tf64::INFINITY.sin_cos();
+            ///
+            /// assert_is_nan!(sin);
+            /// assert_is_nan!(cos);
+            /// ```
+            ///
+            /// See [`f64::sin_cos()`] for more details.
+            #[inline]
+            #[must_use]
+            pub fn sin_cos(self) -> (#sin_name, #cos_name) {
+                let (sin, cos) = self.get().sin_cos();
+
+                (#sin_value, #cos_value)
+            }
+        }
+    }
+}
+
+/// Generate the `PowiConst` implementations, one per class of exponents,
+/// each with the result of `powi` for the exponents of that class.
+#[cfg(any(feature = "std", feature = "libm"))]
+pub fn get_impl_powi_const(
+    float: &FloatDefinition,
+    floats: &[FloatDefinition],
+) -> proc_macro2::TokenStream {
+    let mut output = proc_macro2::TokenStream::new();
+
+    let float_full_type = &float.full_type_ident();
+    let float_type = &float.float_type_ident();
+
+    for class in POWI_EXPONENTS {
+        let result = return_type_definition(
+            &ReturnTypeSpecification::FloatSpecifications(powi_result(&float.s, class)),
+            floats,
+        );
+
+        let output_name = output_name(&result, float_type);
+        let value = match &result {
+            ReturnTypeDefinition::FloatDefinition(d) => {
+                let output_call = &d.call_tokens();
+
+                quote! { unsafe { #output_call::new_unchecked(value) } }
+            }
+            ReturnTypeDefinition::NativeFloat => native_float_return(
+                &quote! { value },
+                &format!("{}::powi_const()", float.full_type_name()),
+            ),
+        };
+
+        let class = class.ident();
+
+        output.extend(quote! {
+            impl PowiConst<crate::powi::#class> for #float_full_type {
+                type Output = #output_name;
+
+                #[inline]
+                fn powi_const<const N: i32>(self) -> Self::Output
+                where
+                    crate::powi::Exponent<N>: crate::powi::ExponentClass<Class = crate::powi::#class>,
+                {
+                    let value = self.get().powi(N);
+
+                    #value
+                }
+            }
+        });
+    }
+
+    output
+}
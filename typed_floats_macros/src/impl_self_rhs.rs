@@ -1,13 +1,11 @@
 use quote::quote;
 
-use crate::types::{
-    FloatDefinition, FloatSpecifications, OpRhs, OpRhsBuilder, ReturnTypeSpecification,
-};
-
-fn add_result(float: &FloatDefinition, rhs: &FloatDefinition) -> ReturnTypeSpecification {
-    let spec_a = &float.s;
-    let spec_b = &rhs.s;
+use crate::types::{FloatSpecifications, OpRhs, OpRhsBuilder, ReturnTypeSpecification};
 
+pub(crate) const fn add_result(
+    spec_a: &FloatSpecifications,
+    spec_b: &FloatSpecifications,
+) -> ReturnTypeSpecification {
     let can_sign_be_different = (spec_a.accept_negative && spec_b.accept_positive)
         || (spec_a.accept_positive && spec_b.accept_negative);
     let can_sign_be_same = (spec_a.accept_negative && spec_b.accept_negative)
@@ -36,6 +34,34 @@ fn add_result(float: &FloatDefinition, rhs: &FloatDefinition) -> ReturnTypeSpeci
     }
 }
 
+pub(crate) const fn mul_result(
+    spec_a: &FloatSpecifications,
+    spec_b: &FloatSpecifications,
+) -> ReturnTypeSpecification {
+    let can_sign_be_different = (spec_a.accept_negative && spec_b.accept_positive)
+        || (spec_a.accept_positive && spec_b.accept_negative);
+    let can_sign_be_same = (spec_a.accept_negative && spec_b.accept_negative)
+        || (spec_a.accept_positive && spec_b.accept_positive);
+
+    let can_zero_multiply_inf =
+        spec_a.accept_zero && spec_b.accept_inf || spec_a.accept_inf && spec_b.accept_zero;
+
+    let can_be_nan = can_zero_multiply_inf;
+
+    if can_be_nan {
+        ReturnTypeSpecification::NativeFloat
+    } else {
+        // The sign of the result is the XOR of the signs of the operands, even for zeros:
+        // `0.0 * -1.0 = -0.0`
+        ReturnTypeSpecification::FloatSpecifications(FloatSpecifications {
+            accept_inf: true,  // it can always overflow
+            accept_zero: true, // it can always round to zero
+            accept_positive: can_sign_be_same,
+            accept_negative: can_sign_be_different,
+        })
+    }
+}
+
 fn can_one_be_zero_neg_and_the_other_zero_pos(
     spec_a: &FloatSpecifications,
     spec_b: &FloatSpecifications,
@@ -48,6 +74,105 @@ fn can_one_be_zero_neg_and_the_other_zero_pos(
     (can_a_be_zero_neg && can_b_be_zero_pos) || (can_a_be_zero_pos && can_b_be_zero_neg)
 }
 
+/// The result of `min`, where `can_confuse_zero` is `true` if `min(-0.0, 0.0)` may return `0.0`.
+fn min_result(
+    float: &FloatSpecifications,
+    rhs: &FloatSpecifications,
+    can_confuse_zero: bool,
+) -> FloatSpecifications {
+    let can_be_neg_inf =
+        (float.accept_negative && float.accept_inf) || (rhs.accept_negative && rhs.accept_inf);
+    let can_be_pos_inf =
+        float.accept_positive && float.accept_inf && rhs.accept_positive && rhs.accept_inf;
+    let accept_inf = can_be_neg_inf || can_be_pos_inf;
+
+    if !float.accept_positive {
+        let accept_zero = float.accept_zero && (rhs.accept_zero || rhs.accept_positive);
+
+        FloatSpecifications {
+            accept_inf,
+            accept_zero,
+            // Only `+0.0` if `min(-0.0, 0.0)` returns `0.0`
+            accept_positive: can_confuse_zero,
+            accept_negative: true,
+        }
+    } else if !rhs.accept_positive {
+        let accept_zero = rhs.accept_zero && (float.accept_zero || float.accept_positive);
+
+        FloatSpecifications {
+            accept_inf,
+            accept_zero,
+            // Only `+0.0` if `min(-0.0, 0.0)` returns `0.0`
+            accept_positive: can_confuse_zero,
+            accept_negative: true,
+        }
+    } else if !float.accept_negative && !rhs.accept_negative {
+        FloatSpecifications {
+            accept_inf,
+            accept_zero: float.accept_zero || rhs.accept_zero,
+            accept_positive: true,
+            accept_negative: false,
+        }
+    } else {
+        FloatSpecifications {
+            accept_inf: can_be_neg_inf || can_be_pos_inf,
+            accept_zero: float.accept_zero || rhs.accept_zero,
+            accept_positive: true,
+            accept_negative: true,
+        }
+    }
+}
+
+/// The result of `max`, where `can_confuse_zero` is `true` if `max(0.0, -0.0)` may return `-0.0`.
+fn max_result(
+    float: &FloatSpecifications,
+    rhs: &FloatSpecifications,
+    can_confuse_zero: bool,
+) -> FloatSpecifications {
+    let can_be_neg_inf =
+        (float.accept_negative && float.accept_inf) && (rhs.accept_negative && rhs.accept_inf);
+    let can_be_pos_inf =
+        (float.accept_positive && float.accept_inf) || (rhs.accept_positive && rhs.accept_inf);
+
+    let accept_inf = can_be_neg_inf || can_be_pos_inf;
+
+    if !float.accept_negative {
+        let accept_zero = float.accept_zero && (rhs.accept_zero || rhs.accept_negative);
+
+        FloatSpecifications {
+            accept_inf,
+            accept_zero,
+            accept_positive: true,
+            // Only `-0.0` if `max(0.0, -0.0)` returns `-0.0`
+            accept_negative: can_confuse_zero,
+        }
+    } else if !rhs.accept_negative {
+        let accept_zero = rhs.accept_zero && (float.accept_zero || float.accept_negative);
+
+        FloatSpecifications {
+            accept_inf,
+            accept_zero,
+            accept_positive: true,
+            // Only `-0.0` if `max(0.0, -0.0)` returns `-0.0`
+            accept_negative: can_confuse_zero,
+        }
+    } else if !float.accept_positive && !rhs.accept_positive {
+        FloatSpecifications {
+            accept_inf,
+            accept_zero: float.accept_zero || rhs.accept_zero,
+            accept_positive: false,
+            accept_negative: true,
+        }
+    } else {
+        FloatSpecifications {
+            accept_inf: can_be_neg_inf || can_be_pos_inf,
+            accept_zero: float.accept_zero || rhs.accept_zero,
+            accept_positive: true,
+            accept_negative: true,
+        }
+    }
+}
+
 pub fn get_impl_self_rhs() -> Vec<OpRhs> {
     #[allow(unused_mut)] // depending on the enabled features, `mut` may be unused
     let mut ops = vec![
@@ -58,7 +183,7 @@ pub fn get_impl_self_rhs() -> Vec<OpRhs> {
             .op_test(Box::new(|var1, var2| quote! { #var1 + #var2 }))
             .op_is_commutative()
             .comment("The addition of two opposite infinity is `NaN`.")
-            .result(Box::new(add_result))
+            .result(Box::new(|float, rhs| add_result(&float.s, &rhs.s)))
             .build(),
         OpRhsBuilder::new("core::ops::Sub", "sub")
             .with_assign("core::ops::SubAssign", "sub_assign")
@@ -94,6 +219,7 @@ pub fn get_impl_self_rhs() -> Vec<OpRhs> {
         OpRhsBuilder::new("core::ops::Rem", "rem")
             .with_assign("core::ops::RemAssign", "rem_assign")
             .bin_op("%")
+            .comment("The result has the sign of the dividend, whatever the sign of the divisor.")
             .result(Box::new(|float, rhs| {
                 let spec_a = &float.s;
                 let spec_b = &rhs.s;
@@ -145,31 +271,7 @@ pub fn get_impl_self_rhs() -> Vec<OpRhs> {
             .bin_op("*")
             .op_is_commutative()
             .comment("The result of zero multiplied by infinity is `NaN`. Rounding errors may generate zero from non-zero values.")
-            .result(Box::new(|float, rhs| {
-                let spec_a = &float.s;
-                let spec_b = &rhs.s;
-
-                let can_sign_be_different = (spec_a.accept_negative && spec_b.accept_positive)
-                    || (spec_a.accept_positive && spec_b.accept_negative);
-                let can_sign_be_same = (spec_a.accept_negative && spec_b.accept_negative)
-                    || (spec_a.accept_positive && spec_b.accept_positive);
-
-                let can_zero_multiply_inf = spec_a.accept_zero && spec_b.accept_inf
-                    || spec_a.accept_inf && spec_b.accept_zero;
-
-                let can_be_nan = can_zero_multiply_inf;
-
-                if can_be_nan {
-                      ReturnTypeSpecification::NativeFloat
-                } else {
-                    ReturnTypeSpecification::FloatSpecifications(FloatSpecifications {
-                        accept_inf: true,  // it can always overflow
-                        accept_zero: true, // it can always round to zero
-                        accept_positive: can_sign_be_same,
-                        accept_negative: can_sign_be_different,
-                    })
-                }
-            }))
+            .result(Box::new(|float, rhs| mul_result(&float.s, &rhs.s)))
             .build(),
             #[cfg(any(feature = "std", feature = "libm"))]
         OpRhsBuilder::new("Hypot", "hypot")
@@ -178,6 +280,7 @@ pub fn get_impl_self_rhs() -> Vec<OpRhs> {
             .result(Box::new(|float, rhs| {
                 ReturnTypeSpecification::FloatSpecifications(FloatSpecifications {
                     accept_inf: true, // it can always overflow
+                    // `hypot(x, y)` is zero only if both `x` and `y` are zero
                     accept_zero: float.s.accept_zero && rhs.s.accept_zero,
                     accept_positive: true,
                     accept_negative: false,
@@ -196,51 +299,11 @@ pub fn get_impl_self_rhs() -> Vec<OpRhs> {
                 // (0.0_f64).min(-0.0_f64) == 0.0_f64
                 let can_confuse_zero = can_one_be_zero_neg_and_the_other_zero_pos(&float.s, &rhs.s);
 
-                let can_be_neg_inf = (float.s.accept_negative && float.s.accept_inf)
-                    || (rhs.s.accept_negative && rhs.s.accept_inf);
-                let can_be_pos_inf = float.s.accept_positive
-                    && float.s.accept_inf
-                    && rhs.s.accept_positive
-                    && rhs.s.accept_inf;
-                let accept_inf = can_be_neg_inf || can_be_pos_inf;
-
-                let output_def = if !float.s.accept_positive {
-                    let accept_zero =
-                        float.s.accept_zero && (rhs.s.accept_zero || rhs.s.accept_positive);
-
-                    FloatSpecifications {
-                        accept_inf,
-                        accept_zero,
-                        accept_positive: accept_zero || can_confuse_zero,
-                        accept_negative: true,
-                    }
-                } else if !rhs.s.accept_positive {
-                    let accept_zero =
-                        rhs.s.accept_zero && (float.s.accept_zero || float.s.accept_positive);
-
-                    FloatSpecifications {
-                        accept_inf,
-                        accept_zero,
-                        accept_positive: accept_zero || can_confuse_zero,
-                        accept_negative: true,
-                    }
-                } else if !float.s.accept_negative && !rhs.s.accept_negative {
-                    FloatSpecifications {
-                        accept_inf,
-                        accept_zero: float.s.accept_zero || rhs.s.accept_zero,
-                        accept_positive: true,
-                        accept_negative: false,
-                    }
-                } else {
-                    FloatSpecifications {
-                        accept_inf: can_be_neg_inf || can_be_pos_inf,
-                        accept_zero: float.s.accept_zero || rhs.s.accept_zero,
-                        accept_positive: true,
-                        accept_negative: true,
-                    }
-                };
-
-                ReturnTypeSpecification::FloatSpecifications(output_def)
+                ReturnTypeSpecification::FloatSpecifications(min_result(
+                    &float.s,
+                    &rhs.s,
+                    can_confuse_zero,
+                ))
             }))
             .build(),
         OpRhsBuilder::new("Max", "max")
@@ -254,56 +317,56 @@ pub fn get_impl_self_rhs() -> Vec<OpRhs> {
                 // fmin(+0.0, -0.0) returns either -0.0 or 0.0
                 let can_confuse_zero = can_one_be_zero_neg_and_the_other_zero_pos(&float.s, &rhs.s);
 
-                let can_be_neg_inf = (float.s.accept_negative && float.s.accept_inf)
-                    && (rhs.s.accept_negative && rhs.s.accept_inf);
-                let can_be_pos_inf = (float.s.accept_positive && float.s.accept_inf)
-                    || (rhs.s.accept_positive && rhs.s.accept_inf);
-
-                let accept_inf = can_be_neg_inf || can_be_pos_inf;
-
-                let output_def = if !float.s.accept_negative {
-                    let accept_zero =
-                        float.s.accept_zero && (rhs.s.accept_zero || rhs.s.accept_negative);
-
-                    FloatSpecifications {
-                        accept_inf,
-                        accept_zero,
-                        accept_positive: true,
-                        accept_negative: accept_zero && can_confuse_zero,
-                    }
-                } else if !rhs.s.accept_negative {
-                    let accept_zero =
-                        rhs.s.accept_zero && (float.s.accept_zero || float.s.accept_negative);
+                ReturnTypeSpecification::FloatSpecifications(max_result(
+                    &float.s,
+                    &rhs.s,
+                    can_confuse_zero,
+                ))
+            }))
+            .build(),
+        OpRhsBuilder::new("TotalMin", "total_min")
+            .op_is_commutative()
+            .op_fn(Box::new(|_, _| {
+                quote! {{
+                    let (a, b) = (self.get(), rhs.get());
 
-                    FloatSpecifications {
-                        accept_inf,
-                        accept_zero,
-                        accept_positive: true,
-                        accept_negative: accept_zero && can_confuse_zero,
-                    }
-                } else if !float.s.accept_positive && !rhs.s.accept_positive {
-                    FloatSpecifications {
-                        accept_inf,
-                        accept_zero: float.s.accept_zero || rhs.s.accept_zero,
-                        accept_positive: false,
-                        accept_negative: true,
-                    }
-                } else {
-                    FloatSpecifications {
-                        accept_inf: can_be_neg_inf || can_be_pos_inf,
-                        accept_zero: float.s.accept_zero || rhs.s.accept_zero,
-                        accept_positive: true,
-                        accept_negative: true,
-                    }
-                };
+                    if a.total_cmp(&b).is_le() { a } else { b }
+                }}
+            }))
+            .op_test_primitive(Box::new(|var1, var2| {
+                quote! {{
+                    if #var1.total_cmp(&#var2).is_le() { #var1 } else { #var2 }
+                }}
+            }))
+            .result(Box::new(|float, rhs| {
+                // `-0.0` is less than `0.0`, so the result is always the same
+                ReturnTypeSpecification::FloatSpecifications(min_result(&float.s, &rhs.s, false))
+            }))
+            .build(),
+        OpRhsBuilder::new("TotalMax", "total_max")
+            .op_is_commutative()
+            .op_fn(Box::new(|_, _| {
+                quote! {{
+                    let (a, b) = (self.get(), rhs.get());
 
-                ReturnTypeSpecification::FloatSpecifications(output_def)
+                    if a.total_cmp(&b).is_ge() { a } else { b }
+                }}
+            }))
+            .op_test_primitive(Box::new(|var1, var2| {
+                quote! {{
+                    if #var1.total_cmp(&#var2).is_ge() { #var1 } else { #var2 }
+                }}
+            }))
+            .result(Box::new(|float, rhs| {
+                // `0.0` is greater than `-0.0`, so the result is always the same
+                ReturnTypeSpecification::FloatSpecifications(max_result(&float.s, &rhs.s, false))
             }))
             .build(),
         #[cfg(any(feature = "std", feature = "libm"))]
         OpRhsBuilder::new("Copysign", "copysign")
             .op_test_primitive(Box::new(|var1, var2| quote! { #var1.copysign(#var2) }))
             .result(Box::new(|float, rhs| {
+                // The magnitude (zero, infinity) comes from `self`, the sign only from `rhs`
                 ReturnTypeSpecification::FloatSpecifications(FloatSpecifications {
                     accept_inf: float.s.accept_inf,
                     accept_zero: float.s.accept_zero,
@@ -321,14 +384,17 @@ pub fn get_impl_self_rhs() -> Vec<OpRhs> {
                 let spec_a = &float.s;
                 let spec_b = &rhs.s;
 
-                let can_be_positive = spec_a.accept_positive || spec_b.accept_positive;
-                let can_be_negative = spec_a.accept_negative || spec_b.accept_negative;
+                // The result has the sign of `y` (`self`), even for zeros:
+                // `atan2(0.0, -0.0) = π` and `atan2(-0.0, -0.0) = -π`
+                // If `x` can't be positive, the magnitude of the result is at least π/2.
+                // Otherwise, `atan2(0.0, x) = 0.0` and `atan2(y, x)` may underflow.
+                let can_be_zero = spec_b.accept_positive;
 
                 ReturnTypeSpecification::FloatSpecifications(FloatSpecifications {
                     accept_inf: false,
-                    accept_zero: true,
-                    accept_positive: can_be_positive,
-                    accept_negative: can_be_negative,
+                    accept_zero: can_be_zero,
+                    accept_positive: spec_a.accept_positive,
+                    accept_negative: spec_a.accept_negative,
                 })
             }))
             .build(),
@@ -337,9 +403,12 @@ pub fn get_impl_self_rhs() -> Vec<OpRhs> {
             .op_test_primitive(Box::new(|var1, var2| quote! { #var1.powf(#var2) }))
             .comment("If the base is negative and the exponent is not an integer, the result is `NaN`.")
             .result(Box::new(|float, _| {
+                // `(-2.0).powf(0.5)` is `NaN`
                 if float.s.accept_negative {
                     ReturnTypeSpecification::NativeFloat
                 } else {
+                    // `0.0.powf(-1.0)` is `inf` and `0.0.powf(1.0)` is `0.0`,
+                    // and any other base can overflow or underflow with a large exponent.
                     ReturnTypeSpecification::FloatSpecifications(FloatSpecifications {
                         accept_negative: false,
                         accept_positive: true,
@@ -349,6 +418,142 @@ pub fn get_impl_self_rhs() -> Vec<OpRhs> {
                 }
             }))
             .build(),
+        OpRhsBuilder::new("Midpoint", "midpoint")
+            .op_is_commutative()
+            .op_fn(Box::new(|_, _| {
+                quote! {{
+                    let (a, b) = (self.get(), rhs.get());
+                    let sum = a + b;
+
+                    if sum.is_infinite() && a.is_finite() && b.is_finite() {
+                        // Both are large enough to be halved without rounding
+                        (a / 2.0) + (b / 2.0)
+                    } else {
+                        sum / 2.0
+                    }
+                }}
+            }))
+            .op_test_primitive(Box::new(|var1, var2| {
+                quote! {{
+                    let sum = #var1 + #var2;
+
+                    if sum.is_infinite() && #var1.is_finite() && #var2.is_finite() {
+                        (#var1 / 2.0) + (#var2 / 2.0)
+                    } else {
+                        sum / 2.0
+                    }
+                }}
+            }))
+            .comment("The midpoint of two opposite infinity is `NaN`.")
+            .result(Box::new(|float, rhs| {
+                match add_result(&float.s, &rhs.s) {
+                    ReturnTypeSpecification::NativeFloat => ReturnTypeSpecification::NativeFloat,
+                    ReturnTypeSpecification::FloatSpecifications(mut output_spec) => {
+                        // Same as `add` but it can't overflow.
+                        output_spec.accept_inf = float.s.accept_inf || rhs.s.accept_inf;
+                        // Halving the sum rounds it to zero if it is the smallest subnormal,
+                        // e.g. `midpoint(f64::from_bits(1), 0.0)`, which needs one of the operands to be zero
+                        // or the operands to have opposite signs. Otherwise the sum of two non-zero numbers
+                        // of the same sign is at least twice the smallest subnormal.
+                        output_spec.accept_zero |= float.s.accept_zero || rhs.s.accept_zero;
+
+                        ReturnTypeSpecification::FloatSpecifications(output_spec)
+                    }
+                }
+            }))
+            .build(),
+        OpRhsBuilder::new("PositiveDiff", "positive_diff")
+            .op_fn(Box::new(|_, _| {
+                quote! {{
+                    let diff = self.get() - rhs.get();
+
+                    if diff > 0.0 { diff } else { 0.0 }
+                }}
+            }))
+            .op_test_primitive(Box::new(|var1, var2| {
+                quote! {{
+                    let diff = #var1 - #var2;
+
+                    if diff > 0.0 { diff } else { 0.0 }
+                }}
+            }))
+            .comment("The difference of two infinity of the same sign is `+0.0` instead of `NaN`.")
+            .result(Box::new(|float, rhs| {
+                let spec_a = &float.s;
+                let spec_b = &rhs.s;
+
+                // `self <= rhs` is possible if they can have the same sign, or both be zero.
+                let can_be_zero = spec_a.accept_negative
+                    || spec_b.accept_positive
+                    || (spec_a.accept_zero && spec_b.accept_zero);
+
+                // `MAX - MIN` overflows
+                let can_overflow = spec_a.accept_positive && spec_b.accept_negative;
+
+                ReturnTypeSpecification::FloatSpecifications(FloatSpecifications {
+                    accept_inf: can_overflow
+                        || (spec_a.accept_inf && spec_a.accept_positive)
+                        || (spec_b.accept_inf && spec_b.accept_negative),
+                    accept_zero: can_be_zero,
+                    // `+0.0` is returned instead of any negative difference
+                    accept_positive: true,
+                    accept_negative: false,
+                })
+            }))
+            .build(),
+        OpRhsBuilder::new("AbsDiff", "abs_diff")
+            .op_fn(Box::new(|_, _| {
+                quote! {{
+                    let diff = self.get() - rhs.get();
+
+                    // `abs` is not available without `std`, and `-0.0` must become `+0.0`
+                    if diff.is_sign_negative() { -diff } else { diff }
+                }}
+            }))
+            .op_test_primitive(Box::new(|var1, var2| {
+                quote! {{
+                    let diff = #var1 - #var2;
+
+                    if diff.is_sign_negative() { -diff } else { diff }
+                }}
+            }))
+            .op_is_commutative()
+            .comment("The difference of two infinity of the same sign is `NaN`.")
+            .result(Box::new(|float, rhs| {
+                let spec_a = &float.s;
+                let spec_b = &rhs.s;
+
+                let can_sign_be_different = (spec_a.accept_negative && spec_b.accept_positive)
+                    || (spec_a.accept_positive && spec_b.accept_negative);
+                let can_sign_be_same = (spec_a.accept_negative && spec_b.accept_negative)
+                    || (spec_a.accept_positive && spec_b.accept_positive);
+
+                // As for `sub`, `inf - inf` is `NaN`
+                if spec_a.accept_inf && spec_b.accept_inf && can_sign_be_same {
+                    return ReturnTypeSpecification::NativeFloat;
+                }
+
+                ReturnTypeSpecification::FloatSpecifications(FloatSpecifications {
+                    // `MAX - MIN` overflows
+                    accept_inf: spec_a.accept_inf || spec_b.accept_inf || can_sign_be_different,
+                    accept_zero: can_sign_be_same || (spec_a.accept_zero && spec_b.accept_zero),
+                    accept_positive: true,
+                    accept_negative: false,
+                })
+            }))
+            .build(),
+        #[cfg(any(feature = "std", feature = "libm"))]
+        OpRhsBuilder::new("Log", "log")
+            .op_test_primitive(Box::new(|var1, var2| quote! { #var1.log(#var2) }))
+            .comment("The result is always `NaN` for `1.0.log(1.0)`, `0.0.log(0.0)` and negative values.")
+            .result(Box::new(|_, _| {
+                // The result is `self.ln() / rhs.ln()`.
+                // All types accepting positive numbers accept `1.0`, and `1.0.ln()` is `0.0`
+                // so `1.0.log(1.0)` is `0.0 / 0.0`.
+                // All the other types accept negative numbers, and `(-1.0).ln()` is `NaN`.
+                ReturnTypeSpecification::NativeFloat
+            }))
+            .build(),
     ];
 
     #[cfg(any(feature = "std", feature = "libm"))]
@@ -400,6 +605,51 @@ pub fn get_impl_self_rhs() -> Vec<OpRhs> {
         }));
 
         ops.push(div_euclid.build());
+
+        let rem_euclid = OpRhsBuilder::new("RemEuclid", "rem_euclid");
+
+        #[cfg(feature = "std")]
+        let rem_euclid =
+            rem_euclid.op_fn(Box::new(|_, _| quote! { self.get().rem_euclid(rhs.get()) }));
+        #[cfg(all(feature = "libm", not(feature = "std")))]
+        let rem_euclid = rem_euclid.op_fn(Box::new(
+            |_, _| quote! { num_traits::Euclid::rem_euclid(&self.get(),&rhs.get()) },
+        ));
+
+        #[cfg(feature = "std")]
+        let rem_euclid =
+            rem_euclid.op_test_primitive(Box::new(|var1, var2| quote! { #var1.rem_euclid(#var2) }));
+
+        #[cfg(all(feature = "libm", not(feature = "std")))]
+        let rem_euclid = rem_euclid.op_test_primitive(Box::new(
+            |var1, var2| quote! { num_traits::Euclid::rem_euclid(&#var1, &#var2) },
+        ));
+
+        let rem_euclid = rem_euclid
+            .comment("The result is never negative except for `-0.0`, returned when `self` is a negative multiple of `rhs` (or `-0.0`).")
+            .result(Box::new(|float, rhs| {
+                let spec_a = &float.s;
+                let spec_b = &rhs.s;
+
+                // Same as `%`
+                let can_be_nan = spec_b.accept_zero || spec_a.accept_inf;
+
+                if can_be_nan {
+                    ReturnTypeSpecification::NativeFloat
+                } else {
+                    // `r = self % rhs` has the sign of `self`, and `r + |rhs|` is returned if `r < 0.0`.
+                    // `(-6.0).rem_euclid(3.0)` is `-0.0`
+                    // `(-1.0).rem_euclid(inf)` is `inf`
+                    ReturnTypeSpecification::FloatSpecifications(FloatSpecifications {
+                        accept_inf: spec_a.accept_negative && spec_b.accept_inf,
+                        accept_zero: true,
+                        accept_positive: true,
+                        accept_negative: spec_a.accept_negative,
+                    })
+                }
+            }));
+
+        ops.push(rem_euclid.build());
     };
 
     ops
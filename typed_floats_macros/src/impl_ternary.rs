@@ -0,0 +1,84 @@
+use quote::quote;
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::impl_self_rhs::{add_result, mul_result};
+use crate::types::{FloatSpecifications, OpTernary, OpTernaryBuilder, ReturnTypeSpecification};
+
+pub fn get_impl_ternary() -> Vec<OpTernary> {
+    vec![
+        OpTernaryBuilder::new("Clamp", "clamp")
+            .op_test_precondition(Box::new(|_, min, max| quote! { #min <= #max }))
+            .result(Box::new(|float, min, max| {
+                let (x, min, max) = (&float.s, &min.s, &max.s);
+
+                // The result is `self` if it is between the bounds, otherwise the crossed bound.
+                // The bounds are compared by value, so `self` is returned as is
+                // if it is a zero and a bound is a zero of the other sign.
+                // As `min <= max`, a bound can only be zero if the other one allows it.
+                let can_min_be_zero = min.accept_zero && (max.accept_zero || max.accept_positive);
+                let can_max_be_zero = max.accept_zero && (min.accept_zero || min.accept_negative);
+                let can_x_be_zero = x.accept_zero
+                    && (min.accept_zero || min.accept_negative)
+                    && (max.accept_zero || max.accept_positive);
+
+                let can_be_pos_inf = max.accept_inf
+                    && max.accept_positive
+                    && ((x.accept_inf && x.accept_positive)
+                        || (min.accept_inf && min.accept_positive));
+                let can_be_neg_inf = min.accept_inf
+                    && min.accept_negative
+                    && ((x.accept_inf && x.accept_negative)
+                        || (max.accept_inf && max.accept_negative));
+
+                let output_spec = FloatSpecifications {
+                    accept_inf: can_be_pos_inf || can_be_neg_inf,
+                    accept_zero: can_x_be_zero
+                        || (can_min_be_zero && x.accept_negative)
+                        || (can_max_be_zero && x.accept_positive),
+                    accept_positive: ((min.accept_positive || x.accept_positive)
+                        && max.accept_positive)
+                        || (can_min_be_zero && min.accept_positive && x.accept_negative)
+                        || (can_max_be_zero && max.accept_positive && x.accept_positive)
+                        || (can_x_be_zero && x.accept_positive),
+                    accept_negative: ((max.accept_negative || x.accept_negative)
+                        && min.accept_negative)
+                        || (can_max_be_zero && max.accept_negative && x.accept_positive)
+                        || (can_min_be_zero && min.accept_negative && x.accept_negative)
+                        || (can_x_be_zero && x.accept_negative),
+                };
+
+                if !output_spec.accept_positive && !output_spec.accept_negative {
+                    // `min > max`: it always panics, so any type would be correct
+                    return ReturnTypeSpecification::FloatSpecifications(x.clone());
+                }
+
+                ReturnTypeSpecification::FloatSpecifications(output_spec)
+            }))
+            .build(),
+        #[cfg(any(feature = "std", feature = "libm"))]
+        OpTernaryBuilder::new("MulAdd", "mul_add")
+            .result(Box::new(|float, a, b| {
+                let mut product = match mul_result(&float.s, &a.s) {
+                    ReturnTypeSpecification::NativeFloat => {
+                        return ReturnTypeSpecification::NativeFloat
+                    }
+                    ReturnTypeSpecification::FloatSpecifications(product) => product,
+                };
+
+                // The product isn't rounded before the addition, so it can't overflow:
+                // `inf - inf` is only possible if one of the factors is infinite.
+                product.accept_inf = float.s.accept_inf || a.s.accept_inf;
+
+                match add_result(&product, &b.s) {
+                    ReturnTypeSpecification::NativeFloat => ReturnTypeSpecification::NativeFloat,
+                    ReturnTypeSpecification::FloatSpecifications(mut output_spec) => {
+                        // But the result itself can always overflow
+                        output_spec.accept_inf = true;
+
+                        ReturnTypeSpecification::FloatSpecifications(output_spec)
+                    }
+                }
+            }))
+            .build(),
+    ]
+}
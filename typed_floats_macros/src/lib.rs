@@ -12,13 +12,29 @@ use types::{FloatDefinition, FloatSpecifications, ReturnTypeDefinition};
 
 mod impl_self;
 use impl_self::get_impl_self;
+#[cfg(any(feature = "std", feature = "libm"))]
+use impl_self::{get_impl_powi_const, get_impl_sin_cos, PowiExponent, POWI_CONST_EXPONENTS};
 
 mod impl_self_rhs;
 use impl_self_rhs::get_impl_self_rhs;
 
+mod impl_ternary;
+use impl_ternary::get_impl_ternary;
+
+mod impl_checked;
+use impl_checked::get_impl_checked;
+
+mod impl_saturating;
+use impl_saturating::get_impl_saturating;
+
+mod impl_lerp;
+use impl_lerp::get_impl_lerp;
+
 mod add_doc;
 use add_doc::generate_main_description;
 
+mod result_possibilities;
+
 mod gen_tests;
 
 static F32: &str = "f32";
@@ -185,6 +201,53 @@ pub fn generate_tests_self_rhs(input: proc_macro::TokenStream) -> proc_macro::To
     output.into()
 }
 
+/// Generate the tests checking that `from_bits` round-trips every value.
+#[proc_macro]
+pub fn generate_tests_from_bits(_input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let mut output = proc_macro2::TokenStream::new();
+
+    output.extend(gen_tests::generate_tests_from_bits(F32));
+    output.extend(gen_tests::generate_tests_from_bits(F64));
+
+    output.into()
+}
+
+/// Generate a test running the given body over the test values,
+/// for each float type and each combination of the bound types.
+///
+/// ```ignore
+/// typed_floats_macros::generate_tests_values!(frexp, Type in [NonNaN, Positive], {
+///     for &value in &values {
+///         let Ok(x) = Type::new(value) else {
+///             continue;
+///         };
+///         // ...
+///     }
+/// });
+/// ```
+///
+/// `Type` alone binds each of the 12 types, and several bindings can be given
+/// (e.g. `Lhs in [...], Rhs in [...]`). The float type is `Float`.
+#[proc_macro]
+pub fn generate_tests_values(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as gen_tests::TestsValues);
+
+    gen_tests::generate_tests_values(&input).into()
+}
+
+/// Generate the tests for ternary operations.
+#[proc_macro]
+pub fn generate_tests_ternary(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let filter = input.to_string();
+
+    let mut output = proc_macro2::TokenStream::new();
+
+    output.extend(gen_tests::generate_tests_ternary(F32, &filter));
+    output.extend(gen_tests::generate_tests_ternary(F64, &filter));
+
+    output.into()
+}
+
 /// Return the FloatDefinition for the given type
 fn get_definitions(float_type: &'static str) -> [FloatDefinition; 12] {
     TYPES
@@ -199,6 +262,26 @@ fn get_definitions(float_type: &'static str) -> [FloatDefinition; 12] {
         .expect("Failed to convert to array")
 }
 
+/// Generate the `ExponentClass` implementations of the exponents usable with `powi_const`.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[proc_macro]
+pub fn generate_exponent_classes(_input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let mut output = proc_macro2::TokenStream::new();
+
+    for n in POWI_CONST_EXPONENTS {
+        let class = PowiExponent::of(n).ident();
+        let n = proc_macro2::Literal::i32_unsuffixed(n);
+
+        output.extend(quote! {
+            impl ExponentClass for Exponent<{ #n }> {
+                type Class = #class;
+            }
+        });
+    }
+
+    output.into()
+}
+
 /// Generate the documentation
 #[proc_macro]
 pub fn generate_docs(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -232,6 +315,7 @@ fn do_generate_floats(floats: &[FloatDefinition]) -> proc_macro2::TokenStream {
 
     let ops = get_impl_self();
     let ops_rhs = get_impl_self_rhs();
+    let ops_ternary = get_impl_ternary();
 
     for float_a in floats {
         for float_b in floats {
@@ -278,16 +362,44 @@ fn do_generate_floats(floats: &[FloatDefinition]) -> proc_macro2::TokenStream {
             output.extend(op.get_impl(float_a, floats));
         }
 
+        #[cfg(any(feature = "std", feature = "libm"))]
+        output.extend(get_impl_sin_cos(float_a, floats, &ops));
+
+        #[cfg(any(feature = "std", feature = "libm"))]
+        output.extend(get_impl_powi_const(float_a, floats));
+
+        for op in &ops_rhs {
+            output.extend(op.get_impl_raw(float_a));
+        }
+
         for float_b in floats {
             for op in &ops_rhs {
                 output.extend(op.get_impl(float_a, float_b, floats));
             }
+
+            output.extend(get_impl_checked(float_a, float_b, floats, &ops_rhs));
+            output.extend(get_impl_saturating(float_a, float_b, floats, &ops_rhs));
+            output.extend(get_impl_lerp(float_a, float_b, floats));
+
+            for float_c in floats {
+                for op in &ops_ternary {
+                    output.extend(op.get_impl(float_a, float_b, float_c, floats));
+                }
+            }
         }
     }
 
     output
 }
 
+/// Generate the `result_possibilities` function and the `POSSIBILITIES` constants.
+#[proc_macro]
+pub fn generate_result_possibilities(_input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let floats_f64 = get_definitions("f64");
+
+    result_possibilities::generate_result_possibilities(&floats_f64).into()
+}
+
 #[proc_macro]
 pub fn test_values(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ident = syn::parse_macro_input!(input as syn::Ident);
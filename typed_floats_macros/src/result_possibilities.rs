@@ -0,0 +1,119 @@
+use quote::quote;
+
+use crate::impl_self::get_impl_self;
+use crate::{FloatDefinition, FloatSpecifications, ReturnTypeDefinition};
+
+fn possibilities(s: &FloatSpecifications, accept_nan: bool) -> proc_macro2::TokenStream {
+    let FloatSpecifications {
+        accept_inf,
+        accept_zero,
+        accept_positive,
+        accept_negative,
+    } = s;
+
+    quote! {
+        FloatPossibilities {
+            accept_nan: #accept_nan,
+            accept_inf: #accept_inf,
+            accept_zero: #accept_zero,
+            accept_positive: #accept_positive,
+            accept_negative: #accept_negative,
+        }
+    }
+}
+
+const NATIVE_FLOAT: FloatSpecifications = FloatSpecifications {
+    accept_inf: true,
+    accept_zero: true,
+    accept_positive: true,
+    accept_negative: true,
+};
+
+pub(crate) fn generate_result_possibilities(
+    floats: &[FloatDefinition],
+) -> proc_macro2::TokenStream {
+    let mut output = proc_macro2::TokenStream::new();
+
+    for float in floats {
+        let name = float.name_ident();
+        let arg = possibilities(&float.s, false);
+        let doc = format!(" The values accepted by `{}`.", float.name);
+
+        output.extend(quote! {
+            impl<T> crate::#name<T> {
+                #[doc = #doc]
+                pub const POSSIBILITIES: FloatPossibilities = #arg;
+            }
+        });
+    }
+
+    let ops = get_impl_self();
+
+    let mut ops_doc = proc_macro2::TokenStream::new();
+    let mut ops_arms = proc_macro2::TokenStream::new();
+
+    for op in &ops {
+        let key = op.key;
+        let doc = format!(" - `{key}`");
+
+        ops_doc.extend(quote! {
+            #[doc = #doc]
+        });
+
+        let mut arms = proc_macro2::TokenStream::new();
+
+        for float in floats {
+            let arg = possibilities(&float.s, false);
+
+            let result = match op.get_result(float, floats) {
+                ReturnTypeDefinition::NativeFloat => possibilities(&NATIVE_FLOAT, true),
+                ReturnTypeDefinition::FloatDefinition(def) => possibilities(&def.s, false),
+            };
+
+            arms.extend(quote! {
+                #arg => Some(#result),
+            });
+        }
+
+        ops_arms.extend(quote! {
+            #key => match arg {
+                #arms
+                _ => None,
+            },
+        });
+    }
+
+    output.extend(quote! {
+        /// Returns the values the result of the unary operation `op` can take,
+        /// when applied to a value of the type accepting exactly `arg`.
+        ///
+        /// This is the same analysis used to choose the return type of each method,
+        /// so the result is always the [`POSSIBILITIES`](crate::NonNaN::POSSIBILITIES) of one of the 12 types,
+        /// or every possibility including `NaN` when the method returns the primitive type.
+        ///
+        /// Returns `None` if `op` is not supported, or if `arg` is not the `POSSIBILITIES` of one of the 12 types.
+        ///
+        /// `op` is the name of the method. The supported operations, depending on the enabled features, are:
+        #ops_doc
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use typed_floats::*;
+        /// let result = result_possibilities("neg", StrictlyPositive::<f64>::POSSIBILITIES);
+        ///
+        /// assert_eq!(result, Some(StrictlyNegative::<f64>::POSSIBILITIES));
+        ///
+        /// assert_eq!(result_possibilities("unknown", NonNaN::<f64>::POSSIBILITIES), None);
+        /// ```
+        #[must_use]
+        pub fn result_possibilities(op: &str, arg: FloatPossibilities) -> Option<FloatPossibilities> {
+            match op {
+                #ops_arms
+                _ => None,
+            }
+        }
+    });
+
+    output
+}
@@ -2,6 +2,8 @@ use proc_macro2::Span;
 use quote::quote;
 use syn::Ident;
 
+use crate::add_doc::comment_line;
+
 #[derive(Clone, Debug)]
 pub struct FloatSpecifications {
     pub(crate) accept_inf: bool,
@@ -33,6 +35,11 @@ impl FloatDefinition {
         quote! { #name<#float_type> }
     }
 
+    /// The name of the type as written in the code, e.g. `NonNaN<f64>`.
+    pub(crate) fn full_type_name(&self) -> String {
+        format!("{}<{}>", self.name, self.float_type)
+    }
+
     pub(crate) fn call_tokens(&self) -> proc_macro2::TokenStream {
         let name = self.name_ident();
         let float_type = self.float_type_ident();
@@ -42,6 +49,17 @@ impl FloatDefinition {
 }
 
 impl FloatSpecifications {
+    /// The specifications accepting the values of both `self` and `other`.
+    #[allow(dead_code)] // depending on the enabled features, this function might not be used
+    pub(crate) const fn union(&self, other: &Self) -> Self {
+        Self {
+            accept_inf: self.accept_inf || other.accept_inf,
+            accept_zero: self.accept_zero || other.accept_zero,
+            accept_positive: self.accept_positive || other.accept_positive,
+            accept_negative: self.accept_negative || other.accept_negative,
+        }
+    }
+
     pub(crate) const fn can_fit_into(&self, into: &Self) -> bool {
         (!self.accept_inf || into.accept_inf)
             && (!self.accept_zero || into.accept_zero)
@@ -143,10 +161,32 @@ pub fn output_name(output: &ReturnTypeDefinition, float_type: &Ident) -> proc_ma
     }
 }
 
+/// The returned value of an operation whose result type is the primitive float.
+///
+/// With `--cfg typed_floats_debug_checks`, a debug assertion checks that the result isn't `NaN`,
+/// to find the operations whose operand types are weaker than expected.
+/// The `cfg` is checked in the generated code, so it is the one of the crate using the macros.
+pub fn native_float_return(
+    op: &proc_macro2::TokenStream,
+    operation: &str,
+) -> proc_macro2::TokenStream {
+    let message = format!("`{operation}` returned `NaN`");
+
+    quote! {
+        {
+            let value = #op;
+            #[cfg(typed_floats_debug_checks)]
+            debug_assert!(!value.is_nan(), #message);
+            value
+        }
+    }
+}
+
 type OpCallback = Box<dyn Fn(&FloatDefinition) -> proc_macro2::TokenStream>;
 type SimpleResultCallback = Box<dyn Fn(&FloatDefinition) -> ReturnTypeSpecification>;
 type ResultCallback = Box<dyn Fn(&FloatDefinition, &[FloatDefinition]) -> ReturnTypeDefinition>;
 type TestCallback = Box<dyn Fn(&Ident) -> proc_macro2::TokenStream>;
+type TestPrimitiveCallback = Box<dyn Fn(&Ident, &Ident) -> proc_macro2::TokenStream>;
 
 pub struct Op {
     pub(crate) key: &'static str,
@@ -160,6 +200,7 @@ pub struct Op {
     op: OpCallback,
     result: ResultCallback,
     test: TestCallback,
+    test_primitive: Option<TestPrimitiveCallback>,
 }
 
 pub struct OpBuilder {
@@ -183,6 +224,7 @@ impl OpBuilder {
                 op: Box::new(move |_| quote! { self.get().#fn_op() }),
                 result: Box::new(|_, _| panic!("No result defined")),
                 test: Box::new(move |var| quote! { #var.#fn_test() }),
+                test_primitive: None,
             },
         }
     }
@@ -213,6 +255,12 @@ impl OpBuilder {
         self
     }
 
+    /// Test to run on the primitive type, if it differs from the one of `op_test`
+    pub fn op_test_primitive(mut self, op: TestPrimitiveCallback) -> Self {
+        self.op.test_primitive = Some(op);
+        self
+    }
+
     #[allow(dead_code)] // depending on the enabled features, this function might not be used
     pub fn params(mut self, params: proc_macro2::TokenStream) -> Self {
         self.op.params = params;
@@ -263,6 +311,20 @@ impl Op {
         (self.test)(&var)
     }
 
+    pub(crate) fn get_test_primitive(
+        &self,
+        var: &str,
+        float_type: &Ident,
+    ) -> proc_macro2::TokenStream {
+        match &self.test_primitive {
+            Some(test_primitive) => {
+                let var = Ident::new(var, Span::call_site());
+                (test_primitive)(&var, float_type)
+            }
+            None => self.get_test(var),
+        }
+    }
+
     pub(crate) fn get_impl(
         &self,
         float: &FloatDefinition,
@@ -282,12 +344,14 @@ impl Op {
                     unsafe { #output_call::new_unchecked(#op) }
                 }
             }
-            ReturnTypeDefinition::NativeFloat => {
-                quote! { #op }
-            }
+            ReturnTypeDefinition::NativeFloat => native_float_return(
+                op,
+                &format!("{}::{}()", float.full_type_name(), self.fn_name),
+            ),
         };
 
-        let output_name = output_name(&output, &float.float_type_ident());
+        let float_type = &float.float_type_ident();
+        let output_name = output_name(&output, float_type);
 
         let fn_ident = Ident::new(self.fn_name, Span::call_site());
 
@@ -295,7 +359,7 @@ impl Op {
 
         let params = &self.params;
 
-        if let Some(trait_name) = &self.trait_name {
+        let mut res = if let Some(trait_name) = &self.trait_name {
             let trait_name: proc_macro2::TokenStream = trait_name.parse().unwrap();
 
             quote! {
@@ -321,7 +385,28 @@ impl Op {
                     }
                 }
             }
-        }
+        };
+
+        let fn_raw_ident = Ident::new(&format!("{}_raw", self.fn_name), Span::call_site());
+        let raw_doc = comment_line(&format!(
+            "Same as `{}`, but returns the result as a primitive, without checking or wrapping it.",
+            self.fn_name
+        ));
+
+        res.extend(quote! {
+            impl #float_full_type {
+                #raw_doc
+                ///
+                /// It is meant for the algorithms that need the raw result, which may be `NaN`.
+                #[inline]
+                #[must_use]
+                pub fn #fn_raw_ident(#params) -> #float_type {
+                    #op
+                }
+            }
+        });
+
+        res
     }
 }
 
@@ -352,6 +437,7 @@ impl OpRhsBuilder {
                 fn_name,
                 trait_name,
                 assign: None,
+                bin_op: None,
                 op_is_commutative: false,
                 skip_check_return_type_strictness: false,
                 comment: None,
@@ -373,6 +459,7 @@ impl OpRhsBuilder {
 
     pub(crate) fn bin_op(mut self, bin_op: &'static str) -> Self {
         self.op.display = bin_op;
+        self.op.bin_op = Some(bin_op);
 
         let op_token: syn::BinOp = syn::parse_str(bin_op).unwrap();
         let op_token2 = op_token;
@@ -441,6 +528,7 @@ pub struct OpRhs {
     pub(crate) fn_name: &'static str,
     pub(crate) trait_name: &'static str,
     pub(crate) assign: Option<(&'static str, &'static str)>,
+    pub(crate) bin_op: Option<&'static str>,
     pub(crate) op_is_commutative: bool,
     pub(crate) skip_check_return_type_strictness: bool,
     pub(crate) comment: Option<&'static str>,
@@ -501,9 +589,15 @@ impl OpRhs {
                     unsafe { Self::Output::new_unchecked(#op) }
                 }
             }
-            ReturnTypeDefinition::NativeFloat => {
-                quote! { #op }
-            }
+            ReturnTypeDefinition::NativeFloat => native_float_return(
+                op,
+                &format!(
+                    "{}::{}({})",
+                    float.full_type_name(),
+                    self.fn_name,
+                    rhs.full_type_name()
+                ),
+            ),
         };
 
         let output_name = output_name(&output, &float.float_type_ident());
@@ -544,4 +638,240 @@ impl OpRhs {
 
         res
     }
+
+    /// The `_raw` method of the operators, taking any `rhs` that can be converted into the primitive type.
+    /// It is a single generic method, as inherent methods can't be overloaded for each type of `rhs`.
+    pub(crate) fn get_impl_raw(&self, float: &FloatDefinition) -> proc_macro2::TokenStream {
+        let Some(bin_op) = self.bin_op else {
+            return proc_macro2::TokenStream::new();
+        };
+
+        let op_token: syn::BinOp = syn::parse_str(bin_op).unwrap();
+
+        let float_full_type = &float.full_type_ident();
+        let float_type = &float.float_type_ident();
+
+        let fn_raw_ident = Ident::new(&format!("{}_raw", self.fn_name), Span::call_site());
+        let raw_doc = comment_line(&format!(
+            "Computes `self {bin_op} rhs` and returns the result as a primitive, without checking or wrapping it."
+        ));
+
+        quote! {
+            impl #float_full_type {
+                #raw_doc
+                ///
+                /// It is meant for the algorithms that need the raw result, which may be `NaN`.
+                #[inline]
+                #[must_use]
+                pub fn #fn_raw_ident(self, rhs: impl Into<#float_type>) -> #float_type {
+                    self.get() #op_token rhs.into()
+                }
+            }
+        }
+    }
+}
+
+type TestTernaryCallback = Box<dyn Fn(&Ident, &Ident, &Ident) -> proc_macro2::TokenStream>;
+type OpTernaryCallback =
+    Box<dyn Fn(&FloatDefinition, &FloatDefinition, &FloatDefinition) -> proc_macro2::TokenStream>;
+type ResultTernaryCallback = Box<
+    dyn Fn(
+        &FloatDefinition,
+        &FloatDefinition,
+        &FloatDefinition,
+        &[FloatDefinition],
+    ) -> ReturnTypeDefinition,
+>;
+type SimpleResultTernaryCallback =
+    Box<dyn Fn(&FloatDefinition, &FloatDefinition, &FloatDefinition) -> ReturnTypeSpecification>;
+
+pub struct OpTernaryBuilder {
+    op: OpTernary,
+}
+
+impl OpTernaryBuilder {
+    #[allow(dead_code)] // depending on the enabled features, this function might not be used
+    pub(crate) fn new(trait_name: &'static str, fn_name: &'static str) -> Self {
+        let fn_op = Ident::new(fn_name, Span::call_site());
+
+        let fn_test1 = Ident::new(fn_name, Span::call_site());
+        let fn_test2 = Ident::new(fn_name, Span::call_site());
+        let trait_ident: syn::Path = syn::parse_str(trait_name).unwrap();
+
+        Self {
+            op: OpTernary {
+                key: fn_name,
+                fn_name,
+                trait_name,
+                skip_check_return_type_strictness: false,
+                op: Box::new(move |_, _, _| quote! { self.get().#fn_op(a.get(), b.get()) }),
+                result: Box::new(|_, _, _, _| panic!("No result defined")),
+                test: Box::new(
+                    move |var1, var2, var3| quote! { #trait_ident::#fn_test1(#var1, #var2, #var3) },
+                ),
+                test_primitive: Box::new(
+                    move |var1, var2, var3| quote! { #var1.#fn_test2(#var2, #var3) },
+                ),
+                test_precondition: Box::new(|_, _, _| quote! { true }),
+            },
+        }
+    }
+
+    #[allow(dead_code)] // depending on the enabled features, this function might not be used
+    pub(crate) const fn skip_check_return_type_strictness(mut self) -> Self {
+        self.op.skip_check_return_type_strictness = true;
+        self
+    }
+
+    #[allow(dead_code)] // depending on the enabled features, this function might not be used
+    pub(crate) fn op_fn(mut self, callback: OpTernaryCallback) -> Self {
+        self.op.op = callback;
+        self
+    }
+
+    #[allow(dead_code)] // depending on the enabled features, this function might not be used
+    pub(crate) fn op_test_primitive(mut self, callback: TestTernaryCallback) -> Self {
+        self.op.test_primitive = callback;
+        self
+    }
+
+    /// Skip the values for which the operation is not defined (and may panic)
+    #[allow(dead_code)] // depending on the enabled features, this function might not be used
+    pub(crate) fn op_test_precondition(mut self, callback: TestTernaryCallback) -> Self {
+        self.op.test_precondition = callback;
+        self
+    }
+
+    #[allow(dead_code)] // depending on the enabled features, this function might not be used
+    pub(crate) fn result(mut self, result: SimpleResultTernaryCallback) -> Self {
+        self.op.result = Box::new(move |float, a, b, floats| {
+            let output_spec = (result)(float, a, b);
+
+            return_type_definition(&output_spec, floats)
+        });
+
+        self
+    }
+
+    #[allow(dead_code)] // depending on the enabled features, this function might not be used
+    pub(crate) fn build(self) -> OpTernary {
+        self.op
+    }
+}
+
+pub struct OpTernary {
+    pub(crate) key: &'static str,
+    pub(crate) fn_name: &'static str,
+    pub(crate) trait_name: &'static str,
+    pub(crate) skip_check_return_type_strictness: bool,
+    op: OpTernaryCallback,
+    result: ResultTernaryCallback,
+    test: TestTernaryCallback,
+    test_primitive: TestTernaryCallback,
+    test_precondition: TestTernaryCallback,
+}
+
+impl OpTernary {
+    pub(crate) fn get_result(
+        &self,
+        float: &FloatDefinition,
+        a: &FloatDefinition,
+        b: &FloatDefinition,
+        floats: &[FloatDefinition],
+    ) -> ReturnTypeDefinition {
+        (self.result)(float, a, b, floats)
+    }
+
+    pub(crate) fn get_op(
+        &self,
+        float: &FloatDefinition,
+        a: &FloatDefinition,
+        b: &FloatDefinition,
+    ) -> proc_macro2::TokenStream {
+        (self.op)(float, a, b)
+    }
+
+    pub(crate) fn get_test(&self, var1: &str, var2: &str, var3: &str) -> proc_macro2::TokenStream {
+        let var1 = Ident::new(var1, Span::call_site());
+        let var2 = Ident::new(var2, Span::call_site());
+        let var3 = Ident::new(var3, Span::call_site());
+
+        (self.test)(&var1, &var2, &var3)
+    }
+
+    pub(crate) fn get_test_primitive(
+        &self,
+        var1: &str,
+        var2: &str,
+        var3: &str,
+    ) -> proc_macro2::TokenStream {
+        let var1 = Ident::new(var1, Span::call_site());
+        let var2 = Ident::new(var2, Span::call_site());
+        let var3 = Ident::new(var3, Span::call_site());
+
+        (self.test_primitive)(&var1, &var2, &var3)
+    }
+
+    pub(crate) fn get_test_precondition(
+        &self,
+        var1: &str,
+        var2: &str,
+        var3: &str,
+    ) -> proc_macro2::TokenStream {
+        let var1 = Ident::new(var1, Span::call_site());
+        let var2 = Ident::new(var2, Span::call_site());
+        let var3 = Ident::new(var3, Span::call_site());
+
+        (self.test_precondition)(&var1, &var2, &var3)
+    }
+
+    pub(crate) fn get_impl(
+        &self,
+        float: &FloatDefinition,
+        a: &FloatDefinition,
+        b: &FloatDefinition,
+        floats: &[FloatDefinition],
+    ) -> proc_macro2::TokenStream {
+        let output = self.get_result(float, a, b, floats);
+
+        let float_full_type = &float.full_type_ident();
+        let a_full_type = &a.full_type_ident();
+        let b_full_type = &b.full_type_ident();
+
+        let op = &self.get_op(float, a, b);
+
+        let return_value = match output {
+            ReturnTypeDefinition::FloatDefinition(_) => {
+                quote! {
+                    unsafe { Self::Output::new_unchecked(#op) }
+                }
+            }
+            ReturnTypeDefinition::NativeFloat => native_float_return(
+                op,
+                &format!(
+                    "{}::{}({}, {})",
+                    float.full_type_name(),
+                    self.fn_name,
+                    a.full_type_name(),
+                    b.full_type_name()
+                ),
+            ),
+        };
+
+        let output_name = output_name(&output, &float.float_type_ident());
+
+        let trait_ident: syn::Path = syn::parse_str(self.trait_name).unwrap();
+        let fn_ident = Ident::new(self.fn_name, Span::call_site());
+
+        quote! {
+            impl #trait_ident<#a_full_type, #b_full_type> for #float_full_type {
+                type Output = #output_name;
+
+                #[inline]
+                fn #fn_ident(self, a: #a_full_type, b: #b_full_type) -> Self::Output {
+                    #return_value
+                }
+            }
+        }
+    }
 }